@@ -21,12 +21,18 @@ component! {
     RenderTarget {
         render_target: u64,
         z_depth: u32,
-        flip_y: bool
+        flip_y: bool,
+        // Draw order of render targets with the same z depth, lowest first
+        z_order: u32
     },
     BlendMode {
         blend_mode: u8,
         alpha: f32
     },
+    // Layers a render target is drawn on, render targets without one are on layer 1
+    RenderLayers {
+        mask: u32
+    },
     Sprite {
         sprite: u64
     },
@@ -40,6 +46,18 @@ component! {
         data: Vec::<u8>,
         user_data: u64
     },
+    // Build-time sprite atlas, the manifest lists the frames of its page
+    SpriteAtlas {
+        path: String,
+        manifest: Vec::<u8>,
+        loaded: bool
+    },
+    // Flecs Script scene
+    Scene {
+        path: String,
+        script: u64,
+        modified: u64
+    },
     // Bone Animation
     Atlas {
         atlas: u64,
@@ -60,19 +78,78 @@ component! {
         images: Vec::<u64>,
         loaded: bool,
     },
+    // Spine animation playback. Changing `animation` starts it on `track`, mixed with the one before it,
+    // an empty name fades the track out. `queued` plays after the current animation on the same track,
+    // once queued it becomes `animation`.
     BoneAnimation {
-        animation_state: String,
-        animation: String
+        animation: String,
+        track: u32,
+        // Play once and hold the last frame instead of looping
+        once: bool,
+        queued: String,
+        queued_once: bool,
+        // Seconds after the current animation starts that the queued one plays, 0 plays it when the current one ends
+        delay: f32,
+        // Seconds to fade a track out when its animation is cleared, 0 stops it at once
+        mix: f32,
+        skin: String,
+        // Playback speed multiplier, 0 plays at normal speed
+        speed: f32,
+        paused: bool,
+        // Animation and skin applied to the instance, clearing `playing` restarts the animation
+        playing: String,
+        playing_skin: String
     },
     SpineInstance {
         instance: u64,
         instantiated: bool
     },
+    // Event keyed in a Spine animation, forwarded as a child of the animation entity that lives until the next frame.
+    // Observe it with `Observer::dsl("AnimationEvent, Triggered", vec![Event::OnAdd], ..)`
+    AnimationEvent {
+        name: String,
+        int_value: i32,
+        float_value: f32,
+        string_value: String,
+        // Seconds into the animation the event is keyed at
+        time: f32
+    },
     // Frame by Frame Animation
-    FrameByFrameAnimation {},
+    // Sprite sheet animation, `sheet` points to the frames parsed on the host. Set `animation`
+    // to the name of an `AnimationClip` child to play it, an empty name plays every frame in a loop.
+    FrameByFrameAnimation {
+        sheet: u64,
+        // Grid sheets are cut into frames of this size, each shown for `frame_duration` milliseconds
+        frame_width: u32,
+        frame_height: u32,
+        frame_duration: f32,
+        animation: String,
+        // Playback speed multiplier, 0 plays at normal speed
+        speed: f32,
+        paused: bool,
+        // Clip that is playing, the current frame in the sheet and how long it has been shown.
+        // Clearing `playing` restarts the clip.
+        playing: String,
+        frame: u32,
+        elapsed: f32,
+        reverse: bool,
+        // Sheet frame last blitted to the render target, plus one so 0 means nothing was blitted
+        blitted_frame: u32
+    },
+    // Named range of sheet frames, a child of its animation
+    AnimationClip {
+        name: String,
+        from: u32,
+        to: u32,
+        mode: u8,
+        // Play from `to` back to `from`
+        reverse: bool
+    },
     // Tilemaps
     TiledWorld {
-        world: u64
+        world: u64,
+        // Cells within this many pixels of the camera are loaded, 0 uses the game size
+        radius: u32
     },
     TiledCell {
         cell: u64,
@@ -81,6 +158,35 @@ component! {
     Tileset {
         tileset: String
     },
+    // Solid tiles of a cell, one byte per tile, with the cell's world position as origin
+    TileGrid {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        tile_width: u32,
+        tile_height: u32,
+        solid: Vec::<u8>
+    },
+    // Properties of a cell's tiles, readable by guests through `TileProperties::data`. `tiles` holds the gid of the
+    // topmost tile with properties at each tile of the cell's `TileGrid` and `properties` a JSON object of their properties keyed by gid.
+    TileProperties {
+        width: u32,
+        height: u32,
+        tiles: Vec::<u64>,
+        properties: String
+    },
+    // Tile of a cell with a tileset animation, drawn to its own render target
+    AnimatedTile {
+        cell: u64,
+        tileset: u64,
+        gid: u32,
+        frame: u32,
+        elapsed: f32,
+        flip_x: bool,
+        flip_y: bool,
+        flip_diagonal: bool
+    },
 
     // -- Tags --
     // Rendering
@@ -98,6 +204,10 @@ component! {
     // General
     Loading {},
     Loaded {},
+    // Added when an animation clip that doesn't loop reaches its end, removed when a clip starts
+    AnimationFinished {},
+    // Added to an `AnimationEvent` once its values are set
+    Triggered {},
     Connected {},
     Disconnected {},
     // Relationships
@@ -121,6 +231,12 @@ component! {
     RenderSystems {
         entity: u64
     },
+    RenderPhases {
+        pre_render: u64,
+        blit: u64,
+        draw: u64,
+        post_render: u64
+    },
     GameConfig {
         window_width: u32,
         window_height: u32,
@@ -133,17 +249,65 @@ component! {
     },
 
     // Camera components
+    // Cameras draw the render targets on their layers into a viewport rect, in game coordinates
+    // on the screen or in pixels of their render target. A viewport without a size covers the whole destination.
     Camera {
+        viewport_x: f32,
+        viewport_y: f32,
         viewport_width: f32,
         viewport_height: f32,
         zoom: f32,
         min_zoom: f32,
-        max_zoom: f32
+        max_zoom: f32,
+        // Screen shake offset for the current frame, set by the camera system
+        shake_x: f32,
+        shake_y: f32,
+        // Layers of render targets this camera draws, 0 draws every layer
+        layer_mask: u32,
+        // 0xRRGGBBAA the viewport is cleared with before drawing, a transparent color doesn't clear
+        clear_color: u32,
+        // Cameras draw in ascending order, so cameras drawing into a render target
+        // should come before the cameras that show it
+        order: i32,
+        // Entity with the render target to draw into, 0 draws to the screen
+        render_target: u64
+    },
+    // Move the camera smoothly towards a target entity. The target can move freely inside
+    // the deadzone around the view center, `lerp` is the fraction of the distance covered per 60 fps frame.
+    CameraFollow {
+        target: u64,
+        deadzone_width: f32,
+        deadzone_height: f32,
+        lerp: f32,
+        // Smoothed view center
+        x: f32,
+        y: f32,
+        initialized: bool
+    },
+    // Keep the view inside a world rect, cameras without bounds use the extents of the loaded Tiled worlds
+    CameraBounds {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32
+    },
+    // Shake the camera by up to `intensity` pixels, fading out over `duration` seconds
+    CameraShake {
+        intensity: f32,
+        duration: f32,
+        elapsed: f32
     },
     // Add to singletons section
     MainCamera {
         entity: u64
     },
+    // Render targets drawn and culled this frame, and the draw calls and sprites of the last frame's sprite batches
+    RenderStats {
+        drawn: u32,
+        culled: u32,
+        draw_calls: u32,
+        sprites: u32
+    },
 }
 
 pub fn init() {
@@ -157,8 +321,11 @@ pub fn init() {
     Image::register();
     RenderTarget::register();
     BlendMode::register();
+    RenderLayers::register();
     // Fetch
     FetchRequest::register();
+    SpriteAtlas::register();
+    Scene::register();
     // Animation
     Atlas::register();
     Skeleton::register();
@@ -166,12 +333,19 @@ pub fn init() {
     BoneAnimation::register();
     BoneAnimationImage::register();
     SpineInstance::register();
+    AnimationEvent::register();
     // Frame by Frame Animation
     FrameByFrameAnimation::register();
+    AnimationClip::register();
+    AnimationFinished::register();
+    Triggered::register();
     // Tilemaps
     TiledWorld::register();
     TiledCell::register();
     Tileset::register();
+    AnimatedTile::register();
+    TileGrid::register();
+    TileProperties::register();
 
     // Register tags
     // Rendering
@@ -200,17 +374,24 @@ pub fn init() {
     // Register singletons
     KeyboardInput::register();
     RenderSystems::register();
+    RenderPhases::register();
     GameConfig::register();
 
     // Register camera components
     Camera::register();
+    CameraFollow::register();
+    CameraBounds::register();
+    CameraShake::register();
     MainCamera::register();
+    RenderStats::register();
     Player::register();
 
     // Add singletons
     World::add_singleton::<KeyboardInput>();
     World::add_singleton::<RenderSystems>();
+    World::add_singleton::<RenderPhases>();
     World::add_singleton::<GameConfig>();
     World::add_singleton::<MainCamera>();
+    World::add_singleton::<RenderStats>();
     World::add_singleton::<Player>();
 }
\ No newline at end of file
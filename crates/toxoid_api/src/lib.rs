@@ -1,6 +1,10 @@
 #![allow(warnings)]
 pub mod components;
 pub use components::*;
+pub mod query;
+pub use query::*;
+pub mod tile_grid;
+pub use tile_grid::*;
 
 // Native
 #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
@@ -14,6 +18,8 @@ pub use toxoid_host::{
     Observer as ToxoidObserver,
     Phase as ToxoidPhase,
     Pipeline as ToxoidPipeline,
+    Timer as ToxoidTimer,
+    Prefab as ToxoidPrefab,
     Iter as ToxoidIter,
     bindings::exports::toxoid::engine::ecs::{
         GuestComponent,
@@ -26,6 +32,8 @@ pub use toxoid_host::{
         GuestIter,
         GuestPhase,
         GuestPipeline,
+        GuestTimer,
+        GuestPrefab,
         EntityDesc,
         ComponentDesc,
         QueryDesc,
@@ -34,7 +42,9 @@ pub use toxoid_host::{
         SortingDesc,
         MemberType,
         Relationship,
+        Phases,
         Event,
+        Command,
         Guest as WorldGuest,
         EcsEntityT,
         PointerT
@@ -55,6 +65,8 @@ pub use toxoid_guest::bindings::{
         Iter as ToxoidIter,
         Phase as ToxoidPhase,
         Pipeline as ToxoidPipeline,
+        Timer as ToxoidTimer,
+        Prefab as ToxoidPrefab,
         EntityDesc,
         ComponentDesc,
         QueryDesc,
@@ -63,7 +75,9 @@ pub use toxoid_guest::bindings::{
         SortingDesc,
         MemberType,
         Relationship,
+        Phases,
         Event,
+        Command,
         EcsEntityT,
         PointerT,
         self as ToxoidApi
@@ -128,8 +142,21 @@ pub struct Pipeline {
     pipeline: ToxoidPipeline
 }
 
+pub struct Timer {
+    timer: ToxoidTimer
+}
+
+pub struct Prefab {
+    prefab: ToxoidPrefab
+}
+
 pub struct World;
 
+// Batched structural changes, applied on the host in a single call
+pub struct Commands {
+    commands: Vec<Command>
+}
+
 pub static mut CALLBACKS: once_cell::sync::Lazy<Vec<Box<dyn Fn(&Iter)>>> = once_cell::sync::Lazy::new(|| Vec::new());
 
 impl Entity {
@@ -252,6 +279,92 @@ impl Entity {
     pub fn remove_relationship(&mut self, relationship: Relationship, target: Entity) {
         self.entity.remove_relationship(relationship, target.get_id());
     }
+
+    // Add a component, tag or pair by id when the type is only known at runtime
+    pub fn add_id(&mut self, id: ecs_entity_t) {
+        self.entity.add(id);
+    }
+
+    pub fn has_id(&self, id: ecs_entity_t) -> bool {
+        self.entity.has(id)
+    }
+
+    // Set the members of a component from a JSON object, the component is added if needed
+    pub fn set_json(&mut self, component: ecs_entity_t, json: &str) -> bool {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return self.entity.set_json(component, json.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return self.entity.set_json(component, json);
+    }
+
+    // Add components from a serialized Flecs entity
+    pub fn from_json(&mut self, json: &str) -> bool {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return self.entity.from_json(json.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return self.entity.from_json(json);
+    }
+
+    // Add a (R, target) pair where R is a relationship component, e.g. `TilesetRelationship`
+    pub fn add_pair<R: ComponentType + 'static>(&mut self, target: &Entity) {
+        self.entity.add(ToxoidApi::make_pair(R::get_id(), target.get_id()));
+    }
+
+    pub fn remove_pair<R: ComponentType + 'static>(&mut self, target: &Entity) {
+        self.entity.remove(ToxoidApi::make_pair(R::get_id(), target.get_id()));
+    }
+
+    pub fn has_pair<R: ComponentType + 'static>(&self, target: &Entity) -> bool {
+        self.entity.has(ToxoidApi::make_pair(R::get_id(), target.get_id()))
+    }
+
+    // Data of a pair whose relationship has members, the pair is added if it doesn't exist
+    pub fn get_pair<R: Component + ComponentType + Default + 'static>(&mut self, target: &Entity) -> R {
+        let mut component = R::default();
+        let pair = ToxoidApi::make_pair(R::get_id(), target.get_id());
+        let component_ptr = self.entity.get(pair);
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let toxoid_component = ToxoidComponent::new(component_ptr, self.entity.get_id(), pair);
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let toxoid_component = component_ptr;
+        component.set_component(toxoid_component);
+        component.set_entity_added(self.entity.get_id());
+        component.set_component_type(pair);
+        component
+    }
+
+    #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+    pub fn target(&self, relationship: Relationship, index: i32) -> Option<Entity> {
+        self.entity.target(relationship, index).map(|id| Entity { entity: ToxoidEntity { id } })
+    }
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    pub fn target(&self, relationship: Relationship, index: i32) -> Option<Entity> {
+        self.entity.target(relationship, index).map(|entity| Entity { entity })
+    }
+
+    #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+    pub fn targets(&self, relationship: Relationship) -> Vec<Entity> {
+        self.entity.targets(relationship).iter().map(|id| Entity { entity: ToxoidEntity { id: *id } }).collect()
+    }
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    pub fn targets(&self, relationship: Relationship) -> Vec<Entity> {
+        self.entity.targets(relationship).into_iter().map(|entity| Entity { entity }).collect()
+    }
+
+    // Instance of a slot child prefab that was created with this entity
+    pub fn slot(&self, slot: &Prefab) -> Option<Entity> {
+        self.target(Relationship::Custom(slot.get_id()), 0)
+    }
+
+    pub fn target_of<R: ComponentType + 'static>(&self, index: i32) -> Option<Entity> {
+        self.target(Relationship::Custom(R::get_id()), index)
+    }
+
+    pub fn targets_of<R: ComponentType + 'static>(&self) -> Vec<Entity> {
+        self.targets(Relationship::Custom(R::get_id()))
+    }
     
     pub fn is_a(&self, target: Entity) {
         self.entity.add_relationship(Relationship::IsA, target.get_id());
@@ -287,6 +400,22 @@ impl Entity {
         Self { entity: ToxoidEntity::from_id(self.entity.parent().get_id()) }
     }
 
+    // Full path from the root, e.g. "Level.Player"
+    pub fn get_path(&self) -> String {
+        self.entity.get_path()
+    }
+
+    // Look up a child (or grandchild with a dotted path) by name
+    #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+    pub fn lookup(&self, path: &str) -> Option<Entity> {
+        self.entity.lookup(path.to_string()).map(|id| Entity { entity: ToxoidEntity { id } })
+    }
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    pub fn lookup(&self, path: &str) -> Option<Entity> {
+        self.entity.lookup(path).map(|entity| Entity { entity })
+    }
+
     #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
     pub fn children(&self) -> Vec<Entity> {
         self.entity.children().iter().map(|child| Entity { entity: ToxoidEntity { id: *child } }).collect()
@@ -365,8 +494,9 @@ impl Query {
     // }
 
     pub fn iter(&mut self) -> Iter {
+        // The host returns a boxed iter that points to the query's stored iterator
         #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
-        let iter = ToxoidIter::new(self.query.iter());
+        let iter = unsafe { *Box::from_raw(self.query.iter() as usize as *mut ToxoidIter) };
         #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
         let iter = self.query.iter();
         Iter { iter }
@@ -418,6 +548,45 @@ impl Query {
             .collect();
         components
     }
+
+    // Change detection requires a cached query, a query already built without a cache is rebuilt
+    pub fn detect_changes(&mut self) -> &mut Self {
+        self.query.detect_changes();
+        self
+    }
+
+    // Whether the matched tables changed since the last iteration. Always true without `detect_changes`,
+    // always false before `build`.
+    pub fn changed(&self) -> bool {
+        self.query.changed()
+    }
+
+    // Constrain a query variable such as `$Parent`, must be called after `iter` and before `next`
+    pub fn set_var(&mut self, name: &str, value: &Entity) {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        self.query.set_var(name.to_string(), value.get_id());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        self.query.set_var(name, value.get_id());
+    }
+
+    pub fn get_var(&self, name: &str) -> Option<Entity> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let id = self.query.get_var(name.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let id = self.query.get_var(name);
+        if id == 0 { None } else { Some(Entity::from_id(id)) }
+    }
+
+    // Whether an optional term matched for the current table
+    pub fn is_set(&self, index: i8) -> bool {
+        self.query.is_set(index)
+    }
+
+    // Entity the field was matched on, `None` if it was matched on `$this`
+    pub fn field_src(&self, index: i8) -> Option<Entity> {
+        let id = self.query.field_src(index);
+        if id == 0 { None } else { Some(Entity::from_id(id)) }
+    }
 }
 
 
@@ -434,14 +603,22 @@ impl System {
         let callback = Callback::new(callback_fn);
         // Create the Toxoid callback with the registered callback handle
         let callback = ToxoidCallback::new(callback.cb_handle());
-        let query_desc = desc.as_ref().unwrap().query_desc.clone();
-        let desc = desc.unwrap_or(SystemDesc { 
-            name: None, 
-            callback, 
-            query_desc, 
-            is_guest: true, 
-            tick_rate: None
-        });
+        // The registered callback always takes precedence over the one in the descriptor
+        let desc = match desc {
+            Some(desc) => SystemDesc { callback, is_guest: true, ..desc },
+            None => SystemDesc { 
+                name: None, 
+                callback, 
+                query_desc: QueryDesc { expr: "".to_string() }, 
+                is_guest: true, 
+                tick_rate: None,
+                phase: None,
+                interval: None,
+                tick_source: None,
+                multi_threaded: false,
+                immediate: false
+            }
+        };
         Self { system: ToxoidSystem::new(desc) }
     }
 
@@ -449,16 +626,22 @@ impl System {
     pub fn new(desc: Option<SystemDesc>, callback_fn: fn(&Iter)) -> Self {
         // Register the callback in the guest environment
         let callback = Callback::new(callback_fn);
-        // Create the Toxoid callback with the registered callback handle
-        let callback = ToxoidCallback::new(callback.cb_handle());
-        let query_desc = desc.as_ref().unwrap().query_desc.clone();
-        let desc = desc.unwrap_or(SystemDesc { 
-            name: None, 
-            callback: callback.cb_handle(), 
-            query_desc, 
-            is_guest: false, 
-            tick_rate: None 
-        });
+        // The registered callback always takes precedence over the one in the descriptor
+        let desc = match desc {
+            Some(desc) => SystemDesc { callback: callback.cb_handle(), is_guest: false, ..desc },
+            None => SystemDesc { 
+                name: None, 
+                callback: callback.cb_handle(), 
+                query_desc: QueryDesc { expr: "".to_string() }, 
+                is_guest: false, 
+                tick_rate: None,
+                phase: None,
+                interval: None,
+                tick_source: None,
+                multi_threaded: false,
+                immediate: false
+            }
+        };
         Self { system: ToxoidSystem::new(desc) }
     }
 
@@ -477,7 +660,12 @@ impl System {
             callback, 
             is_guest: true, 
             query_desc: QueryDesc { expr: dsl.to_string() },
-            tick_rate
+            tick_rate,
+            phase: None,
+            interval: None,
+            tick_source: None,
+            multi_threaded: false,
+            immediate: false
         };
         Self { system: ToxoidSystem::new(desc) }
     }
@@ -491,7 +679,12 @@ impl System {
             callback: callback.cb_handle(), 
             query_desc: QueryDesc { expr: dsl.to_string() }, 
             is_guest: false,
-            tick_rate
+            tick_rate,
+            phase: None,
+            interval: None,
+            tick_source: None,
+            multi_threaded: false,
+            immediate: false
         };
         Self { system: ToxoidSystem::new(desc) }
     }
@@ -506,6 +699,36 @@ impl System {
         self
     }
 
+    // Run the system in a pipeline phase instead of OnUpdate
+    pub fn phase(self, phase: Phases) -> Self {
+        self.system.phase(phase);
+        self
+    }
+
+    // Run the system every `seconds` instead of every frame, replaces the tick rate
+    pub fn interval(self, seconds: f32) -> Self {
+        self.system.interval(seconds);
+        self
+    }
+
+    // Allow the system to run on multiple worker threads
+    pub fn multi_threaded(self) -> Self {
+        self.system.multi_threaded();
+        self
+    }
+
+    // Apply commands immediately instead of deferring them until the end of the frame
+    pub fn immediate(self) -> Self {
+        self.system.immediate();
+        self
+    }
+
+    // Drive the system from a timer, so systems sharing it tick on the same frame
+    pub fn tick_source(self, timer: &Timer) -> Self {
+        self.system.tick_source(timer.get_id());
+        self
+    }
+
     // TODO: Use query trampoline instead of C functions directlyand use callback resource to make this work on WASM.
     #[cfg(not(target_os = "emscripten"))]
     pub fn order_by(mut self, id: EcsEntityT, callback: unsafe extern "C" fn(u64, *const std::ffi::c_void, u64, *const std::ffi::c_void) -> i32) -> Self {
@@ -531,6 +754,180 @@ impl System {
     }
 }
 
+impl Phase {
+    pub fn new(name: &str) -> Self {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let phase = ToxoidPhase::new(name.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let phase = ToxoidPhase::new(name);
+        Self { phase }
+    }
+
+    pub fn depends_on(self, phase: Phases) -> Self {
+        self.phase.depends_on(phase);
+        self
+    }
+
+    pub fn depends_on_phase(self, phase: &Phase) -> Self {
+        self.phase.depends_on(Phases::Custom(phase.get_id()));
+        self
+    }
+
+    pub fn get_id(&self) -> ecs_entity_t {
+        self.phase.get_id()
+    }
+}
+
+impl Prefab {
+    pub fn new() -> Self {
+        Self::create(None, None)
+    }
+
+    pub fn named(name: &str) -> Self {
+        Self::create(Some(name), None)
+    }
+
+    // Child prefabs are instantiated along with every instance of their parent
+    pub fn child(&self, name: Option<&str>) -> Prefab {
+        Self::create(name, Some(self.get_id()))
+    }
+
+    fn create(name: Option<&str>, parent: Option<ecs_entity_t>) -> Self {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let prefab = ToxoidPrefab::new(name.map(|name| name.to_string()), parent);
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let prefab = ToxoidPrefab::new(name, parent);
+        Self { prefab }
+    }
+
+    pub fn get_id(&self) -> ecs_entity_t {
+        self.prefab.get_id()
+    }
+
+    pub fn add<T: Component + ComponentType + 'static>(&mut self) -> &mut Self {
+        self.prefab.add(T::get_id());
+        self
+    }
+
+    // Members set on the returned component are the defaults for instances
+    pub fn get<T: Component + ComponentType + Default + 'static>(&self) -> T {
+        let mut component = T::default();
+        let component_ptr = self.prefab.get(T::get_id());
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let toxoid_component = ToxoidComponent::new(component_ptr, self.get_id(), T::get_id());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let toxoid_component = component_ptr;
+        component.set_component(toxoid_component);
+        component.set_entity_added(self.get_id());
+        component.set_component_type(T::get_id());
+        component
+    }
+
+    // Copy the prefab's value into each instance for a component that is inherited
+    pub fn override_component<T: Component + ComponentType + 'static>(&mut self) -> &mut Self {
+        self.prefab.override_component(T::get_id());
+        self
+    }
+
+    // Share the prefab's value with instances, this applies to the component type for every prefab
+    pub fn inherit<T: Component + ComponentType + 'static>(&mut self) -> &mut Self {
+        self.prefab.inherit_component(T::get_id());
+        self
+    }
+
+    // Make this prefab a variant of another prefab
+    pub fn is_a(&mut self, base: &Prefab) -> &mut Self {
+        self.prefab.is_a(base.get_id());
+        self
+    }
+
+    // Child prefab that instances of this prefab can find their copy of with `Entity::slot`
+    pub fn slot(&self, name: Option<&str>) -> Prefab {
+        let slot = self.child(name);
+        slot.prefab.slot();
+        slot
+    }
+
+    pub fn spawn(&self) -> Option<Entity> {
+        self.spawn_batch(1).pop().map(World::get_entity)
+    }
+
+    // Instantiate many entities with a single bulk operation, returns their ids
+    // so large batches don't make a host call per entity. Wrap one with `World::get_entity` to use it.
+    pub fn spawn_batch(&self, count: u32) -> Vec<u64> {
+        self.prefab.instantiate(count)
+    }
+}
+
+impl Timer {
+    pub fn new() -> Self {
+        Self { timer: ToxoidTimer::new() }
+    }
+
+    // Timer that ticks once after `seconds`
+    pub fn timeout(seconds: f32) -> Self {
+        let timer = Self::new();
+        timer.set_timeout(seconds);
+        timer
+    }
+
+    // Timer that ticks every `seconds`
+    pub fn interval(seconds: f32) -> Self {
+        let timer = Self::new();
+        timer.set_interval(seconds);
+        timer
+    }
+
+    // Rate filter that ticks every `rate` ticks of the source timer, or every `rate` frames
+    pub fn rate(rate: i32, source: Option<&Timer>) -> Self {
+        let timer = Self::new();
+        timer.set_rate(rate, source);
+        timer
+    }
+
+    pub fn get_id(&self) -> ecs_entity_t {
+        self.timer.get_id()
+    }
+
+    pub fn set_timeout(&self, seconds: f32) {
+        self.timer.set_timeout(seconds);
+    }
+
+    pub fn get_timeout(&self) -> f32 {
+        self.timer.get_timeout()
+    }
+
+    pub fn set_interval(&self, seconds: f32) {
+        self.timer.set_interval(seconds);
+    }
+
+    pub fn get_interval(&self) -> f32 {
+        self.timer.get_interval()
+    }
+
+    pub fn set_rate(&self, rate: i32, source: Option<&Timer>) {
+        self.timer.set_rate(rate, source.map(|source| source.get_id()));
+    }
+
+    pub fn start(&self) {
+        self.timer.start();
+    }
+
+    pub fn stop(&self) {
+        self.timer.stop();
+    }
+
+    pub fn reset(&self) {
+        self.timer.reset();
+    }
+
+    // Emit a custom event on the target every time the timer ticks,
+    // observe it with `Observer::dsl(.., vec![Event::Custom(event)], ..)`
+    pub fn fire(&self, event: ecs_entity_t, target: &Entity) {
+        self.timer.fire(event, target.get_id());
+    }
+}
+
 impl Observer {
     // Not wasm
     #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
@@ -688,6 +1085,28 @@ impl Iter {
             .collect();
         components
     }
+
+    pub fn get_var(&self, name: &str) -> Option<Entity> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let id = self.iter.get_var(name.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let id = self.iter.get_var(name);
+        if id == 0 { None } else { Some(Entity::from_id(id)) }
+    }
+
+    pub fn is_set(&self, index: i8) -> bool {
+        self.iter.is_set(index)
+    }
+
+    pub fn field_src(&self, index: i8) -> Option<Entity> {
+        let id = self.iter.field_src(index);
+        if id == 0 { None } else { Some(Entity::from_id(id)) }
+    }
+
+    // Whether the tables matched by the current result changed since the last iteration
+    pub fn changed(&self) -> bool {
+        self.iter.changed()
+    }
 }
 
 impl World {
@@ -716,6 +1135,13 @@ impl World {
         ToxoidApi::remove_singleton(component_id);
     }
 
+    // Runs the callback whenever a member of the singleton is set, read the new value with `get_singleton`
+    pub fn on_singleton_set<T: Component + ComponentType + 'static>(callback_fn: fn(&Iter)) -> Observer {
+        let mut observer = Observer::dsl(&format!("{}($)", T::get_name()), vec![Event::OnSet], callback_fn);
+        observer.build();
+        observer
+    }
+
     pub fn get_entity(entity_id: u64) -> Entity {
         #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
         return Entity { entity: ToxoidEntity { id: entity_id } };
@@ -727,12 +1153,170 @@ impl World {
         ToxoidApi::remove_entity(entity_id);
     }
 
+    // Create `count` entities that all have the given components with a single host call, returns their ids
+    pub fn spawn_batch(count: u32, components: &[ecs_entity_t]) -> Vec<u64> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return ToxoidApi::spawn_batch(count, components.to_vec());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return ToxoidApi::spawn_batch(count, components);
+    }
+
+    // Delete every entity that has the component
+    pub fn delete_with<T: ComponentType + 'static>() {
+        ToxoidApi::delete_with(T::get_id());
+    }
+
+    // Evaluate a Flecs Script, returns the script entity that owns the created entities
+    pub fn script_init(filename: &str, code: &str) -> Option<Entity> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        let script = ToxoidApi::script_init(filename.to_string(), code.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        let script = ToxoidApi::script_init(filename, code);
+        if script == 0 { None } else { Some(World::get_entity(script)) }
+    }
+
+    // Re-evaluate a script, entities created by the previous code are replaced
+    pub fn script_update(script: &Entity, code: &str) -> bool {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return ToxoidApi::script_update(script.get_id(), code.to_string());
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return ToxoidApi::script_update(script.get_id(), code);
+    }
+
+    pub fn script_clear(script: &Entity) {
+        ToxoidApi::script_clear(script.get_id());
+    }
+
+    // Look up an entity by name or dotted path, resolved from the current scope up to the root
+    pub fn lookup(path: &str) -> Option<Entity> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return ToxoidApi::get_entity_named(path.to_string()).map(|id| Entity { entity: ToxoidEntity { id } });
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return ToxoidApi::get_entity_named(path).map(|entity| Entity { entity });
+    }
+
+    // Returns the entity if it is still alive
+    pub fn try_get_entity(entity_id: u64) -> Option<Entity> {
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        return ToxoidApi::get_entity(entity_id).map(|id| Entity { entity: ToxoidEntity { id } });
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        return ToxoidApi::get_entity(entity_id).map(|entity| Entity { entity });
+    }
+
+    // New named entities are created as children of the scope and lookups resolve relative to it
+    pub fn set_scope(scope: &Entity) -> Entity {
+        World::get_entity(ToxoidApi::set_scope(scope.get_id()))
+    }
+
+    pub fn get_scope() -> Option<Entity> {
+        let scope = ToxoidApi::get_scope();
+        if scope == 0 { None } else { Some(World::get_entity(scope)) }
+    }
+
+    pub fn scope<F: FnOnce()>(scope: &Entity, f: F) {
+        let previous = ToxoidApi::set_scope(scope.get_id());
+        f();
+        ToxoidApi::set_scope(previous);
+    }
+
     pub fn has_entity_named(name: String) -> bool {
         #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
         return ToxoidApi::has_entity_named(name);
         #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
         return ToxoidApi::has_entity_named(name.as_str());
     }
+
+    // Queue structural changes until `defer_end`, returns true if the world was not already deferred
+    pub fn defer_begin() -> bool {
+        ToxoidApi::defer_begin()
+    }
+
+    // Apply queued changes, returns true if the world left deferred mode
+    pub fn defer_end() -> bool {
+        ToxoidApi::defer_end()
+    }
+
+    pub fn is_deferred() -> bool {
+        ToxoidApi::is_deferred()
+    }
+
+    // Run a closure with structural changes deferred until it returns
+    pub fn defer<F: FnOnce()>(f: F) {
+        World::defer_begin();
+        f();
+        World::defer_end();
+    }
+
+    // Run a closure with the world in readonly mode, changes are applied when it returns.
+    // Inside systems the world is already readonly, so the closure just runs.
+    pub fn readonly<F: FnOnce()>(multi_threaded: bool, f: F) {
+        let began = ToxoidApi::readonly_begin(multi_threaded);
+        f();
+        if began {
+            ToxoidApi::readonly_end();
+        }
+    }
+}
+
+impl Commands {
+    pub fn new() -> Self {
+        Self { commands: Vec::new() }
+    }
+
+    pub fn add<T: Component + ComponentType + 'static>(&mut self, entity: &Entity) -> &mut Self {
+        self.commands.push(Command::Add((entity.get_id(), T::get_id())));
+        self
+    }
+
+    pub fn remove<T: Component + ComponentType + 'static>(&mut self, entity: &Entity) -> &mut Self {
+        self.commands.push(Command::Remove((entity.get_id(), T::get_id())));
+        self
+    }
+
+    pub fn add_relationship(&mut self, entity: &Entity, relationship: Relationship, target: &Entity) -> &mut Self {
+        self.commands.push(Command::AddRelationship((entity.get_id(), relationship, target.get_id())));
+        self
+    }
+
+    pub fn remove_relationship(&mut self, entity: &Entity, relationship: Relationship, target: &Entity) -> &mut Self {
+        self.commands.push(Command::RemoveRelationship((entity.get_id(), relationship, target.get_id())));
+        self
+    }
+
+    pub fn enable(&mut self, entity: &Entity) -> &mut Self {
+        self.commands.push(Command::Enable(entity.get_id()));
+        self
+    }
+
+    pub fn disable(&mut self, entity: &Entity) -> &mut Self {
+        self.commands.push(Command::Disable(entity.get_id()));
+        self
+    }
+
+    pub fn delete(&mut self, entity: &Entity) -> &mut Self {
+        self.commands.push(Command::Delete(entity.get_id()));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    // Submit all queued commands in one call, leaving the buffer empty for reuse
+    pub fn submit(&mut self) {
+        if self.commands.is_empty() {
+            return;
+        }
+        let commands = std::mem::take(&mut self.commands);
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+        ToxoidApi::submit(commands);
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+        ToxoidApi::submit(&commands);
+    }
 }
 
 pub fn run_callback(iter: ToxoidIter, handle: u64) {
@@ -780,7 +1364,31 @@ pub enum DataType {
     Cell,
     Tileset,
     Audio,
-    Font
+    Font,
+    Scene,
+    TilesetSource,
+    SpriteAtlas,
+    SpriteAtlasPage,
+    SpriteSheet,
+    AsepriteSheet
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationMode {
+    Loop,
+    PingPong,
+    Once
+}
+
+impl AnimationMode {
+    pub fn from_u8(mode: u8) -> Self {
+        match mode {
+            1 => AnimationMode::PingPong,
+            2 => AnimationMode::Once,
+            _ => AnimationMode::Loop
+        }
+    }
 }
 
 #[repr(u32)]
@@ -837,6 +1445,62 @@ pub fn load_sprite(path: &str, rendered_on_load: bool) -> Entity {
     entity
 }
 
+// Load a build-time sprite atlas manifest and its page. Sprites loaded after it
+// whose path is one of its frames use the frame's region of the page instead of being fetched.
+pub fn load_sprite_atlas(path: &str) -> Entity {
+    let mut entity = Entity::new(None);
+    entity.add::<SpriteAtlas>();
+    entity.add::<Sprite>();
+    let mut sprite_atlas = entity.get::<SpriteAtlas>();
+    sprite_atlas.set_path(path.to_string());
+    fetch(path, DataType::SpriteAtlas, Some(entity.get_id()));
+    entity
+}
+
+fn new_frame_by_frame_animation(rendered_on_load: bool) -> Entity {
+    let mut entity = Entity::new(None);
+    entity.add::<FrameByFrameAnimation>();
+    entity.add::<Sprite>();
+    entity.add::<Position>();
+    entity.add::<Size>();
+    if rendered_on_load {
+        entity.add::<RenderableOnLoad>();
+    }
+    entity
+}
+
+// Load a sprite sheet cut into a grid of frames, each shown for `frame_duration` milliseconds
+pub fn load_sprite_sheet(path: &str, frame_width: u32, frame_height: u32, frame_duration: f32, rendered_on_load: bool) -> Entity {
+    let mut entity = new_frame_by_frame_animation(rendered_on_load);
+    let animation = entity.get::<FrameByFrameAnimation>();
+    animation.set_frame_width(frame_width);
+    animation.set_frame_height(frame_height);
+    animation.set_frame_duration(frame_duration);
+    fetch(path, DataType::SpriteSheet, Some(entity.get_id()));
+    entity
+}
+
+// Load a sprite sheet exported from Aseprite as JSON, its tags become animation clips
+pub fn load_aseprite(path: &str, rendered_on_load: bool) -> Entity {
+    let entity = new_frame_by_frame_animation(rendered_on_load);
+    fetch(path, DataType::AsepriteSheet, Some(entity.get_id()));
+    entity
+}
+
+// Add a named range of frames to an animation
+pub fn add_animation_clip(animation_entity: &Entity, name: &str, from: u32, to: u32, mode: AnimationMode, reverse: bool) -> Entity {
+    let mut clip_entity = Entity::new(None);
+    clip_entity.add::<AnimationClip>();
+    let clip = clip_entity.get::<AnimationClip>();
+    clip.set_name(name.to_string());
+    clip.set_from(from);
+    clip.set_to(to);
+    clip.set_mode(mode as u8);
+    clip.set_reverse(reverse);
+    clip_entity.child_of_id(animation_entity.get_id());
+    clip_entity
+}
+
 pub fn load_animation(atlas_filename: &str, skeleton_filename: &str, rendered_on_load: bool) -> Entity {
     let mut entity = Entity::new(None);
     entity.add::<Loading>();
@@ -858,6 +1522,22 @@ pub fn load_animation(atlas_filename: &str, skeleton_filename: &str, rendered_on
     entity
 }
 
+// Start a Spine animation on a track, mixed with the animation that was playing on it
+pub fn play_bone_animation(animation_entity: &mut Entity, name: &str, track: u32, once: bool) {
+    let animation = animation_entity.get::<BoneAnimation>();
+    animation.set_track(track);
+    animation.set_once(once);
+    animation.set_animation(name.to_string());
+}
+
+// Play a Spine animation after the current one on its track, or `delay` seconds after the current one started
+pub fn queue_bone_animation(animation_entity: &mut Entity, name: &str, once: bool, delay: f32) {
+    let animation = animation_entity.get::<BoneAnimation>();
+    animation.set_queued_once(once);
+    animation.set_delay(delay);
+    animation.set_queued(name.to_string());
+}
+
 pub fn load_worldmap(path: &str, rendered_on_load: bool) -> Entity {
     let mut entity = Entity::new(None);
     entity.add::<TiledWorld>();
@@ -897,6 +1577,36 @@ pub fn load_tileset(path: &str, rendered_on_load: bool) -> Entity {
     entity
 }
 
+// Load an external Tiled tileset (`.tsx` / `.tsj`), its image is fetched once the tileset is parsed
+pub fn load_tileset_source(path: &str, rendered_on_load: bool) -> Entity {
+    let mut entity = Entity::new(None);
+    entity.add::<Tileset>();
+    entity.add::<Sprite>();
+    entity.add::<Size>();
+    if rendered_on_load {
+        entity.add::<RenderableOnLoad>();
+    }
+    fetch(path, DataType::TilesetSource, Some(entity.get_id()));
+    entity
+}
+
+// Load a `.flecs` scene script, the entities it declares are created when it finishes loading
+pub fn load_scene(path: &str) -> Entity {
+    let mut entity = Entity::new(None);
+    entity.add::<Scene>();
+    let mut scene = entity.get::<Scene>();
+    scene.set_path(path.to_string());
+    fetch(path, DataType::Scene, Some(entity.get_id()));
+    entity
+}
+
+// Fetch the scene script again and re-evaluate it
+pub fn reload_scene(entity: &Entity) {
+    let mut entity = World::get_entity(entity.get_id());
+    let scene = entity.get::<Scene>();
+    fetch(&scene.get_path(), DataType::Scene, Some(entity.get_id()));
+}
+
 pub fn c_string(rust_str: &str) -> *const i8 {
     use std::collections::HashMap;
     use std::sync::Once;
@@ -0,0 +1,186 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// A single typed term, `&T` reads a component, `&mut T` reads and writes it
+// and `Option<..>` makes the term optional.
+pub trait QueryTerm {
+    type Item;
+    fn term() -> String;
+    fn fetch(iter: &Iter, index: i8) -> Vec<Self::Item>;
+}
+
+// A tuple of typed terms, e.g. `(&Position, &mut Size)`
+pub trait QueryTerms {
+    type Item;
+    fn expr() -> String;
+    fn fetch(iter: &Iter) -> Vec<Self::Item>;
+}
+
+impl<'a, T: Component + ComponentType + Default + 'static> QueryTerm for &'a T {
+    type Item = T;
+
+    fn term() -> String {
+        format!("[in] {}", T::get_name())
+    }
+
+    fn fetch(iter: &Iter, index: i8) -> Vec<T> {
+        iter.components::<T>(index)
+    }
+}
+
+impl<'a, T: Component + ComponentType + Default + 'static> QueryTerm for &'a mut T {
+    type Item = T;
+
+    fn term() -> String {
+        format!("[inout] {}", T::get_name())
+    }
+
+    fn fetch(iter: &Iter, index: i8) -> Vec<T> {
+        iter.components::<T>(index)
+    }
+}
+
+impl<'a, T: Component + ComponentType + Default + 'static> QueryTerm for Option<&'a T> {
+    type Item = Option<T>;
+
+    fn term() -> String {
+        format!("[in] ?{}", T::get_name())
+    }
+
+    fn fetch(iter: &Iter, index: i8) -> Vec<Option<T>> {
+        fetch_optional::<T>(iter, index)
+    }
+}
+
+impl<'a, T: Component + ComponentType + Default + 'static> QueryTerm for Option<&'a mut T> {
+    type Item = Option<T>;
+
+    fn term() -> String {
+        format!("[inout] ?{}", T::get_name())
+    }
+
+    fn fetch(iter: &Iter, index: i8) -> Vec<Option<T>> {
+        fetch_optional::<T>(iter, index)
+    }
+}
+
+// Optional terms are set or not for the whole table
+fn fetch_optional<T: Component + ComponentType + Default + 'static>(iter: &Iter, index: i8) -> Vec<Option<T>> {
+    if iter.is_set(index) {
+        iter.components::<T>(index).into_iter().map(Some).collect()
+    } else {
+        (0..iter.count()).map(|_| None).collect()
+    }
+}
+
+toxoid_api_macro::impl_query_terms!(16);
+
+// "(R, T)" for matching a relationship pair in a query expression
+pub fn pair<R: ComponentType, T: ComponentType>() -> String {
+    format!("({}, {})", R::get_name(), T::get_name())
+}
+
+// "(R, $var)" for binding the target of a relationship to a query variable
+pub fn pair_var<R: ComponentType>(var: &str) -> String {
+    format!("({}, ${})", R::get_name(), var.trim_start_matches('$'))
+}
+
+pub struct TypedQuery<Q: QueryTerms> {
+    filters: Vec<String>,
+    query: Option<Query>,
+    terms: PhantomData<Q>
+}
+
+impl<Q: QueryTerms> TypedQuery<Q> {
+    pub fn new() -> Self {
+        Self { filters: Vec::new(), query: None, terms: PhantomData }
+    }
+
+    // Filters are appended after the data terms so they don't shift the term indexes
+    pub fn with<T: ComponentType>(mut self) -> Self {
+        self.filters.push(format!("[none] {}", T::get_name()));
+        self
+    }
+
+    pub fn without<T: ComponentType>(mut self) -> Self {
+        self.filters.push(format!("!{}", T::get_name()));
+        self
+    }
+
+    pub fn with_pair<R: ComponentType, T: ComponentType>(mut self) -> Self {
+        self.filters.push(format!("[none] {}", pair::<R, T>()));
+        self
+    }
+
+    // Matches entities that have any target for the relationship, the target is available as `$var`
+    pub fn with_relationship<R: ComponentType>(mut self, var: &str) -> Self {
+        self.filters.push(format!("[none] {}", pair_var::<R>(var)));
+        self
+    }
+
+    pub fn expr(&self) -> String {
+        std::iter::once(Q::expr())
+            .chain(self.filters.iter().cloned())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn build(mut self) -> Self {
+        self.build_query();
+        self
+    }
+
+    fn build_query(&mut self) {
+        let mut query = Query::dsl(&self.expr());
+        query.build();
+        self.query = Some(query);
+    }
+
+    pub fn run<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Q::Item)
+    {
+        self.run_entity(|_, item| f(item));
+    }
+
+    pub fn run_entity<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Entity, Q::Item)
+    {
+        // Build lazily so the query can be reused between runs
+        if self.query.is_none() {
+            self.build_query();
+        }
+        let query = self.query.as_mut().unwrap();
+        let mut iter = query.iter();
+        while iter.next() {
+            iter.each_entity::<Q, _>(&mut f);
+        }
+    }
+}
+
+impl Query {
+    pub fn each<Q: QueryTerms>() -> TypedQuery<Q> {
+        TypedQuery::new()
+    }
+}
+
+impl Iter {
+    // Used from systems built with `QueryTerms::expr`, so the terms line up with the system query
+    pub fn each<Q: QueryTerms, F>(&self, mut f: F)
+    where
+        F: FnMut(Q::Item)
+    {
+        Q::fetch(self).into_iter().for_each(|item| f(item));
+    }
+
+    pub fn each_entity<Q: QueryTerms, F>(&self, mut f: F)
+    where
+        F: FnMut(&Entity, Q::Item)
+    {
+        self.entities()
+            .iter()
+            .zip(Q::fetch(self))
+            .for_each(|(entity, item)| f(entity, item));
+    }
+}
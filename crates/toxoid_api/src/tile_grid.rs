@@ -0,0 +1,243 @@
+use crate::*;
+use std::collections::HashMap;
+
+// Keeps box edges that touch a tile boundary out of the next tile
+const EDGE_EPSILON: f32 = 0.001;
+
+// A copy of a `TileGrid` component from `TileGrid::data`, so queries don't read the component for every tile
+#[derive(Debug, Clone, Default)]
+pub struct TileGridData {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub solid: Vec<u8>,
+}
+
+// A copy of a `TileProperties` component from `TileProperties::data`, with the properties parsed
+#[derive(Debug, Clone, Default)]
+pub struct TilePropertiesData {
+    pub width: u32,
+    pub height: u32,
+    pub tiles: Vec<u64>,
+    pub properties: HashMap<u64, serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TileHit {
+    pub tile_x: i32,
+    pub tile_y: i32,
+    // World position where the ray enters the tile
+    pub x: f32,
+    pub y: f32,
+    pub distance: f32,
+    // Side of the tile that was hit, zero when the ray starts inside a solid tile
+    pub normal_x: f32,
+    pub normal_y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TileSweep {
+    // How far the box can move before touching a solid tile
+    pub dx: f32,
+    pub dy: f32,
+    pub hit_x: bool,
+    pub hit_y: bool,
+}
+
+impl TileGridData {
+    // Tiles outside the grid are not solid
+    pub fn is_solid(&self, tile_x: i32, tile_y: i32) -> bool {
+        if tile_x < 0 || tile_y < 0 || tile_x >= self.width as i32 || tile_y >= self.height as i32 {
+            return false;
+        }
+        self.solid
+            .get(tile_x as usize + tile_y as usize * self.width as usize)
+            .is_some_and(|solid| *solid != 0)
+    }
+
+    pub fn is_solid_at(&self, x: f32, y: f32) -> bool {
+        let (tile_x, tile_y) = self.world_to_tile(x, y);
+        self.is_solid(tile_x, tile_y)
+    }
+
+    pub fn world_to_tile(&self, x: f32, y: f32) -> (i32, i32) {
+        (self.tile_x(x), self.tile_y(y))
+    }
+
+    // Top left corner of a tile in world coordinates
+    pub fn tile_to_world(&self, tile_x: i32, tile_y: i32) -> (f32, f32) {
+        (
+            self.x as f32 + tile_x as f32 * self.tile_width as f32,
+            self.y as f32 + tile_y as f32 * self.tile_height as f32,
+        )
+    }
+
+    fn tile_x(&self, x: f32) -> i32 {
+        ((x - self.x as f32) / self.tile_width.max(1) as f32).floor() as i32
+    }
+
+    fn tile_y(&self, y: f32) -> i32 {
+        ((y - self.y as f32) / self.tile_height.max(1) as f32).floor() as i32
+    }
+
+    // Whether a box overlaps any solid tile
+    pub fn overlaps(&self, x: f32, y: f32, width: f32, height: f32) -> bool {
+        let (x0, y0) = self.world_to_tile(x, y);
+        let (x1, y1) = self.world_to_tile(x + width - EDGE_EPSILON, y + height - EDGE_EPSILON);
+        (y0..=y1).any(|tile_y| (x0..=x1).any(|tile_x| self.is_solid(tile_x, tile_y)))
+    }
+
+    // First solid tile along the segment, walking the tiles it crosses in order
+    pub fn raycast(&self, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> Option<TileHit> {
+        let tile_width = self.tile_width.max(1) as f32;
+        let tile_height = self.tile_height.max(1) as f32;
+        // Work in tile units relative to the grid origin
+        let start_x = (from_x - self.x as f32) / tile_width;
+        let start_y = (from_y - self.y as f32) / tile_height;
+        let dx = (to_x - from_x) / tile_width;
+        let dy = (to_y - from_y) / tile_height;
+        let mut tile_x = start_x.floor() as i32;
+        let mut tile_y = start_y.floor() as i32;
+        let (end_x, end_y) = self.world_to_tile(to_x, to_y);
+        let step_x = if dx > 0. { 1 } else { -1 };
+        let step_y = if dy > 0. { 1 } else { -1 };
+        let delta_x = if dx != 0. { (1. / dx).abs() } else { f32::INFINITY };
+        let delta_y = if dy != 0. { (1. / dy).abs() } else { f32::INFINITY };
+        let mut next_x = if dx > 0. {
+            (tile_x as f32 + 1. - start_x) / dx
+        } else if dx < 0. {
+            (start_x - tile_x as f32) / -dx
+        } else {
+            f32::INFINITY
+        };
+        let mut next_y = if dy > 0. {
+            (tile_y as f32 + 1. - start_y) / dy
+        } else if dy < 0. {
+            (start_y - tile_y as f32) / -dy
+        } else {
+            f32::INFINITY
+        };
+        let mut t = 0.;
+        let mut normal = (0., 0.);
+        loop {
+            if self.is_solid(tile_x, tile_y) {
+                let length = ((to_x - from_x).powi(2) + (to_y - from_y).powi(2)).sqrt();
+                return Some(TileHit {
+                    tile_x,
+                    tile_y,
+                    x: from_x + (to_x - from_x) * t,
+                    y: from_y + (to_y - from_y) * t,
+                    distance: length * t,
+                    normal_x: normal.0,
+                    normal_y: normal.1,
+                });
+            }
+            if tile_x == end_x && tile_y == end_y {
+                return None;
+            }
+            if next_x < next_y {
+                t = next_x;
+                next_x += delta_x;
+                tile_x += step_x;
+                normal = (-step_x as f32, 0.);
+            } else {
+                t = next_y;
+                next_y += delta_y;
+                tile_y += step_y;
+                normal = (0., -step_y as f32);
+            }
+            if t > 1. {
+                return None;
+            }
+        }
+    }
+
+    // Move a box by (dx, dy), stopping at solid tiles. Axes are resolved separately, x first,
+    // so the box slides along walls.
+    pub fn sweep(&self, x: f32, y: f32, width: f32, height: f32, dx: f32, dy: f32) -> TileSweep {
+        let (moved_x, hit_x) = self.sweep_x(x, y, width, height, dx);
+        let (moved_y, hit_y) = self.sweep_y(x + moved_x, y, width, height, dy);
+        TileSweep { dx: moved_x, dy: moved_y, hit_x, hit_y }
+    }
+
+    fn sweep_x(&self, x: f32, y: f32, width: f32, height: f32, dx: f32) -> (f32, bool) {
+        let rows = self.tile_y(y)..=self.tile_y(y + height - EDGE_EPSILON);
+        let column_solid = |column: i32| rows.clone().any(|row| self.is_solid(column, row));
+        if dx > 0. {
+            let first = self.tile_x(x + width - EDGE_EPSILON) + 1;
+            let last = self.tile_x(x + width + dx - EDGE_EPSILON);
+            if let Some(column) = (first..=last).find(|column| column_solid(*column)) {
+                return (self.tile_to_world(column, 0).0 - (x + width), true);
+            }
+        } else if dx < 0. {
+            let first = self.tile_x(x) - 1;
+            let last = self.tile_x(x + dx);
+            if let Some(column) = (last..=first).rev().find(|column| column_solid(*column)) {
+                return (self.tile_to_world(column + 1, 0).0 - x, true);
+            }
+        }
+        (dx, false)
+    }
+
+    fn sweep_y(&self, x: f32, y: f32, width: f32, height: f32, dy: f32) -> (f32, bool) {
+        let columns = self.tile_x(x)..=self.tile_x(x + width - EDGE_EPSILON);
+        let row_solid = |row: i32| columns.clone().any(|column| self.is_solid(column, row));
+        if dy > 0. {
+            let first = self.tile_y(y + height - EDGE_EPSILON) + 1;
+            let last = self.tile_y(y + height + dy - EDGE_EPSILON);
+            if let Some(row) = (first..=last).find(|row| row_solid(*row)) {
+                return (self.tile_to_world(0, row).1 - (y + height), true);
+            }
+        } else if dy < 0. {
+            let first = self.tile_y(y) - 1;
+            let last = self.tile_y(y + dy);
+            if let Some(row) = (last..=first).rev().find(|row| row_solid(*row)) {
+                return (self.tile_to_world(0, row + 1).1 - y, true);
+            }
+        }
+        (dy, false)
+    }
+}
+
+impl TileGrid {
+    // Reading the component copies the whole `solid` vec, so take the data once
+    // per frame and run every query on it
+    pub fn data(&self) -> TileGridData {
+        TileGridData {
+            x: self.get_x(),
+            y: self.get_y(),
+            width: self.get_width(),
+            height: self.get_height(),
+            tile_width: self.get_tile_width(),
+            tile_height: self.get_tile_height(),
+            solid: self.get_solid(),
+        }
+    }
+}
+
+impl TilePropertiesData {
+    // Property of the topmost tile with properties at a tile position, in the same tile coordinates as `TileGridData`.
+    // Lower tiles with properties are hidden by the ones above them.
+    pub fn property(&self, tile_x: i32, tile_y: i32, name: &str) -> Option<&serde_json::Value> {
+        if tile_x < 0 || tile_y < 0 || tile_x >= self.width as i32 || tile_y >= self.height as i32 {
+            return None;
+        }
+        let gid = self.tiles.get(tile_x as usize + tile_y as usize * self.width as usize)?;
+        self.properties.get(gid)?.get(name)
+    }
+}
+
+impl TileProperties {
+    // Parses the properties, take the data once and keep it while the cell is loaded
+    pub fn data(&self) -> TilePropertiesData {
+        TilePropertiesData {
+            width: self.get_width(),
+            height: self.get_height(),
+            tiles: self.get_tiles(),
+            properties: serde_json::from_str(&self.get_properties()).unwrap_or_default(),
+        }
+    }
+}
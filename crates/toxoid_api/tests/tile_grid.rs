@@ -0,0 +1,104 @@
+use toxoid_api::*;
+
+// 5x4 tiles of 16x16 with a wall on the right, a floor and a block in the middle
+fn grid() -> TileGridData {
+    TileGridData {
+        x: 0,
+        y: 0,
+        width: 5,
+        height: 4,
+        tile_width: 16,
+        tile_height: 16,
+        solid: vec![
+            0, 0, 0, 0, 1,
+            0, 0, 0, 0, 1,
+            0, 0, 1, 0, 1,
+            1, 1, 1, 1, 1,
+        ],
+    }
+}
+
+fn assert_near(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{} != {}", a, b);
+}
+
+#[test]
+fn raycast_from_inside_solid_tile() {
+    let hit = grid().raycast(40., 40., 0., 0.).unwrap();
+    assert_eq!((hit.tile_x, hit.tile_y), (2, 2));
+    assert_eq!((hit.x, hit.y, hit.distance), (40., 40., 0.));
+    assert_eq!((hit.normal_x, hit.normal_y), (0., 0.));
+}
+
+#[test]
+fn raycast_axis_aligned() {
+    let hit = grid().raycast(8., 8., 100., 8.).unwrap();
+    assert_eq!((hit.tile_x, hit.tile_y), (4, 0));
+    assert_near(hit.x, 64.);
+    assert_near(hit.distance, 56.);
+    assert_eq!((hit.normal_x, hit.normal_y), (-1., 0.));
+
+    let hit = grid().raycast(8., 8., 8., 100.).unwrap();
+    assert_eq!((hit.tile_x, hit.tile_y), (0, 3));
+    assert_near(hit.y, 48.);
+    assert_near(hit.distance, 40.);
+    assert_eq!((hit.normal_x, hit.normal_y), (0., -1.));
+}
+
+#[test]
+fn raycast_diagonal() {
+    let hit = grid().raycast(4., 8., 44., 48.).unwrap();
+    assert_eq!((hit.tile_x, hit.tile_y), (2, 2));
+    assert_near(hit.x, 32.);
+    assert_near(hit.y, 36.);
+    assert_near(hit.distance, 28. * 2f32.sqrt());
+    assert_eq!((hit.normal_x, hit.normal_y), (-1., 0.));
+}
+
+#[test]
+fn raycast_negative_direction() {
+    let hit = grid().raycast(56., 40., 0., 40.).unwrap();
+    assert_eq!((hit.tile_x, hit.tile_y), (2, 2));
+    assert_near(hit.x, 48.);
+    assert_near(hit.distance, 8.);
+    assert_eq!((hit.normal_x, hit.normal_y), (1., 0.));
+    // Leaves the top of the grid without hitting anything
+    assert_eq!(grid().raycast(56., 40., 56., -20.), None);
+}
+
+#[test]
+fn raycast_stops_at_end() {
+    assert_eq!(grid().raycast(8., 8., 40., 8.), None);
+}
+
+#[test]
+fn sweep_flush_against_wall() {
+    // The right edge touches the wall, so the box can't move into it
+    let sweep = grid().sweep(48., 0., 16., 16., 10., 0.);
+    assert_eq!(sweep, TileSweep { dx: 0., dy: 0., hit_x: true, hit_y: false });
+    // Standing on the floor doesn't block moving sideways, only down
+    let sweep = grid().sweep(0., 32., 16., 16., 10., 5.);
+    assert_eq!(sweep, TileSweep { dx: 10., dy: 0., hit_x: false, hit_y: true });
+}
+
+#[test]
+fn sweep_stops_at_tiles() {
+    let sweep = grid().sweep(0., 20., 16., 16., 0., 30.);
+    assert_eq!(sweep, TileSweep { dx: 0., dy: 12., hit_x: false, hit_y: true });
+    let sweep = grid().sweep(0., 32., 16., 16., 20., 0.);
+    assert_eq!(sweep, TileSweep { dx: 16., dy: 0., hit_x: true, hit_y: false });
+}
+
+#[test]
+fn sweep_negative_direction() {
+    let sweep = grid().sweep(50., 32., 10., 10., -20., 0.);
+    assert_eq!(sweep, TileSweep { dx: -2., dy: 0., hit_x: true, hit_y: false });
+    let sweep = grid().sweep(20., 20., 8., 8., 0., -30.);
+    assert_eq!(sweep, TileSweep { dx: 0., dy: -30., hit_x: false, hit_y: false });
+}
+
+#[test]
+fn sweep_slides_along_wall() {
+    let sweep = grid().sweep(48., 0., 16., 16., 10., 10.);
+    assert_eq!(sweep, TileSweep { dx: 0., dy: 10., hit_x: true, hit_y: false });
+}
@@ -900,4 +900,38 @@ fn make_variable_name(t: &Type) -> Ident {
     }
 
     format_ident!("{}", snake_case)
-}
\ No newline at end of file
+}
+// Implements `QueryTerms` for tuples of `QueryTerm`s up to the given arity,
+// the index of each term is its position in the tuple so it always lines up with the generated expression.
+#[proc_macro]
+pub fn impl_query_terms(input: TokenStream) -> TokenStream {
+    let max = parse_macro_input!(input as syn::LitInt)
+        .base10_parse::<usize>()
+        .expect("Expected maximum tuple arity");
+
+    let impls = (1..=max).map(|arity| {
+        let types: Vec<Ident> = (0..arity).map(|i| format_ident!("T{}", i)).collect();
+        let vars: Vec<Ident> = (0..arity).map(|i| format_ident!("term_{}", i)).collect();
+        let indexes: Vec<i8> = (0..arity).map(|i| i as i8).collect();
+        quote! {
+            impl<#(#types: QueryTerm),*> QueryTerms for (#(#types,)*) {
+                type Item = (#(#types::Item,)*);
+
+                fn expr() -> String {
+                    vec![#(#types::term()),*].join(", ")
+                }
+
+                fn fetch(iter: &Iter) -> Vec<Self::Item> {
+                    #(let mut #vars = #types::fetch(iter, #indexes).into_iter();)*
+                    (0..iter.count())
+                        .map(|_| (#(#vars.next().unwrap(),)*))
+                        .collect()
+                }
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        #(#impls)*
+    })
+}
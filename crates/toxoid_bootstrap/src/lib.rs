@@ -14,7 +14,7 @@ pub extern "C" fn init_bootstrap(user_data: *mut core::ffi::c_void) {
     // Initialize renderer
     toxoid_sokol::sokol_init();
     
-    // Enable render systems
+    // Enable render phases
     let render_systems = World::get_singleton::<RenderSystems>();
     let mut entity = Entity::from_id(render_systems.get_entity());
     entity.enable();
@@ -44,11 +44,7 @@ pub fn init(init_host: extern "C" fn()) {
     // Initialize systems
     systems::init();
 
-    // TODO: Possibly change this when we have Flecs system phases
-    // so that we can dynamically enable / disable systems and the ECS
-    // so initialization order vs the decoupled renderer is not 
-    // nessecarily an issue. (This is already implmented, but should now be implemented in a threaded way with decoupling).
-    // Also want to make sure we're running on different loops / threads 
-    // for gameplay logic (ECS) vs rendering (Sokol + ECS with renderer phase tagged systems)
+    // Render systems run in their own phases (see `systems::render::init`),
+    // which stay disabled until the renderer is initialized in `init_bootstrap`.
     renderer::init(init_host);
 }
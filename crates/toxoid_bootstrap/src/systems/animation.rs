@@ -0,0 +1,345 @@
+use toxoid_api::*;
+use toxoid_sokol::{bindings::*, SokolRenderTarget, SokolRenderer2D, SokolSprite, sapp};
+use toxoid_render::Renderer2D;
+use std::ffi::{CStr, CString};
+
+// Frames shown for this many milliseconds when a sheet doesn't say
+const DEFAULT_FRAME_DURATION: f32 = 100.;
+
+pub struct SheetFrame {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    // Milliseconds
+    pub duration: f32,
+}
+
+pub struct SpriteSheet {
+    pub frames: Vec<SheetFrame>,
+}
+
+// Aseprite tag, played as an animation clip
+pub struct SheetTag {
+    pub name: String,
+    pub from: u32,
+    pub to: u32,
+    pub mode: AnimationMode,
+    pub reverse: bool,
+}
+
+impl SpriteSheet {
+    // Cut an image into frames row by row
+    pub fn grid(image_width: u32, image_height: u32, frame_width: u32, frame_height: u32, duration: f32) -> Self {
+        let frame_width = if frame_width > 0 { frame_width } else { image_width };
+        let frame_height = if frame_height > 0 { frame_height } else { image_height };
+        let duration = if duration > 0. { duration } else { DEFAULT_FRAME_DURATION };
+        let columns = image_width / frame_width.max(1);
+        let rows = image_height / frame_height.max(1);
+        let frames = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .map(|(column, row)| SheetFrame {
+                x: column * frame_width,
+                y: row * frame_height,
+                width: frame_width,
+                height: frame_height,
+                duration,
+            })
+            .collect();
+        SpriteSheet { frames }
+    }
+
+    // Aseprite JSON exports, with frames as a hash or an array. Returns the tags and the sheet image path.
+    pub fn parse_aseprite(json: &[u8]) -> Result<(Self, Vec<SheetTag>, String), String> {
+        let json: serde_json::Value = serde_json::from_slice(json).map_err(|error| error.to_string())?;
+        let frames = match &json["frames"] {
+            // Hash frames are in frame order, serde_json keeps the file's key order with `preserve_order`
+            serde_json::Value::Object(frames) => frames.values().collect::<Vec<_>>(),
+            serde_json::Value::Array(frames) => frames.iter().collect(),
+            _ => return Err("no frames".to_string()),
+        };
+        let frames = frames
+            .into_iter()
+            .map(|frame| {
+                let rect = &frame["frame"];
+                let value = |key: &str| rect[key].as_u64().map(|value| value as u32).ok_or_else(|| format!("frame has no `{}`", key));
+                Ok(SheetFrame {
+                    x: value("x")?,
+                    y: value("y")?,
+                    width: value("w")?,
+                    height: value("h")?,
+                    duration: frame["duration"].as_f64().map_or(DEFAULT_FRAME_DURATION, |duration| duration as f32),
+                })
+            })
+            .collect::<Result<Vec<SheetFrame>, String>>()?;
+        let tags = json["meta"]["frameTags"]
+            .as_array()
+            .map(|tags| {
+                tags.iter()
+                    .map(|tag| {
+                        let direction = tag["direction"].as_str().unwrap_or("forward");
+                        // Tags that repeat a fixed number of times are played once
+                        let mode = if direction.starts_with("pingpong") {
+                            AnimationMode::PingPong
+                        } else if tag["repeat"].is_null() {
+                            AnimationMode::Loop
+                        } else {
+                            AnimationMode::Once
+                        };
+                        SheetTag {
+                            name: tag["name"].as_str().unwrap_or_default().to_string(),
+                            from: tag["from"].as_u64().unwrap_or(0) as u32,
+                            to: tag["to"].as_u64().unwrap_or(0) as u32,
+                            mode,
+                            reverse: direction == "reverse" || direction == "pingpong_reverse",
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let image = json["meta"]["image"].as_str().ok_or("no image")?.to_string();
+        Ok((SpriteSheet { frames }, tags, image))
+    }
+
+    // Size of the largest frame, the size of the animation's render target
+    pub fn frame_size(&self) -> (u32, u32) {
+        self.frames
+            .iter()
+            .fold((0, 0), |(width, height), frame| (width.max(frame.width), height.max(frame.height)))
+    }
+}
+
+// Next frame of a clip, None when a clip that doesn't loop is over
+fn next_frame(frame: u32, from: u32, to: u32, mode: AnimationMode, reverse: &mut bool) -> Option<u32> {
+    let next = if *reverse { frame.checked_sub(1) } else { Some(frame + 1) };
+    match next.filter(|next| *next >= from && *next <= to) {
+        Some(next) => Some(next),
+        None => match mode {
+            AnimationMode::Loop => Some(if *reverse { to } else { from }),
+            AnimationMode::PingPong => {
+                *reverse = !*reverse;
+                Some(if *reverse { frame.saturating_sub(1).max(from) } else { (frame + 1).min(to) })
+            }
+            AnimationMode::Once => None,
+        },
+    }
+}
+
+// Clip range and mode of the requested animation, every frame in a loop without a matching clip
+fn find_clip(animation_entity: &Entity, name: &str, frame_count: u32) -> (u32, u32, AnimationMode, bool) {
+    let last = frame_count.saturating_sub(1);
+    if !name.is_empty() {
+        for mut child in animation_entity.children() {
+            if !child.has::<AnimationClip>() {
+                continue;
+            }
+            let clip = child.get::<AnimationClip>();
+            if clip.get_name() == name {
+                let to = clip.get_to().min(last);
+                return (clip.get_from().min(to), to, AnimationMode::from_u8(clip.get_mode()), clip.get_reverse());
+            }
+        }
+    }
+    (0, last, AnimationMode::Loop, false)
+}
+
+// Advance sprite sheet animations and blit the current frame when it changes
+#[components(FrameByFrameAnimation, Sprite, _, RenderTarget)]
+pub fn animate_frames_system(iter: &Iter) {
+    let delta_ms = (sapp::frame_duration() * 1000.) as f32;
+    let mut entities = iter.entities();
+    for (i, (animation, sprite, rt)) in components.into_iter().enumerate() {
+        let animation_entity = entities.get_mut(i).unwrap();
+        if animation.get_sheet() == 0 || sprite.get_sprite() == 0 {
+            continue;
+        }
+        let sheet = unsafe { &*(animation.get_sheet() as *const SpriteSheet) };
+        if sheet.frames.is_empty() {
+            continue;
+        }
+        let name = animation.get_animation();
+        let (from, to, mode, reverse) = find_clip(animation_entity, &name, sheet.frames.len() as u32);
+
+        // Start the clip over when another one is requested
+        let mut frame = animation.get_frame();
+        let mut elapsed = animation.get_elapsed();
+        let mut playing_reverse = animation.get_reverse();
+        if animation.get_playing() != name || frame < from || frame > to {
+            animation.set_playing(name);
+            frame = if reverse { to } else { from };
+            elapsed = 0.;
+            playing_reverse = reverse;
+            animation_entity.remove::<AnimationFinished>();
+        }
+
+        if !animation.get_paused() && !animation_entity.has::<AnimationFinished>() {
+            let speed = if animation.get_speed() > 0. { animation.get_speed() } else { 1. };
+            elapsed += delta_ms * speed;
+            loop {
+                let duration = sheet.frames[frame as usize].duration.max(1.);
+                if elapsed < duration {
+                    break;
+                }
+                elapsed -= duration;
+                match next_frame(frame, from, to, mode, &mut playing_reverse) {
+                    Some(next) => frame = next,
+                    None => {
+                        elapsed = 0.;
+                        animation_entity.add::<AnimationFinished>();
+                        break;
+                    }
+                }
+            }
+        }
+        animation.set_frame(frame);
+        animation.set_elapsed(elapsed);
+        animation.set_reverse(playing_reverse);
+
+        if animation.get_blitted_frame() == frame + 1 {
+            continue;
+        }
+        animation.set_blitted_frame(frame + 1);
+        let sheet_frame = &sheet.frames[frame as usize];
+        let sprite_box = unsafe { Box::from_raw(sprite.get_sprite() as *mut SokolSprite) };
+        let sprite_trait_object: Box<dyn toxoid_render::Sprite> = sprite_box;
+        let rt_box = unsafe { Box::from_raw(rt.get_render_target() as *mut SokolRenderTarget) };
+        let rt_trait_object: Box<dyn toxoid_render::RenderTarget> = rt_box;
+        let (rt_width, rt_height) = sheet.frame_size();
+        SokolRenderer2D::begin_rt(&rt_trait_object, rt_width as f32, rt_height as f32);
+        SokolRenderer2D::blit_sprite(
+            &sprite_trait_object,
+            sheet_frame.x as f32, sheet_frame.y as f32, sheet_frame.width as f32, sheet_frame.height as f32,
+            &rt_trait_object,
+            0., 0.
+        );
+        SokolRenderer2D::end_rt();
+        // The sprite and render target are owned by their components
+        Box::into_raw(sprite_trait_object);
+        Box::into_raw(rt_trait_object);
+    }
+}
+
+pub fn spine_string(string: &sspine_string) -> String {
+    unsafe { CStr::from_ptr(string.cstr.as_ptr()) }.to_string_lossy().into_owned()
+}
+
+fn find_bone_animation(skeleton: sspine_skeleton, name: &str) -> Option<sspine_anim> {
+    let name = CString::new(name).ok()?;
+    let anim = unsafe { sspine_anim_by_name(skeleton, name.as_ptr()) };
+    unsafe { sspine_anim_valid(anim) }.then_some(anim)
+}
+
+fn find_skin(skeleton: sspine_skeleton, name: &str) -> Option<sspine_skin> {
+    let name = CString::new(name).ok()?;
+    let skin = unsafe { sspine_skin_by_name(skeleton, name.as_ptr()) };
+    unsafe { sspine_skin_valid(skin) }.then_some(skin)
+}
+
+// Events live for a single frame, delete last frame's before bone animations forward new ones
+pub fn clear_animation_events_system(_iter: &Iter) {
+    World::delete_with::<AnimationEvent>();
+}
+
+// Apply `BoneAnimation` changes to the Spine instance, advance it and forward the events it triggered
+#[components(BoneAnimation, Skeleton, SpineInstance)]
+pub fn animate_bones_system(iter: &Iter) {
+    let delta_time = sapp::frame_duration() as f32;
+    let mut entities = iter.entities();
+    for (i, (animation, skeleton, spine_instance)) in components.into_iter().enumerate() {
+        let animation_entity = entities.get_mut(i).unwrap();
+        if !spine_instance.get_instantiated() || skeleton.get_skeleton() == 0 {
+            continue;
+        }
+        let instance = unsafe { *(spine_instance.get_instance() as *const sspine_instance) };
+        let spine_skeleton = unsafe { *(skeleton.get_skeleton() as *const sspine_skeleton) };
+        let track = animation.get_track() as i32;
+
+        let skin = animation.get_skin();
+        if skin != animation.get_playing_skin() {
+            animation.set_playing_skin(skin.clone());
+            if !skin.is_empty() {
+                match find_skin(spine_skeleton, &skin) {
+                    Some(spine_skin) => unsafe { sspine_set_skin(instance, spine_skin) },
+                    None => println!("Bone animation has no skin {}", skin),
+                }
+            }
+        }
+
+        let name = animation.get_animation();
+        if name != animation.get_playing() {
+            let previous = animation.get_playing();
+            animation.set_playing(name.clone());
+            if name.is_empty() {
+                unsafe { sspine_set_empty_animation(instance, track, animation.get_mix()) };
+            } else if let Some(anim) = find_bone_animation(spine_skeleton, &name) {
+                unsafe {
+                    if track > 0 && previous.is_empty() {
+                        // Tracks above 0 are layered over track 0, mix them in from the setup pose
+                        sspine_set_empty_animation(instance, track, 0.);
+                        sspine_add_animation(instance, anim, track, !animation.get_once(), 0.);
+                    } else {
+                        sspine_set_animation(instance, anim, track, !animation.get_once());
+                    }
+                }
+            } else {
+                println!("Bone animation has no animation {}", name);
+            }
+        }
+
+        let queued = animation.get_queued();
+        if !queued.is_empty() {
+            animation.set_queued(String::new());
+            match find_bone_animation(spine_skeleton, &queued) {
+                Some(anim) => {
+                    unsafe { sspine_add_animation(instance, anim, track, !animation.get_queued_once(), animation.get_delay()) };
+                    animation.set_animation(queued.clone());
+                    animation.set_playing(queued);
+                    animation.set_once(animation.get_queued_once());
+                }
+                None => println!("Bone animation has no animation {}", queued),
+            }
+        }
+
+        let speed = if animation.get_speed() > 0. { animation.get_speed() } else { 1. };
+        let delta_time = if animation.get_paused() { 0. } else { delta_time * speed };
+        unsafe { sspine_update_instance(instance, delta_time) };
+
+        let event_count = unsafe { sspine_num_triggered_events(instance) };
+        for index in 0..event_count {
+            let triggered = unsafe { sspine_get_triggered_event_info(instance, index) };
+            if !triggered.valid {
+                continue;
+            }
+            let info = unsafe { sspine_get_event_info(triggered.event) };
+            let mut event_entity = Entity::new(None);
+            event_entity.add::<AnimationEvent>();
+            let event = event_entity.get::<AnimationEvent>();
+            event.set_name(spine_string(&info.name));
+            event.set_int_value(triggered.int_value);
+            event.set_float_value(triggered.float_value);
+            event.set_string_value(spine_string(&triggered.string_value));
+            event.set_time(triggered.time);
+            event_entity.child_of_id(animation_entity.get_id());
+            event_entity.add::<Triggered>();
+        }
+    }
+}
+
+pub fn init() {
+    // No query, clears the events once per frame before the render phases that forward new ones
+    let render_phases = World::get_singleton::<RenderPhases>();
+    System::new(None, clear_animation_events_system)
+        .phase(Phases::Custom(render_phases.get_pre_render()))
+        .build();
+
+    // Free the parsed sheet with its animation
+    Observer::dsl("FrameByFrameAnimation", vec![Event::OnRemove], |iter| {
+        iter.components::<FrameByFrameAnimation>(0).iter().for_each(|animation| {
+            if animation.get_sheet() != 0 {
+                drop(unsafe { Box::from_raw(animation.get_sheet() as *mut SpriteSheet) });
+                animation.set_sheet(0);
+            }
+        });
+    })
+        .build();
+}
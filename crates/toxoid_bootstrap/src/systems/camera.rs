@@ -0,0 +1,145 @@
+use toxoid_api::*;
+use toxoid_sokol::sapp;
+use rand::Rng;
+use std::sync::Mutex;
+
+// Size of the camera view at zoom 1 and the zoom, falls back to the size of the camera's render target or the game
+pub fn camera_view(camera_entity: &mut Entity) -> (f32, f32, f32) {
+    let game_config = World::get_singleton::<GameConfig>();
+    let (mut width, mut height, mut zoom) = (game_config.get_game_width() as f32, game_config.get_game_height() as f32, 1.);
+    if camera_entity.has::<Camera>() {
+        let camera = camera_entity.get::<Camera>();
+        if let Some(mut rt_entity) = World::try_get_entity(camera.get_render_target()).filter(|rt_entity| rt_entity.has::<Size>()) {
+            let size = rt_entity.get::<Size>();
+            width = size.get_width() as f32;
+            height = size.get_height() as f32;
+        }
+        if camera.get_viewport_width() > 0. && camera.get_viewport_height() > 0. {
+            width = camera.get_viewport_width();
+            height = camera.get_viewport_height();
+        }
+        if camera.get_zoom() > 0. {
+            zoom = camera.get_zoom();
+        }
+    }
+    (width, height, zoom)
+}
+
+// Extents of every loaded Tiled world as (x0, y0, x1, y1), set when a world loads instead of querying the worlds every frame
+static WORLD_BOUNDS: Mutex<Option<(f32, f32, f32, f32)>> = Mutex::new(None);
+
+// Grow the world bounds by the cells of a Tiled world that finished loading
+pub fn add_world_bounds(tiled_world: &toxoid_tiled::TiledWorld) {
+    let mut bounds = WORLD_BOUNDS.lock().unwrap();
+    for cell in tiled_world.maps.iter().flatten() {
+        let (x0, y0) = (cell.x as f32, cell.y as f32);
+        let (x1, y1) = (x0 + cell.width as f32, y0 + cell.height as f32);
+        *bounds = Some(match *bounds {
+            Some((bx0, by0, bx1, by1)) => (bx0.min(x0), by0.min(y0), bx1.max(x1), by1.max(y1)),
+            None => (x0, y0, x1, y1),
+        });
+    }
+}
+
+// Extents of every loaded Tiled world as (x, y, width, height)
+fn tiled_world_bounds() -> Option<(f32, f32, f32, f32)> {
+    WORLD_BOUNDS.lock().unwrap().map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0, y1 - y0))
+}
+
+// Keep a view of `size` centered at `center` inside the bounds, centered on the bounds when they're smaller
+fn clamp_center(center: f32, size: f32, min: f32, extent: f32) -> f32 {
+    if extent <= size {
+        min + extent / 2.
+    } else {
+        center.clamp(min + size / 2., min + extent - size / 2.)
+    }
+}
+
+// Follow, bounds and shake for cameras. The camera `Position` is the top left of the view at zoom 1,
+// zoom is applied around the view center when drawing.
+#[components(Camera, Position)]
+pub fn camera_system(iter: &Iter) {
+    let delta_time = sapp::frame_duration() as f32;
+    let mut entities = iter.entities();
+    let world_bounds = tiled_world_bounds();
+    for (i, (camera, position)) in components.into_iter().enumerate() {
+        let camera_entity = entities.get_mut(i).unwrap();
+        let (view_width, view_height, zoom) = camera_view(camera_entity);
+        let mut center_x = position.get_x() as f32 + view_width / 2.;
+        let mut center_y = position.get_y() as f32 + view_height / 2.;
+
+        if camera_entity.has::<CameraFollow>() {
+            let follow = camera_entity.get::<CameraFollow>();
+            if !follow.get_initialized() {
+                follow.set_x(center_x);
+                follow.set_y(center_y);
+                follow.set_initialized(true);
+            }
+            center_x = follow.get_x();
+            center_y = follow.get_y();
+            if let Some(mut target) = World::try_get_entity(follow.get_target()).filter(|target| target.has::<Position>()) {
+                let target_position = target.get::<Position>();
+                let (target_width, target_height) = if target.has::<Size>() {
+                    let size = target.get::<Size>();
+                    (size.get_width() as f32, size.get_height() as f32)
+                } else {
+                    (0., 0.)
+                };
+                let target_x = target_position.get_x() as f32 + target_width / 2.;
+                let target_y = target_position.get_y() as f32 + target_height / 2.;
+                // Only move when the target leaves the deadzone, just enough to put it back on the edge
+                let half_deadzone_x = follow.get_deadzone_width() / 2.;
+                let half_deadzone_y = follow.get_deadzone_height() / 2.;
+                let desired_x = center_x + (target_x - center_x).signum() * ((target_x - center_x).abs() - half_deadzone_x).max(0.);
+                let desired_y = center_y + (target_y - center_y).signum() * ((target_y - center_y).abs() - half_deadzone_y).max(0.);
+                // Frame rate independent smoothing, a lerp of 0 or 1 snaps to the target
+                let lerp = follow.get_lerp();
+                let t = if lerp <= 0. || lerp >= 1. { 1. } else { 1. - (1. - lerp).powf(delta_time * 60.) };
+                center_x += (desired_x - center_x) * t;
+                center_y += (desired_y - center_y) * t;
+            }
+        }
+
+        // Clamp the zoomed view to the world
+        let bounds = if camera_entity.has::<CameraBounds>() {
+            let bounds = camera_entity.get::<CameraBounds>();
+            Some((bounds.get_x() as f32, bounds.get_y() as f32, bounds.get_width() as f32, bounds.get_height() as f32))
+        } else {
+            world_bounds
+        };
+        if let Some((bounds_x, bounds_y, bounds_width, bounds_height)) = bounds {
+            center_x = clamp_center(center_x, view_width / zoom, bounds_x, bounds_width);
+            center_y = clamp_center(center_y, view_height / zoom, bounds_y, bounds_height);
+        }
+        if camera_entity.has::<CameraFollow>() {
+            let follow = camera_entity.get::<CameraFollow>();
+            follow.set_x(center_x);
+            follow.set_y(center_y);
+        }
+        position.set_x((center_x - view_width / 2.).round() as i32);
+        position.set_y((center_y - view_height / 2.).round() as i32);
+
+        // Shake fades out linearly and stops once its duration is over
+        let (mut shake_x, mut shake_y) = (0., 0.);
+        if camera_entity.has::<CameraShake>() {
+            let shake = camera_entity.get::<CameraShake>();
+            let elapsed = shake.get_elapsed() + delta_time;
+            if elapsed >= shake.get_duration() {
+                camera_entity.remove::<CameraShake>();
+            } else {
+                shake.set_elapsed(elapsed);
+                let strength = shake.get_intensity() * (1. - elapsed / shake.get_duration());
+                let mut rng = rand::thread_rng();
+                shake_x = rng.gen_range(-1.0..=1.0) * strength;
+                shake_y = rng.gen_range(-1.0..=1.0) * strength;
+            }
+        }
+        camera.set_shake_x(shake_x);
+        camera.set_shake_y(shake_y);
+    }
+}
+
+pub fn init() {
+    System::dsl("Camera, Position", None, camera_system)
+        .build();
+}
@@ -1,7 +1,18 @@
 use toxoid_api::*;
 use toxoid_render::Renderer2D;
-use toxoid_sokol::{bindings::*, SokolRenderer2D};
+use toxoid_sokol::{bindings::*, atlas, SokolRenderer2D};
 use crate::prefabs::create_render_target; 
+use super::animation::{SpriteSheet, spine_string};
+use std::sync::Mutex;
+
+// Sprite atlases that are still loading and the sprite fetch requests waiting for them,
+// so sprites that are frames of an atlas use its page even when they're loaded right after it
+struct AtlasQueue {
+    loading: u32,
+    sprites: Vec<u64>,
+}
+
+static ATLAS_QUEUE: Mutex<AtlasQueue> = Mutex::new(AtlasQueue { loading: 0, sprites: Vec::new() });
 
 #[no_mangle]
 pub extern "C" fn fetch_callback(response: *const sfetch_response_t) {
@@ -19,6 +30,10 @@ pub extern "C" fn fetch_callback(response: *const sfetch_response_t) {
     fetch_request.set_data(data);
     entity.remove::<Loading>();
     entity.add::<Loaded>();
+    // The data was copied, free the buffer allocated in `sokol_fetch`
+    if response.finished {
+        drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(response.buffer.ptr as *mut u8, response.buffer.size)) });
+    }
 }
 
 fn sokol_fetch(path: &str, entity: &mut Entity) {
@@ -43,8 +58,9 @@ fn sokol_fetch(path: &str, entity: &mut Entity) {
     sfetch_request.callback = Some(fetch_callback);
     // Store entity in the user data / ctx of request so that
     // we can associate the entity with the fetch request / response
-    let entity_id = Box::into_raw(Box::new(entity.get_id()));
-    let ptr = entity_id as *mut core::ffi::c_void;
+    // sokol fetch copies the user data, so it can live on the stack
+    let entity_id = entity.get_id();
+    let ptr = &entity_id as *const u64 as *mut core::ffi::c_void;
     let size = core::mem::size_of::<u64>();
     sfetch_request.user_data = sfetch_range_t {
         ptr,
@@ -53,6 +69,57 @@ fn sokol_fetch(path: &str, entity: &mut Entity) {
     unsafe { sfetch_send(&sfetch_request) };
 }
 
+// Set the sprite of a loaded sprite entity and create the render target it's blitted to
+fn sprite_loaded(sprite_entity: &mut Entity, sokol_sprite: Box<dyn toxoid_render::Sprite>) {
+    let sprite_width = sokol_sprite.width();
+    let sprite_height = sokol_sprite.height();
+    // Set size
+    let size = sprite_entity.get::<Size>();
+    size.set_width(sprite_width);
+    size.set_height(sprite_height);
+    // Set sprite
+    let sprite = sprite_entity.get::<Sprite>();
+    sprite.set_sprite(Box::into_raw(sokol_sprite) as *mut () as u64);
+    sprite_entity.add::<Blittable>();
+    // Create render target entity
+    let mut rt_entity = create_render_target(sprite_width, sprite_height);
+    sprite_entity.child_of_id(rt_entity.get_id());
+    // Create renderable entity
+    if sprite_entity.has::<RenderableOnLoad>() {
+        rt_entity.add::<Renderable>();
+    }
+    sprite_entity.add::<Loaded>();
+}
+
+// Sprites in a loaded atlas are regions of its page, there's nothing to fetch
+fn fetch_sprite(entity: &mut Entity) {
+    let fetch_request = entity.get::<FetchRequest>();
+    let path = fetch_request.get_path();
+    if let Some(sokol_sprite) = atlas::sprite(&path) {
+        entity.remove::<Loading>();
+        sprite_loaded(&mut Entity::from_id(fetch_request.get_user_data()), Box::new(sokol_sprite));
+        return;
+    }
+    sokol_fetch(&path, entity);
+}
+
+// An atlas finished loading or failed to, fetch the queued sprites once no atlas is loading
+fn sprite_atlas_done() {
+    let sprites = {
+        let mut queue = ATLAS_QUEUE.lock().unwrap();
+        queue.loading = queue.loading.saturating_sub(1);
+        if queue.loading > 0 {
+            return;
+        }
+        std::mem::take(&mut queue.sprites)
+    };
+    for id in sprites {
+        if let Some(mut entity) = World::try_get_entity(id) {
+            fetch_sprite(&mut entity);
+        }
+    }
+}
+
 // #[cfg(feature = "spine")]
 pub fn bone_animation_loaded(entity: &mut Entity) {
     // Create spine atlas object from loaded atlas data.
@@ -102,10 +169,15 @@ pub fn bone_animation_loaded(entity: &mut Entity) {
     instance_component.set_instance(Box::into_raw(Box::new(instance)) as u64);
     instance_component.set_instantiated(true);
 
-    // configure a simple animation sequence
-
-    unsafe { sspine_add_animation(instance, sspine_anim_by_name(spine_skeleton, c_string("idle_down")), 0, true, 0.) };
-    unsafe { sspine_set_animation(instance, sspine_anim_by_name(spine_skeleton, c_string("idle_down")), 0, true) };
+    // Play the skeleton's first animation unless one was requested while loading,
+    // animate_bones_system starts it on the instance
+    let bone_animation = entity.get::<BoneAnimation>();
+    if bone_animation.get_animation().is_empty() {
+        let anim_info = unsafe { sspine_get_anim_info(sspine_anim_by_index(spine_skeleton, 0)) };
+        if anim_info.valid {
+            bone_animation.set_animation(spine_string(&anim_info.name));
+        }
+    }
 
     let atlas_images_num = unsafe { sspine_num_images(spine_atlas) };
 
@@ -158,13 +230,88 @@ pub fn bone_animation_loaded(entity: &mut Entity) {
     entity.add::<Blittable>();
 }
 
+// Collision and property grids of a cell, once every tileset of the cell has resolved
+fn set_tile_data(cell_entity: &mut Entity, cell: &toxoid_tiled::TiledCell) {
+    let (min_x, min_y, grid_width, grid_height) = cell.tile_bounds();
+    let (cell_x, cell_y) = if cell_entity.has::<Position>() {
+        let cell_position = cell_entity.get::<Position>();
+        (cell_position.get_x(), cell_position.get_y())
+    } else {
+        (0, 0)
+    };
+    // Solid tiles from collision layers and tiles with collision shapes
+    cell_entity.add::<TileGrid>();
+    let tile_grid = cell_entity.get::<TileGrid>();
+    tile_grid.set_x(cell_x + min_x * cell.tilewidth as i32);
+    tile_grid.set_y(cell_y + min_y * cell.tileheight as i32);
+    tile_grid.set_width(grid_width);
+    tile_grid.set_height(grid_height);
+    tile_grid.set_tile_width(cell.tilewidth);
+    tile_grid.set_tile_height(cell.tileheight);
+    tile_grid.set_solid(cell.collision_grid());
+    // Tile properties for guests, which can't read the parsed cell
+    let property_grid = cell.property_grid();
+    let properties = property_grid
+        .iter()
+        .filter(|gid| **gid != 0)
+        .filter_map(|gid| Some((gid.to_string(), cell.tile(*gid)?.properties_json())))
+        .collect::<serde_json::Map<_, _>>();
+    cell_entity.add::<TileProperties>();
+    let tile_properties = cell_entity.get::<TileProperties>();
+    tile_properties.set_width(grid_width);
+    tile_properties.set_height(grid_height);
+    tile_properties.set_tiles(property_grid.into_iter().map(u64::from).collect());
+    tile_properties.set_properties(serde_json::Value::Object(properties).to_string());
+}
+
+// Reload scenes when their script file changes on disk
+#[cfg(not(target_arch = "wasm32"))]
+pub fn scene_reload_system(iter: &Iter) {
+    iter.entities().iter_mut().for_each(|entity| {
+        let scene = entity.get::<Scene>();
+        let modified = std::fs::metadata(scene.get_path())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|modified| modified.as_secs())
+            .unwrap_or(0);
+        if modified == 0 || modified == scene.get_modified() {
+            return;
+        }
+        // The first check only records the time, the initial load is already in flight
+        if scene.get_modified() != 0 {
+            toxoid_api::reload_scene(entity);
+        }
+        scene.set_modified(modified);
+    });
+}
+
 // Fetch Observers
 pub fn init() {
+    #[cfg(not(target_arch = "wasm32"))]
+    System::dsl("Scene", None, scene_reload_system)
+        .interval(1.0)
+        .build();
+
     Observer::dsl("FetchRequest, Loading", vec![Event::OnAdd], |iter| {
         iter.entities().iter_mut().for_each(|entity| {
             let fetch_request = entity.get::<FetchRequest>();
-            let path = fetch_request.get_path();
-            sokol_fetch(&path, entity);
+            let data_type = fetch_request.get_data_type();
+            if data_type == DataType::SpriteAtlas as u8 {
+                ATLAS_QUEUE.lock().unwrap().loading += 1;
+            }
+            if data_type == DataType::Sprite as u8 {
+                // Wait for the atlases that are loading, the sprite may be one of their frames
+                let mut queue = ATLAS_QUEUE.lock().unwrap();
+                if queue.loading > 0 {
+                    queue.sprites.push(entity.get_id());
+                    return;
+                }
+                drop(queue);
+                fetch_sprite(entity);
+                return;
+            }
+            sokol_fetch(&fetch_request.get_path(), entity);
         });
     })
         .build();
@@ -175,6 +322,14 @@ pub fn init() {
             let data = fetch_request.get_data();
             let data_type = fetch_request.get_data_type();
             let size = data.len() as usize;
+            // The entity the data was fetched for may be gone by now, e.g. a cell that was unloaded
+            let user_data = fetch_request.get_user_data();
+            if user_data != 0 && World::try_get_entity(user_data).is_none() {
+                if data_type == DataType::SpriteAtlas as u8 || data_type == DataType::SpriteAtlasPage as u8 {
+                    sprite_atlas_done();
+                }
+                return;
+            }
             match data_type as u8 {
                 d if d == DataType::BoneAnimationImage as u8 => {
                     let mut image_entity = Entity::from_id(fetch_request.get_user_data());
@@ -225,29 +380,93 @@ pub fn init() {
                 d if d == DataType::Sprite as u8 => {
                     // Create entity from entity ID passed to user data
                     let mut sprite_entity = Entity::from_id(fetch_request.get_user_data());
-                    // Get data
-                    let data_box = data.into_boxed_slice();
-                    let data_ptr = Box::into_raw(data_box);
-                    // Create sokol sprite
-                    let sokol_sprite = SokolRenderer2D::create_sprite(data_ptr as *const u8, size);
-                    let sprite_width = sokol_sprite.width();
-                    let sprite_height = sokol_sprite.height();
-                    // Set size
-                    let size = sprite_entity.get::<Size>();
-                    size.set_width(sprite_width);
-                    size.set_height(sprite_height);
+                    // Create sokol sprite, the pixels are copied so the data can be dropped
+                    let sokol_sprite = SokolRenderer2D::create_sprite(data.as_ptr(), size);
+                    sprite_loaded(&mut sprite_entity, sokol_sprite);
+                },
+                d if d == DataType::SpriteSheet as u8 => {
+                    let mut animation_entity = Entity::from_id(fetch_request.get_user_data());
+                    let sokol_sprite = SokolRenderer2D::create_sprite(data.as_ptr(), size);
+                    let animation = animation_entity.get::<FrameByFrameAnimation>();
+                    // Aseprite sheets are parsed before their image is fetched, grids are cut now
+                    if animation.get_sheet() == 0 {
+                        let sheet = SpriteSheet::grid(
+                            sokol_sprite.width(),
+                            sokol_sprite.height(),
+                            animation.get_frame_width(),
+                            animation.get_frame_height(),
+                            animation.get_frame_duration()
+                        );
+                        animation.set_sheet(Box::into_raw(Box::new(sheet)) as u64);
+                    }
+                    let sheet = unsafe { &*(animation.get_sheet() as *const SpriteSheet) };
+                    let (frame_width, frame_height) = sheet.frame_size();
+                    // Set size of a frame
+                    let size = animation_entity.get::<Size>();
+                    size.set_width(frame_width);
+                    size.set_height(frame_height);
                     // Set sprite
-                    let sprite = sprite_entity.get::<Sprite>();
+                    let sprite = animation_entity.get::<Sprite>();
                     sprite.set_sprite(Box::into_raw(sokol_sprite) as *mut () as u64);
-                    sprite_entity.add::<Blittable>();
-                    // Create render target entity
-                    let mut rt_entity = create_render_target(sprite_width, sprite_height);
-                    sprite_entity.child_of_id(rt_entity.get_id());
-                    // Create renderable entity
-                    if sprite_entity.has::<RenderableOnLoad>() {
+                    // Frames are blitted to a render target of the frame size by the animation system
+                    let mut rt_entity = create_render_target(frame_width, frame_height);
+                    rt_entity.get::<RenderTarget>().set_z_depth(ZDepth::AbovePlayer as u32);
+                    animation_entity.child_of_id(rt_entity.get_id());
+                    if animation_entity.has::<RenderableOnLoad>() {
                         rt_entity.add::<Renderable>();
                     }
-                    sprite_entity.add::<Loaded>();
+                    animation_entity.add::<Loaded>();
+                },
+                d if d == DataType::AsepriteSheet as u8 => {
+                    let mut animation_entity = Entity::from_id(fetch_request.get_user_data());
+                    let (sheet, tags, image) = match SpriteSheet::parse_aseprite(&data) {
+                        Ok(aseprite) => aseprite,
+                        Err(e) => {
+                            println!("Failed to parse Aseprite sheet {}: {}", fetch_request.get_path(), e);
+                            return;
+                        }
+                    };
+                    let animation = animation_entity.get::<FrameByFrameAnimation>();
+                    animation.set_sheet(Box::into_raw(Box::new(sheet)) as u64);
+                    for tag in tags {
+                        add_animation_clip(&animation_entity, &tag.name, tag.from, tag.to, tag.mode, tag.reverse);
+                    }
+                    // The sheet image is relative to the JSON
+                    let image = toxoid_tiled::resolve_path(&fetch_request.get_path(), &image);
+                    fetch(&image, DataType::SpriteSheet, Some(animation_entity.get_id()));
+                },
+                d if d == DataType::SpriteAtlas as u8 => {
+                    let mut atlas_entity = Entity::from_id(fetch_request.get_user_data());
+                    let Some(image) = atlas::manifest_image(&data) else {
+                        println!("Sprite atlas {} has no page image", fetch_request.get_path());
+                        sprite_atlas_done();
+                        return;
+                    };
+                    let sprite_atlas = atlas_entity.get::<SpriteAtlas>();
+                    sprite_atlas.set_manifest(data);
+                    // The page image is relative to the manifest
+                    let image = toxoid_tiled::resolve_path(&fetch_request.get_path(), &image);
+                    fetch(&image, DataType::SpriteAtlasPage, Some(atlas_entity.get_id()));
+                },
+                d if d == DataType::SpriteAtlasPage as u8 => {
+                    let mut atlas_entity = Entity::from_id(fetch_request.get_user_data());
+                    let sokol_sprite = SokolRenderer2D::create_sprite(data.as_ptr(), size);
+                    let page = sokol_sprite.as_any().downcast_ref::<toxoid_sokol::SokolSprite>().unwrap();
+                    let sprite_atlas = atlas_entity.get::<SpriteAtlas>();
+                    // Frames are keyed by their path, relative to the manifest's directory
+                    let path = sprite_atlas.get_path();
+                    let directory = path.rsplit_once('/').map_or("", |(directory, _)| directory);
+                    if let Err(e) = atlas::add_manifest(&sprite_atlas.get_manifest(), page, directory) {
+                        println!("Failed to parse sprite atlas {}: {}", path, e);
+                        sprite_atlas_done();
+                        return;
+                    }
+                    sprite_atlas.set_loaded(true);
+                    // The page is owned by the atlas entity
+                    let sprite = atlas_entity.get::<Sprite>();
+                    sprite.set_sprite(Box::into_raw(sokol_sprite) as *mut () as u64);
+                    atlas_entity.add::<Loaded>();
+                    sprite_atlas_done();
                 },
                 d if d == DataType::BoneAnimationAtlas as u8 => {
                     let mut animation_entity = Entity::from_id(fetch_request.get_user_data());
@@ -271,69 +490,70 @@ pub fn init() {
                     let mut world_entity = Entity::from_id(fetch_request.get_user_data());
                     let world = world_entity.get::<TiledWorld>();
                     let data_str = std::str::from_utf8(data.as_slice()).unwrap();
-                    let tiled_world = toxoid_tiled::parse_world(data_str);
-                    world.set_world(Box::into_raw(Box::new(tiled_world.clone())) as u64);
-                    let world_entity_id = world_entity.get_id();
-                    
-                    tiled_world
-                        .maps
-                        .unwrap()
-                        .iter()
-                        .for_each(|cell| {
-                            let mut cell_entity = toxoid_api::load_cell(format!("assets/{}", cell.file_name).as_str(), true);
-                            cell_entity.child_of_id(world_entity_id);
-                            
-                            // Set cell position
-                            cell_entity.add::<Position>();
-                            let cell_pos = cell_entity.get::<Position>();
-                            cell_pos.set_x(cell.x);
-                            cell_pos.set_y(cell.y);
-                            
-                            // Set cell size
-                            cell_entity.add::<Size>();
-                            let cell_size = cell_entity.get::<Size>();
-                            cell_size.set_width(cell.width);
-                            cell_size.set_height(cell.height);
-
-                            // Add blittable component
-                            cell_entity.add::<Blittable>();
-
-                            // Parent cell to player
-                            let player_singleton = World::get_singleton::<Player>();
-                            let mut player_entity = Entity::from_id(player_singleton.get_entity());
-                            player_entity.child_of_id(cell_entity.get_id());
-                        });
+                    let tiled_world = match toxoid_tiled::parse_world(data_str) {
+                        Ok(tiled_world) => tiled_world,
+                        Err(e) => {
+                            println!("Failed to parse world {}: {}", fetch_request.get_path(), e);
+                            return;
+                        }
+                    };
+                    // Cameras without bounds are kept inside the loaded worlds
+                    super::camera::add_world_bounds(&tiled_world);
+                    // Cells are loaded and unloaded around the camera by `world_streaming_system`
+                    world.set_world(Box::into_raw(Box::new(tiled_world)) as u64);
                 },
                 d if d == DataType::Cell as u8 => {
                     let mut cell_entity = Entity::from_id(fetch_request.get_user_data());
                     let cell = cell_entity.get::<TiledCell>();
                     let data_str = std::str::from_utf8(data.as_slice()).unwrap();
-                    let tiled_cell = toxoid_tiled::parse_cell(data_str);
+                    let tiled_cell = match toxoid_tiled::parse_cell(data_str) {
+                        Ok(tiled_cell) => tiled_cell,
+                        Err(e) => {
+                            println!("Failed to parse cell {}: {}", fetch_request.get_path(), e);
+                            return;
+                        }
+                    };
                     
                     // Add Size component with map dimensions
                     cell_entity.add::<Size>();
                     let size = cell_entity.get::<Size>();
-                    let map_width = tiled_cell.width * tiled_cell.tilewidth;
-                    let map_height = tiled_cell.height * tiled_cell.tileheight;
+                    let (map_width, map_height) = tiled_cell.pixel_size();
                     size.set_width(map_width);
                     size.set_height(map_height);
                     
                     cell.set_cell(Box::into_raw(Box::new(tiled_cell.clone())) as u64);
-                    let tileset = tiled_cell.tilesets.get(0).unwrap();
-                    let mut tileset_entity = toxoid_api::load_tileset(format!("assets/{}", tileset.image.as_str()).as_str(), true);
-                    // cell_entity.add_relationship(Relationship::Custom(TilesetRelationship::get_id()), tileset_entity);
-                    tileset_entity.child_of_id(cell_entity.get_id());
+
+                    let (cell_x, cell_y) = if cell_entity.has::<Position>() {
+                        let cell_position = cell_entity.get::<Position>();
+                        (cell_position.get_x(), cell_position.get_y())
+                    } else {
+                        (0, 0)
+                    };
+                    // External tilesets hold tile data too, so wait for them to resolve
+                    if !tiled_cell.tilesets.iter().any(|tileset| tileset.is_external()) {
+                        set_tile_data(&mut cell_entity, &tiled_cell);
+                    }
+                    // Spawn objects once when the cell loads, not when it's blitted
+                    crate::systems::objects::spawn_objects(&cell_entity, cell_x, cell_y, &tiled_cell);
+                    // Load every tileset the map uses, keyed by asset so the cell can match them to its gids
+                    tiled_cell.tilesets.iter().for_each(|tileset| {
+                        let path = format!("assets/{}", tileset.asset());
+                        let mut tileset_entity = if tileset.is_external() {
+                            toxoid_api::load_tileset_source(&path, true)
+                        } else {
+                            toxoid_api::load_tileset(&path, true)
+                        };
+                        let mut tileset_component = tileset_entity.get::<Tileset>();
+                        tileset_component.set_tileset(tileset.asset().to_string());
+                        cell_entity.add_pair::<TilesetRelationship>(&tileset_entity);
+                        tileset_entity.child_of_id(cell_entity.get_id());
+                    });
                     cell_entity.add::<Blittable>();
-                    // let mut tileset = tileset_entity.get::<Tileset>();
-                    // tileset.set_tileset(Box::into_raw(Box::new(tileset.clone())) as u64);
                 },
                 d if d == DataType::Tileset as u8 => {
                     let mut tileset_entity = Entity::from_id(fetch_request.get_user_data());
-                    // Convert Vec into Box to keep the data alive while creating the sprite
-                    let data_box = data.into_boxed_slice();
-                    let data_ptr = Box::into_raw(data_box);
-                    // Create sokol sprite using the boxed data
-                    let sokol_sprite = SokolRenderer2D::create_sprite(data_ptr as *const u8, size);
+                    // Create sokol sprite, the pixels are copied so the data can be dropped
+                    let sokol_sprite = SokolRenderer2D::create_sprite(data.as_ptr(), size);
                     // Set size
                     let size = tileset_entity.get::<Size>();
                     size.set_width(sokol_sprite.width());
@@ -343,6 +563,52 @@ pub fn init() {
                     sprite.set_sprite(Box::into_raw(sokol_sprite) as *mut () as u64);
                     tileset_entity.add::<Blittable>();
                 },
+                d if d == DataType::TilesetSource as u8 => {
+                    let mut tileset_entity = Entity::from_id(fetch_request.get_user_data());
+                    let data_str = std::str::from_utf8(data.as_slice()).unwrap_or("");
+                    let external = match toxoid_tiled::parse_tileset(data_str) {
+                        Ok(external) => external,
+                        Err(e) => {
+                            println!("Failed to parse tileset {}: {}", fetch_request.get_path(), e);
+                            return;
+                        }
+                    };
+                    // Fill in the cell's tileset entry, then fetch the image like an embedded tileset
+                    let source = tileset_entity.get::<Tileset>().get_tileset();
+                    let mut cell_entity = tileset_entity.parent();
+                    let cell = cell_entity.get::<TiledCell>();
+                    let cell = cell.get_cell() as *mut toxoid_tiled::TiledCell;
+                    let image = unsafe { (*cell).tilesets.iter_mut() }
+                        .find(|tileset| tileset.asset() == source)
+                        .map(|tileset| {
+                            tileset.resolve(external);
+                            tileset.image.clone()
+                        });
+                    if let Some(image) = image {
+                        fetch(&format!("assets/{}", image), DataType::Tileset, Some(tileset_entity.get_id()));
+                    }
+                    if unsafe { !(*cell).tilesets.iter().any(|tileset| tileset.is_external()) } {
+                        set_tile_data(&mut cell_entity, unsafe { &*cell });
+                    }
+                },
+                d if d == DataType::Scene as u8 => {
+                    let mut scene_entity = Entity::from_id(fetch_request.get_user_data());
+                    let scene = scene_entity.get::<Scene>();
+                    // Empty code would delete the scene's entities on reload
+                    let Ok(code) = std::str::from_utf8(data.as_slice()) else {
+                        println!("Scene is not valid UTF-8: {}", scene.get_path());
+                        return;
+                    };
+                    if scene.get_script() == 0 {
+                        // First load creates the script entity that owns the scene's entities
+                        match World::script_init(&scene.get_path(), code) {
+                            Some(script) => scene.set_script(script.get_id()),
+                            None => println!("Failed to evaluate scene: {}", scene.get_path())
+                        }
+                    } else if !World::script_update(&World::get_entity(scene.get_script()), code) {
+                        println!("Failed to reload scene: {}", scene.get_path());
+                    }
+                },
                 _ => {
                     println!("File with unknown data type fetched: {:?}", data_type);
                 }
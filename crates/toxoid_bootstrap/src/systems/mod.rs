@@ -1,5 +1,9 @@
 mod render;
 mod fetch;
+mod objects;
+mod streaming;
+mod camera;
+mod animation;
 use toxoid_api::*;
 
 // Trampoline closure from Rust using C callback and binding_ctx field to call a Rust closure
@@ -53,4 +57,7 @@ pub fn init() {
     }
     render::init();
     fetch::init();
+    streaming::init();
+    camera::init();
+    animation::init();
 }
\ No newline at end of file
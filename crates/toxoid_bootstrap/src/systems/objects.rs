@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use toxoid_api::*;
+
+// Spawn the objects of every object layer in a cell, including the ones in groups
+pub fn spawn_objects(cell_entity: &Entity, cell_x: i32, cell_y: i32, cell: &toxoid_tiled::TiledCell) {
+    fn spawn_layers(cell_entity: &Entity, cell_x: i32, cell_y: i32, layers: &[toxoid_tiled::Layer], names: &mut HashSet<String>) {
+        for layer in layers {
+            if let Some(layers) = &layer.layers {
+                spawn_layers(cell_entity, cell_x, cell_y, layers, names);
+            } else if layer.layer_type == "objectgroup" {
+                layer
+                    .objects
+                    .iter()
+                    .flatten()
+                    .for_each(|object| {
+                        spawn_object(cell_entity, cell_x, cell_y, object, names);
+                    });
+            }
+        }
+    }
+    // Names given to objects of this cell, lookups can't see names set while commands are deferred
+    let mut names = HashSet::new();
+    spawn_layers(cell_entity, cell_x, cell_y, &cell.layers, &mut names);
+}
+
+// Spawn an entity for a Tiled object, as a child of the cell it belongs to.
+// The object `type` (or `class`) names a prefab to instance, custom properties
+// named after a component set that component from their value and an `entity`
+// property holds a serialized Flecs entity.
+pub fn spawn_object(cell_entity: &Entity, cell_x: i32, cell_y: i32, object: &toxoid_tiled::Object, names: &mut HashSet<String>) -> Entity {
+    let prefab = if object.object_type.is_empty() {
+        None
+    } else {
+        World::lookup(&object.object_type)
+    };
+    let mut entity = match prefab {
+        // Only prefabs are instanced, other entities with the name are left alone
+        Some(prefab) if prefab.has_id(get_component_id("flecs.core.Prefab")) => Entity::from_prefab_id(None, prefab.get_id()),
+        Some(_) => {
+            println!("Object {} type {} is not a prefab", object.id, object.object_type);
+            Entity::new(None)
+        },
+        None => Entity::new(None)
+    };
+    entity.child_of_id(cell_entity.get_id());
+    // Names are unique per parent
+    if !object.name.is_empty() && !names.contains(&object.name) && cell_entity.lookup(&object.name).is_none() {
+        names.insert(object.name.clone());
+        entity.set_name(object.name.clone());
+    }
+
+    // Tile objects are anchored at their bottom left corner
+    let y = if object.gid.is_some() { object.y - object.height } else { object.y };
+    entity.add::<Position>();
+    entity.add::<Size>();
+    let mut position = entity.get::<Position>();
+    position.set_x((cell_x as f32 + object.x) as i32);
+    position.set_y((cell_y as f32 + y) as i32);
+    let mut size = entity.get::<Size>();
+    size.set_width(object.width as u32);
+    size.set_height(object.height as u32);
+
+    object
+        .properties
+        .iter()
+        .flatten()
+        .for_each(|property| {
+            if property.name == "entity" {
+                if !entity.from_json(&property.value_json()) {
+                    eprintln!("Failed to parse entity for object {}", object.id);
+                }
+                return;
+            }
+            // The lookup finds any entity with the name, only components are set
+            let component_id = get_component_id(&property.name);
+            if component_id == 0 || !World::get_entity(component_id).has_id(get_component_id("flecs.core.Component")) {
+                return;
+            }
+            // Class properties hold the component members, other values set its only member
+            let json = if property.value.is_class() {
+                property.value_json()
+            } else {
+                match World::get_entity(component_id).children().as_slice() {
+                    [member] => format!("{{\"{}\": {}}}", member.get_name(), property.value_json()),
+                    _ => {
+                        println!("Ignoring {} of object {}, only components with one member can be set from a value", property.name, object.id);
+                        return;
+                    }
+                }
+            };
+            if !entity.set_json(component_id, &json) {
+                eprintln!("Failed to set {} for object {}", property.name, object.id);
+            }
+        });
+    entity
+}
@@ -1,15 +1,15 @@
 use toxoid_api::*;
-use toxoid_sokol::{bindings::*, SokolRenderTarget, SokolRenderer2D, SokolSprite, sapp};
+use toxoid_sokol::{bindings::*, atlas, SokolRenderTarget, SokolRenderer2D, SokolSprite, sapp, sg};
 use toxoid_render::Renderer2D;
+use std::cell::RefCell;
+use std::sync::Mutex;
 
 use crate::prefabs::create_render_target;
 
-// SpineInstance, Position, BoneAnimation
-#[components(SpineInstance, _, _)]
+type BoneAnimationTerms = (&'static SpineInstance,);
+
 pub fn blit_bone_animation_system(iter: &Iter) {
-    let mut entities = iter.entities();
-    for (i, spine_instance) in components.into_iter().enumerate() {
-        let entity = entities.get_mut(i).unwrap();
+    iter.each_entity::<BoneAnimationTerms, _>(|entity, (spine_instance,)| {
         if spine_instance.get_instantiated() {
             unsafe {
                 // Get render target and its spine context
@@ -19,11 +19,8 @@ pub fn blit_bone_animation_system(iter: &Iter) {
                 let rt_ptr_box = Box::from_raw(rt_ptr as *mut SokolRenderTarget);
                 let rt_trait_object: &Box<dyn toxoid_render::RenderTarget> = Box::leak(Box::new(rt_ptr_box as Box<dyn toxoid_render::RenderTarget>));
                 let instance = spine_instance.get_instance() as *mut sspine_instance;
-                
-                // Update spine instance but don't set position
-                sspine_update_instance(*instance, sapp_frame_duration() as f32);
-                
-                // Draw spine instance
+
+                // Draw spine instance, animate_bones_system has already advanced it
                 sspine_draw_instance_in_layer(*instance, 0);
 
                 let (window_width, window_height) = (sapp::width(), sapp::height());
@@ -47,7 +44,7 @@ pub fn blit_bone_animation_system(iter: &Iter) {
                 rt_entity.add::<Renderable>();
             }
         }
-    }
+    });
 }
 
 // Blit sprite to render target
@@ -86,144 +83,200 @@ pub fn blit_cell_system(iter: &Iter) {
     let mut entities = iter.entities();
     for (i, (_cell, _size)) in components.into_iter().enumerate() {
         let cell_entity = entities.get_mut(i).unwrap();
-        let mut children = cell_entity.children();
-        if children.len() > 0 {
-            // TODO: Make this into Flecs Query with custom relationships
-            let mut tileset_entities = children.iter_mut().filter(|child| child.has::<Tileset>() && child.has::<Blittable>()).collect::<Vec<_>>();
-            let mut children = cell_entity.children();
-
-            if tileset_entities.len() > 0 {
-                // Get tileset 
-                let tileset_entity = tileset_entities.get_mut(0).unwrap();
-                let sprite = tileset_entity.get::<Sprite>();
-                
-                let sprite_ptr = sprite.get_sprite();
-                let sprite_box = unsafe { Box::from_raw(sprite_ptr as *mut SokolSprite) };
-                let tileset_sprite: &Box<dyn toxoid_render::Sprite> = Box::leak(Box::new(sprite_box as Box<dyn toxoid_render::Sprite>));
-
-                let cell = cell_entity.get::<TiledCell>();
-                let cell = cell.get_cell() as *mut toxoid_tiled::TiledCell;
-                let pixel_width = unsafe { (*cell).width * (*cell).tilewidth };
-                let pixel_height = unsafe { (*cell).height * (*cell).tileheight };
-                let tile_width = unsafe { (*cell).tilewidth };
-                let tile_height = unsafe { (*cell).tileheight };
-                let position = cell_entity.get::<Position>();
-                let cell_x = position.get_x();
-                let cell_y = position.get_y();
-                let image_width = 4800;
-                // let image_height = 720;
-                let rt = SokolRenderer2D::create_render_target(pixel_width, pixel_height);
-                SokolRenderer2D::begin_rt(&rt, pixel_width as f32, pixel_height as f32);
-                unsafe {
-                    // Set proper blend mode before drawing tiles
-                    (*cell)
-                        .layers
-                        .iter()
-                        .for_each(|layer| {
-                            if layer.layer_type == "group" {
-                                layer
-                                    .layers
-                                    .as_ref()
-                                    .unwrap()
-                                    .iter()
-                                    .for_each(|layer| {
-                            if layer.layer_type == "tilelayer" {
-                                let height = (*cell).height;
-                                let width = (*cell).width;
-                                // Iterate over the tiles in the map
-                                for y in 0..height {
-                                    for x in 0..width {
-                                        // Calculate the position to blit each tile on the render target
-                                        let dest_x = x as f32 * tile_width as f32;
-                                        let dest_y = y as f32 * tile_height as f32;
-                                        let i = x as usize + (y as usize * width as usize);
-                                        let tile_id = layer.data.as_ref().unwrap()[i];
-                                        // Tiled 1-indexes the tile ids
-                                        // and 0 is a special value for an empty tile
-                                        if tile_id == 0 {
-                                            continue;
-                                        }
-                                        // Calculate x and y position of the tile in the tileset
-                                        // Based on the tile id which is the index of the tile in the tileset
-                                        // Assuming the tileset is a single row of tiles
-                                        // You may need to adjust this based on the tileset layout
-                                        // Calculate the source x and y position of the tile in the tileset
-                                        let tileset_width = image_width / tile_width;
-                                        let tileset_x = (tile_id - 1) % tileset_width;
-                                        let tileset_y = (tile_id - 1) / tileset_width;
-                                        let src_x = tileset_x as f32 * tile_width as f32;
-                                        let src_y = tileset_y as f32 * tile_height as f32;
-                                        
-                                        // Blit tile from the tileset to the map's render target
-                                        // Assuming you have a way to determine the source tile's position in the tileset, adjust src_x and src_y accordingly
-                                        SokolRenderer2D::blit_sprite(&tileset_sprite, src_x, src_y, tile_width as f32, tile_height as f32, &rt, dest_x, dest_y);
-                                        
-                                        // Create an entity for each tile
-                                        let mut tile_entity = Entity::new(None);
-                                        tile_entity.add::<Position>();
-                                        let position = tile_entity.get::<Position>();
-                                        position.set_x(dest_x as i32);
-                                        position.set_y(dest_y as i32);
-                                        tile_entity.add::<Size>();
-                                        let size = tile_entity.get::<Size>();
-                                        size.set_width(tile_width);
-                                        size.set_height(tile_height);
-                                        // Add other components as needed, e.g., for collision checks
-                                    }
-                                }
-                            } else if layer.layer_type == "objectgroup" {
-                                layer
-                                    .objects
-                                    .as_ref()
-                                    .unwrap()
-                                    .iter()
-                                    .for_each(|object| {
-                                        object
-                                        .properties
-                                        .as_ref()
-                                        .unwrap()
-                                        .iter()
-                                        .for_each(|property| {
-                                            if property.name == "entity" {
-                                                // println!("Entity: {}", property.value.as_str());
-                                                // toxoid_json_to_entity(
-                                                //     make_c_string(
-                                                //         property.value.as_str()
-                                                //     )
-                                                // );
-                                            }
-                                            // if property.name == "filename" {
-                                                
-                                            // }
-                                            });
-                                        });
-                                    }
-                            });
-                        }
+        let cell = cell_entity.get::<TiledCell>();
+        let cell = cell.get_cell() as *mut toxoid_tiled::TiledCell;
+        // Tilesets that finished loading
+        let mut tileset_entities = cell_entity
+            .targets_of::<TilesetRelationship>()
+            .into_iter()
+            .filter(|tileset| tileset.has::<Blittable>())
+            .collect::<Vec<_>>();
+        // Wait for every tileset of the map, then line their sprites up with the map's tilesets
+        if tileset_entities.len() > 0 && tileset_entities.len() >= unsafe { (*cell).tilesets.len() } {
+            let tileset_sprites = unsafe { &(*cell).tilesets }
+                .iter()
+                .map(|tileset| {
+                    let index = tileset_entities
+                        .iter_mut()
+                        .position(|entity| entity.get::<Tileset>().get_tileset() == tileset.asset())?;
+                    let sprite = tileset_entities[index].get::<Sprite>();
+                    let sprite_ptr = sprite.get_sprite();
+                    let sprite_box = unsafe { Box::from_raw(sprite_ptr as *mut SokolSprite) };
+                    let tileset_sprite: &Box<dyn toxoid_render::Sprite> = Box::leak(Box::new(sprite_box as Box<dyn toxoid_render::Sprite>));
+                    Some((tileset_entities[index].get_id(), tileset_sprite))
+                })
+                .collect::<Vec<Option<(u64, &Box<dyn toxoid_render::Sprite>)>>>();
+
+            let (pixel_width, pixel_height) = unsafe { (*cell).pixel_size() };
+            // Infinite maps can start at negative tile positions
+            let (min_x, min_y, width, height) = unsafe { (*cell).tile_bounds() };
+            let tile_width = unsafe { (*cell).tilewidth };
+            let tile_height = unsafe { (*cell).tileheight };
+            let position = cell_entity.get::<Position>();
+            let cell_x = position.get_x();
+            let cell_y = position.get_y();
+            // Animated tiles get their own render targets. To keep the layer order the cell is split into a render target
+            // per run of layers that ends at a layer with animated tiles, and those tiles are drawn right after their run.
+            let mut z_order = 0;
+            let mut cell_rt: Option<Box<dyn toxoid_render::RenderTarget>> = None;
+            unsafe {
+                for layer in (*cell).tile_layers() {
+                    // Hidden layers, e.g. collision layers, are not drawn
+                    if !layer.visible {
+                        continue;
+                    }
+                    let rt = cell_rt.get_or_insert_with(|| {
+                        let rt = cell_render_target(cell_entity, cell_x, cell_y, pixel_width, pixel_height, z_order);
+                        SokolRenderer2D::begin_rt(&rt, pixel_width as f32, pixel_height as f32);
+                        rt
                     });
+                    let mut animated_tiles: Vec<(f32, f32, toxoid_tiled::TileGid, u64)> = Vec::new();
+                    // Iterate over the tiles in the map
+                    for y in 0..height {
+                        for x in 0..width {
+                            // Calculate the position to blit each tile on the render target
+                            let dest_x = x as f32 * tile_width as f32;
+                            let dest_y = y as f32 * tile_height as f32;
+                            let tile = toxoid_tiled::TileGid::parse(layer.tile_at(min_x + x as i32, min_y + y as i32));
+                            // Tiled 1-indexes the tile ids
+                            // and 0 is a special value for an empty tile
+                            if tile.is_empty() {
+                                continue;
+                            }
+                            // Find the tileset the gid belongs to and the tile's rect in its image
+                            let Some(tileset_index) = (*cell).tileset_index(tile.gid) else {
+                                continue;
+                            };
+                            let Some((tileset_id, tileset_sprite)) = tileset_sprites[tileset_index] else {
+                                continue;
+                            };
+                            let Some((src_x, src_y, src_width, src_height)) = (*cell).tilesets[tileset_index].tile_rect(tile.gid) else {
+                                continue;
+                            };
+                            // Tiles larger than the map grid are aligned to the bottom of their cell
+                            let blit_y = dest_y + tile_height as f32 - src_height as f32;
+                            if (*cell).tilesets[tileset_index].tile(tile.gid).is_some_and(|tile| tile.is_animated()) {
+                                animated_tiles.push((dest_x, blit_y, tile, tileset_id));
+                                continue;
+                            }
+
+                            // Blit tile from the tileset to the map's render target
+                            SokolRenderer2D::blit_sprite_flipped(
+                                tileset_sprite,
+                                src_x as f32, src_y as f32, src_width as f32, src_height as f32,
+                                rt,
+                                dest_x, blit_y,
+                                tile.flip_x, tile.flip_y, tile.flip_diagonal
+                            );
+                        }
+                    }
+                    if animated_tiles.is_empty() {
+                        continue;
+                    }
+                    // Close the run, the next visible layer starts a new render target above the animated tiles
+                    SokolRenderer2D::end_rt();
+                    // The render target is owned by its component
+                    Box::into_raw(cell_rt.take().unwrap());
+                    for (dest_x, dest_y, tile, tileset_id) in animated_tiles {
+                        let tileset = (*cell).tileset_for_gid(tile.gid).unwrap();
+                        let mut tile_entity = create_render_target(tileset.tilewidth, tileset.tileheight);
+                        tile_entity.add::<AnimatedTile>();
+                        let animated_tile = tile_entity.get::<AnimatedTile>();
+                        animated_tile.set_cell(cell_entity.get_id());
+                        animated_tile.set_tileset(tileset_id);
+                        animated_tile.set_gid(tile.gid);
+                        // No frame blitted yet
+                        animated_tile.set_frame(u32::MAX);
+                        animated_tile.set_flip_x(tile.flip_x);
+                        animated_tile.set_flip_y(tile.flip_y);
+                        animated_tile.set_flip_diagonal(tile.flip_diagonal);
+                        let render_target = tile_entity.get::<RenderTarget>();
+                        render_target.set_z_depth(ZDepth::BottomLayer as u32);
+                        render_target.set_z_order(z_order + 1);
+                        let position = tile_entity.get::<Position>();
+                        position.set_x(cell_x + dest_x as i32);
+                        position.set_y(cell_y + dest_y as i32);
+                        tile_entity.child_of_id(cell_entity.get_id());
+                        tile_entity.add::<Renderable>();
+                    }
+                    z_order += 2;
                 }
+            }
+            if let Some(rt) = cell_rt {
                 SokolRenderer2D::end_rt();
+                Box::into_raw(rt);
+            }
 
-                // Get render target entity
-                let mut rt_entity = create_render_target(pixel_width, pixel_height);
-                let render_target = rt_entity.get::<RenderTarget>();
-                // Set render target
-                render_target.set_render_target(Box::leak(rt) as *const _ as *const std::ffi::c_void as u64);
-                // Set z depth
-                render_target.set_z_depth(ZDepth::BottomLayer as u32);
+            // Remove the blittable component
+            cell_entity.remove::<Blittable>();
+        }
+    }
+}
 
-                // Set position
-                let position = rt_entity.get::<Position>();
-                position.set_x(cell_x);
-                position.set_y(cell_y);
+// Render target entity for a run of a cell's layers, deleted with the cell when it's unloaded
+fn cell_render_target(cell_entity: &Entity, x: i32, y: i32, width: u32, height: u32, z_order: u32) -> Box<dyn toxoid_render::RenderTarget> {
+    let mut rt_entity = create_render_target(width, height);
+    let render_target = rt_entity.get::<RenderTarget>();
+    render_target.set_z_depth(ZDepth::BottomLayer as u32);
+    render_target.set_z_order(z_order);
+    let position = rt_entity.get::<Position>();
+    position.set_x(x);
+    position.set_y(y);
+    rt_entity.add::<Renderable>();
+    rt_entity.child_of_id(cell_entity.get_id());
+    unsafe { Box::from_raw(render_target.get_render_target() as *mut SokolRenderTarget) }
+}
 
-                // Add renderable component
-                rt_entity.add::<Renderable>();
-                
-                // Remove the blittable component
-                cell_entity.remove::<Blittable>();
-            }
+// Blit the current frame of animated tiles, only when the frame changes
+#[components(AnimatedTile, RenderTarget)]
+pub fn animate_tiles_system(iter: &Iter) {
+    let delta_ms = (sapp::frame_duration() * 1000.) as f32;
+    for (animated_tile, rt) in components {
+        let mut cell_entity = World::get_entity(animated_tile.get_cell());
+        let cell = cell_entity.get::<TiledCell>();
+        let cell = cell.get_cell() as *const toxoid_tiled::TiledCell;
+        if cell.is_null() {
+            continue;
         }
+        let gid = animated_tile.get_gid();
+        let Some(tileset) = (unsafe { (*cell).tileset_for_gid(gid) }) else {
+            continue;
+        };
+        let Some(tile) = tileset.tile(gid) else {
+            continue;
+        };
+        let duration = tile.animation_duration().max(1) as f32;
+        let elapsed = (animated_tile.get_elapsed() + delta_ms) % duration;
+        animated_tile.set_elapsed(elapsed);
+        let Some(frame) = tile.frame_at(elapsed as u32) else {
+            continue;
+        };
+        if frame == animated_tile.get_frame() {
+            continue;
+        }
+        let Some((src_x, src_y, src_width, src_height)) = tileset.tile_rect(tileset.firstgid + frame) else {
+            continue;
+        };
+        animated_tile.set_frame(frame);
+
+        let mut tileset_entity = World::get_entity(animated_tile.get_tileset());
+        let sprite = tileset_entity.get::<Sprite>();
+        let sprite_box = unsafe { Box::from_raw(sprite.get_sprite() as *mut SokolSprite) };
+        let sprite_trait_object: Box<dyn toxoid_render::Sprite> = sprite_box;
+        let rt_box = unsafe { Box::from_raw(rt.get_render_target() as *mut SokolRenderTarget) };
+        let rt_trait_object: Box<dyn toxoid_render::RenderTarget> = rt_box;
+        SokolRenderer2D::begin_rt(&rt_trait_object, src_width as f32, src_height as f32);
+        SokolRenderer2D::blit_sprite_flipped(
+            &sprite_trait_object,
+            src_x as f32, src_y as f32, src_width as f32, src_height as f32,
+            &rt_trait_object,
+            0., 0.,
+            animated_tile.get_flip_x(), animated_tile.get_flip_y(), animated_tile.get_flip_diagonal()
+        );
+        SokolRenderer2D::end_rt();
+        // The sprite and render target are owned by their components
+        Box::into_raw(sprite_trait_object);
+        Box::into_raw(rt_trait_object);
     }
 }
 
@@ -244,99 +297,313 @@ pub extern "C" fn draw_render_target_sort(_e1: ecs_entity_t, v1: *const std::ffi
     let rt2_component = ToxoidComponent::from_ptr_host(v2 as u64);
     rt1.set_component(rt1_component);
     rt2.set_component(rt2_component);
-    let z1 = rt1.get_z_depth();
-    let z2 = rt2.get_z_depth();
+    let z1 = (rt1.get_z_depth(), rt1.get_z_order());
+    let z2 = (rt2.get_z_depth(), rt2.get_z_order());
     z1.cmp(&z2) as i32
 }
 
-// Draw Render Targets to screen as final output
+// Reset render stats at the start of every frame
+pub fn reset_render_stats_system(_iter: &Iter) {
+    let render_stats = World::get_singleton::<RenderStats>();
+    render_stats.set_drawn(0);
+    render_stats.set_culled(0);
+}
+
+// Render target queued to be drawn by every camera this frame
+struct DrawItem {
+    entity: u64,
+    render_target: u64,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    blend_mode: u8,
+    flip_y: bool,
+    layers: u32,
+}
+
+// Filled in z order by `queue_render_targets_system`, drawn and emptied by `draw_cameras_system`
+static DRAW_QUEUE: Mutex<Vec<DrawItem>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // Cameras drawn by `draw_cameras_system`, built once by `draw_systems`
+    static CAMERA_QUERY: RefCell<Option<Query>> = const { RefCell::new(None) };
+}
+
+// Queue Render Targets in z order, the system can run once per table
 #[components(RenderTarget, _, Size, Position, BlendMode)]
-pub fn draw_render_targets_system(iter: &Iter) {
-    // Get camera position
-    let main_camera = World::get_singleton::<MainCamera>();
-    let mut camera_entity = Entity::from_id(main_camera.get_entity());
-    let camera_pos = camera_entity.get::<Position>();
+pub fn queue_render_targets_system(iter: &Iter) {
+    let mut entities = iter.entities();
+    let mut draw_queue = DRAW_QUEUE.lock().unwrap();
+    for (i, (rt, size, position, blend_mode)) in components.into_iter().enumerate() {
+        let entity = entities.get_mut(i).unwrap();
+        let layers = if entity.has::<RenderLayers>() { entity.get::<RenderLayers>().get_mask() } else { 1 };
+        draw_queue.push(DrawItem {
+            entity: entity.get_id(),
+            render_target: rt.get_render_target(),
+            x: position.get_x(),
+            y: position.get_y(),
+            width: size.get_width(),
+            height: size.get_height(),
+            blend_mode: blend_mode.get_blend_mode(),
+            flip_y: rt.get_flip_y(),
+            layers,
+        });
+    }
+}
 
-    for (rt, size, position, blend_mode) in components {
-        // Get render target object / pointer
-        let rt_ptr = rt.get_render_target();
-        let rt_ptr_box = unsafe { Box::from_raw(rt_ptr as *mut SokolRenderTarget) };
-        let rt_trait_object: &Box<dyn toxoid_render::RenderTarget> = Box::leak(Box::new(rt_ptr_box as Box<dyn toxoid_render::RenderTarget>));
-        // Get blend mode
-        let blend_mode = blend_mode.get_blend_mode();
-        // Get size
-        let width = size.get_width();
-        let height = size.get_height();
+// Draw the queued Render Targets on a camera's layers to its viewport, returns how many were drawn and culled.
+// Without a camera the whole game area is drawn at the origin.
+fn draw_camera(camera_entity: Option<&mut Entity>, draw_queue: &[DrawItem]) -> (u32, u32) {
+    let game_config = World::get_singleton::<GameConfig>();
+    let (mut view_width, mut view_height, mut zoom) = (game_config.get_game_width() as f32, game_config.get_game_height() as f32, 1.);
+    let (mut camera_x, mut camera_y) = (0, 0);
+    let (mut viewport_x, mut viewport_y) = (0., 0.);
+    let (mut shake_x, mut shake_y, mut layer_mask, mut clear_color, mut target) = (0., 0., 0, 0, 0);
+    let mut viewport_size = None;
+    if let Some(camera_entity) = camera_entity {
+        (view_width, view_height, zoom) = super::camera::camera_view(camera_entity);
+        let position = camera_entity.get::<Position>();
+        camera_x = position.get_x();
+        camera_y = position.get_y();
+        let camera = camera_entity.get::<Camera>();
+        viewport_x = camera.get_viewport_x();
+        viewport_y = camera.get_viewport_y();
+        if camera.get_viewport_width() > 0. && camera.get_viewport_height() > 0. {
+            viewport_size = Some((camera.get_viewport_width(), camera.get_viewport_height()));
+        }
+        shake_x = camera.get_shake_x();
+        shake_y = camera.get_shake_y();
+        layer_mask = camera.get_layer_mask();
+        clear_color = camera.get_clear_color();
+        target = camera.get_render_target();
+    }
+    // Draw to the screen when the render target is missing
+    let destination = World::try_get_entity(target)
+        .filter(|rt_entity| rt_entity.has::<RenderTarget>())
+        .map(|mut rt_entity| rt_entity.get::<RenderTarget>().get_render_target())
+        .filter(|rt_ptr| *rt_ptr != 0)
+        .map(|rt_ptr| {
+            let rt_box = unsafe { Box::from_raw(rt_ptr as *mut SokolRenderTarget) };
+            rt_box as Box<dyn toxoid_render::RenderTarget>
+        });
+
+    // A viewport without a size covers the whole view
+    let (viewport_width, viewport_height) = viewport_size.unwrap_or((view_width, view_height));
 
-        // Remove all scaling calculations and use raw positions
-        let world_x = position.get_x() - camera_pos.get_x();
-        let world_y = position.get_y() - camera_pos.get_y();
+    // Zoom is applied around the view center, so the visible area shrinks towards it
+    let view_left = view_width / 2. - view_width / (2. * zoom);
+    let view_top = view_height / 2. - view_height / (2. * zoom);
+    let view_right = view_width - view_left;
+    let view_bottom = view_height - view_top;
+    let mut drawn = 0;
+    let mut culled = 0;
+
+    SokolRenderer2D::begin_camera(destination.as_ref(), viewport_x, viewport_y, viewport_width, viewport_height, view_width, view_height, clear_color);
+    SokolRenderer2D::push_camera_transform(view_width, view_height, zoom, shake_x, shake_y);
+    for item in draw_queue {
+        // A camera can't draw the render target it draws into
+        if item.entity == target || (layer_mask != 0 && item.layers & layer_mask == 0) {
+            continue;
+        }
+        // Skip render targets outside of the camera view before touching them
+        let view_x = (item.x - camera_x) as f32;
+        let view_y = (item.y - camera_y) as f32;
+        if view_x + item.width as f32 <= view_left
+            || view_y + item.height as f32 <= view_top
+            || view_x >= view_right
+            || view_y >= view_bottom
+        {
+            culled += 1;
+            continue;
+        }
+        drawn += 1;
+
+        // Get render target object / pointer
+        let rt_box = unsafe { Box::from_raw(item.render_target as *mut SokolRenderTarget) };
+        let rt_trait_object: Box<dyn toxoid_render::RenderTarget> = rt_box;
         
         // Flip Y for Spine
         // TODO: Figure out some other way to do this
         #[cfg(all(target_arch="wasm32", target_os="emscripten"))]
-        let source_height = if rt.get_flip_y() { -(height as f32) } else { height as f32 };
+        let source_height = if item.flip_y { -(item.height as f32) } else { item.height as f32 };
         #[cfg(not(all(target_arch="wasm32", target_os="emscripten")))]
-        let source_height = height as f32;
+        let source_height = item.height as f32;
         // Draw directly using game coordinates
         SokolRenderer2D::draw_render_target(
-            rt_trait_object,
+            &rt_trait_object,
             0., 0.,
-            width as f32, source_height,
-            world_x as f32, world_y as f32,
-            width as f32, height as f32,
-            blend_mode
+            item.width as f32, source_height,
+            view_x, view_y,
+            item.width as f32, item.height as f32,
+            item.blend_mode
         );
+        // The render target is owned by its component
+        Box::into_raw(rt_trait_object);
     }
+    SokolRenderer2D::pop_camera_transform();
+    SokolRenderer2D::end_camera(destination.as_ref());
+    if let Some(destination) = destination {
+        Box::into_raw(destination);
+    }
+    (drawn, culled)
+}
+
+// Draw the queued Render Targets once per camera, in camera order. Without cameras they're drawn to the whole screen.
+pub fn draw_cameras_system(_iter: &Iter) {
+    let draw_queue = std::mem::take(&mut *DRAW_QUEUE.lock().unwrap());
+    let mut cameras = Vec::new();
+    CAMERA_QUERY.with(|query| {
+        let mut query = query.borrow_mut();
+        let Some(query) = query.as_mut() else {
+            return;
+        };
+        let mut iter = query.iter();
+        while iter.next() {
+            let cameras_iter = iter.components::<Camera>(0);
+            iter.entities()
+                .into_iter()
+                .zip(cameras_iter.iter())
+                .for_each(|(camera_entity, camera)| cameras.push((camera.get_order(), camera_entity)));
+        }
+    });
+    cameras.sort_by_key(|(order, _)| *order);
+
+    let render_stats = World::get_singleton::<RenderStats>();
+    let (mut drawn, mut culled) = (0, 0);
+    if cameras.is_empty() {
+        (drawn, culled) = draw_camera(None, &draw_queue);
+    }
+    for (_, mut camera_entity) in cameras {
+        let (camera_drawn, camera_culled) = draw_camera(Some(&mut camera_entity), &draw_queue);
+        drawn += camera_drawn;
+        culled += camera_culled;
+    }
+    render_stats.set_drawn(drawn);
+    render_stats.set_culled(culled);
 }
 
 // Systems that draw render targets to the screen as a final output
-pub fn draw_systems(render_systems_entity: &mut Entity) {
-    // Draw Render Targets
-    let system = System::dsl("RenderTarget, Renderable, Size, Position, BlendMode", None, draw_render_targets_system)
+pub fn draw_systems(draw_phase: &Phase) {
+    // Queue Render Targets, then draw them with every camera. Systems in a phase run in the order they're built.
+    System::dsl("RenderTarget, Renderable, Size, Position, BlendMode", None, queue_render_targets_system)
+        .phase(Phases::Custom(draw_phase.get_id()))
         .order_by(RenderTarget::get_id(), draw_render_target_sort)
         .build();
-    render_systems_entity.parent_of_id(system.get_id());
+    let mut camera_query = Query::dsl("Camera, Position");
+    camera_query.build();
+    CAMERA_QUERY.with(|query| *query.borrow_mut() = Some(camera_query));
+    System::dsl("RenderStats($)", None, draw_cameras_system)
+        .phase(Phases::Custom(draw_phase.get_id()))
+        .build();
 }
 
 // Systems that blit render targets
-pub fn blit_systems(render_systems_entity: &mut Entity) {
+pub fn blit_systems(blit_phase: &Phase) {
     // Blit cell to render target
-    let system = System::dsl("TiledCell, Blittable, Size", None, blit_cell_system)
+    System::dsl("TiledCell, Blittable, Size", None, blit_cell_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
+        .build();
+
+    // Animated tiles
+    System::dsl("AnimatedTile, RenderTarget", None, animate_tiles_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
+        .build();
+
+    // Bone animation playback
+    System::dsl("BoneAnimation, Skeleton, SpineInstance", None, super::animation::animate_bones_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
         .build();
-    render_systems_entity.parent_of_id(system.get_id());
 
     // Bone Animation Renderer
-    let system = System::dsl("SpineInstance, Position, Blittable", None, blit_bone_animation_system)
+    let bone_animation_query = Query::each::<BoneAnimationTerms>()
+        .with::<Position>()
+        .with::<Blittable>();
+    System::dsl(&bone_animation_query.expr(), None, blit_bone_animation_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
         .build();
-    render_systems_entity.parent_of_id(system.get_id());
 
     // Blit sprite to render target
-    let system = System::dsl("Sprite, Blittable, Size, (ChildOf, $Parent), RenderTarget($Parent), Size($Parent)", None, blit_sprite_system)
+    System::dsl("Sprite, Blittable, Size, (ChildOf, $Parent), RenderTarget($Parent), Size($Parent)", None, blit_sprite_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
+        .build();
+
+    // Frame by frame animations
+    System::dsl("FrameByFrameAnimation, Sprite, (ChildOf, $Parent), RenderTarget($Parent)", None, super::animation::animate_frames_system)
+        .phase(Phases::Custom(blit_phase.get_id()))
         .build();
-    render_systems_entity.parent_of_id(system.get_id());
 
     // // Rect Renderer
-    // let mut system = System::dsl("Rect, Position, Size, Color, Blittable", None, blit_rect_system);
-    // system.build();
-    // render_systems_entity.parent_of_id(system.get_id());
+    // System::dsl("Rect, Position, Size, Color, Blittable", None, blit_rect_system)
+    //     .phase(Phases::Custom(blit_phase.get_id()))
+    //     .build();
 }
 
 // Rendering Systems
 pub fn init() {
-    // Render systems group
-    let mut render_systems_entity = Entity::named("Render Systems Group");
-    
+    // Render phases run after the standard progress stage, in order:
+    // PreRender -> Blit -> Draw -> PostRender
+    let pre_render = Phase::new("PreRender")
+        .depends_on(Phases::OnStore);
+    let blit = Phase::new("Blit")
+        .depends_on_phase(&pre_render);
+    let draw = Phase::new("Draw")
+        .depends_on_phase(&blit);
+    let post_render = Phase::new("PostRender")
+        .depends_on_phase(&draw);
+
+    // Free render targets when their entity is deleted, e.g. with an unloaded cell
+    Observer::dsl("RenderTarget", vec![Event::OnRemove], |iter| {
+        iter.components::<RenderTarget>(0).iter().for_each(|render_target| {
+            let rt_ptr = render_target.get_render_target();
+            if rt_ptr != 0 {
+                let rt = unsafe { Box::from_raw(rt_ptr as *mut SokolRenderTarget) };
+                SokolRenderer2D::destroy_render_target(rt);
+            }
+        });
+    })
+        .build();
+
+    // Free sprites and their images when their entity is deleted, e.g. the tilesets of an unloaded cell.
+    // Atlas pages are shared by many sprites, they're freed once their last sprite is.
+    Observer::dsl("Sprite", vec![Event::OnRemove], |iter| {
+        iter.components::<Sprite>(0).iter().for_each(|sprite| {
+            let sprite_ptr = sprite.get_sprite();
+            if sprite_ptr != 0 {
+                let sokol_sprite = unsafe { Box::from_raw(sprite_ptr as *mut SokolSprite) };
+                if !atlas::release(sokol_sprite.image) {
+                    sg::destroy_image(sokol_sprite.image);
+                }
+                sprite.set_sprite(0);
+            }
+        });
+    })
+        .build();
+
+    // Systems that query RenderStats($) don't match until the singleton exists
+    World::get_singleton::<RenderStats>();
     // Systems
+    System::dsl("RenderStats($)", None, reset_render_stats_system)
+        .phase(Phases::Custom(pre_render.get_id()))
+        .build();
     // Blit systems
-    blit_systems(&mut render_systems_entity);
+    blit_systems(&blit);
     // Draw systems
-    draw_systems(&mut render_systems_entity);
+    draw_systems(&draw);
+
+    // Disable render systems until the renderer is initialized,
+    // disabling the first render phase disables every phase that depends on it
+    let mut render_root = Entity::from_id(pre_render.get_id());
+    render_root.disable();
 
-    // Disable render systems
-    render_systems_entity.disable();
+    // Set render phases
+    let render_phases = World::get_singleton::<RenderPhases>();
+    render_phases.set_pre_render(pre_render.get_id());
+    render_phases.set_blit(blit.get_id());
+    render_phases.set_draw(draw.get_id());
+    render_phases.set_post_render(post_render.get_id());
 
-    // Set render systems group
+    // Set render systems root
     let render_systems_singleton = World::get_singleton::<RenderSystems>();
-    render_systems_singleton.set_entity(render_systems_entity.get_id());
+    render_systems_singleton.set_entity(pre_render.get_id());
 }
@@ -0,0 +1,144 @@
+use toxoid_api::*;
+
+// Cells are unloaded a bit further out than they are loaded,
+// so cells on the edge of the radius don't load and unload every check
+const UNLOAD_MARGIN: f32 = 1.5;
+
+// Center of the main camera's view, or the player's position when there is no camera
+fn streaming_focus() -> Option<(f32, f32)> {
+    let main_camera = World::get_singleton::<MainCamera>();
+    if main_camera.get_entity() != 0 {
+        let mut camera_entity = Entity::from_id(main_camera.get_entity());
+        if camera_entity.has::<Position>() {
+            let (width, height, _) = super::camera::camera_view(&mut camera_entity);
+            let position = camera_entity.get::<Position>();
+            return Some((position.get_x() as f32 + width / 2., position.get_y() as f32 + height / 2.));
+        }
+    }
+    let player_singleton = World::get_singleton::<Player>();
+    if player_singleton.get_entity() != 0 {
+        let mut player_entity = Entity::from_id(player_singleton.get_entity());
+        if player_entity.has::<Position>() {
+            let position = player_entity.get::<Position>();
+            return Some((position.get_x() as f32, position.get_y() as f32));
+        }
+    }
+    None
+}
+
+// Distance from a point to the closest edge of a cell, 0 inside of it
+fn distance_to_cell(x: f32, y: f32, cell: &toxoid_tiled::TiledCellData) -> f32 {
+    let dx = (cell.x as f32 - x).max(0.).max(x - (cell.x as f32 + cell.width as f32));
+    let dy = (cell.y as f32 - y).max(0.).max(y - (cell.y as f32 + cell.height as f32));
+    (dx * dx + dy * dy).sqrt()
+}
+
+pub fn load_world_cell(world_entity_id: u64, index: u32, cell: &toxoid_tiled::TiledCellData) -> Entity {
+    let mut cell_entity = toxoid_api::load_cell(format!("assets/{}", cell.file_name).as_str(), true);
+    cell_entity.child_of_id(world_entity_id);
+    let tiled_cell = cell_entity.get::<TiledCell>();
+    tiled_cell.set_index(index);
+
+    // Set cell position
+    cell_entity.add::<Position>();
+    let cell_pos = cell_entity.get::<Position>();
+    cell_pos.set_x(cell.x);
+    cell_pos.set_y(cell.y);
+
+    // Set cell size
+    cell_entity.add::<Size>();
+    let cell_size = cell_entity.get::<Size>();
+    cell_size.set_width(cell.width);
+    cell_size.set_height(cell.height);
+
+    // Add blittable component
+    cell_entity.add::<Blittable>();
+
+    // Parent the player to the cell it stands in
+    let player_singleton = World::get_singleton::<Player>();
+    if player_singleton.get_entity() != 0 {
+        let mut player_entity = Entity::from_id(player_singleton.get_entity());
+        let (player_x, player_y) = if player_entity.has::<Position>() {
+            let position = player_entity.get::<Position>();
+            (position.get_x() as f32, position.get_y() as f32)
+        } else {
+            (0., 0.)
+        };
+        if distance_to_cell(player_x, player_y, cell) == 0. {
+            player_entity.child_of_id(cell_entity.get_id());
+        }
+    }
+    cell_entity
+}
+
+// Deleting a cell deletes its children, its tilesets, render targets and object entities
+fn unload_world_cell(world_entity_id: u64, cell_entity: &Entity) {
+    // Keep the player alive by moving it up to the world
+    let player_singleton = World::get_singleton::<Player>();
+    if player_singleton.get_entity() != 0 {
+        let mut player_entity = Entity::from_id(player_singleton.get_entity());
+        if player_entity.parent().get_id() == cell_entity.get_id() {
+            player_entity.child_of_id(world_entity_id);
+        }
+    }
+    let mut cell_entity = World::get_entity(cell_entity.get_id());
+    let cell = cell_entity.get::<TiledCell>();
+    if cell.get_cell() != 0 {
+        drop(unsafe { Box::from_raw(cell.get_cell() as *mut toxoid_tiled::TiledCell) });
+        cell.set_cell(0);
+    }
+    World::remove_entity(cell_entity.get_id());
+}
+
+// Load the cells of a world within its radius of the camera and unload the ones that left it
+pub fn world_streaming_system(iter: &Iter) {
+    let Some((focus_x, focus_y)) = streaming_focus() else {
+        return;
+    };
+    let game_config = World::get_singleton::<GameConfig>();
+    let default_radius = game_config.get_game_width().max(game_config.get_game_height()) as f32;
+    iter.entities().iter_mut().for_each(|world_entity| {
+        let world = world_entity.get::<TiledWorld>();
+        if world.get_world() == 0 {
+            return;
+        }
+        let tiled_world = unsafe { &*(world.get_world() as *const toxoid_tiled::TiledWorld) };
+        let Some(maps) = tiled_world.maps.as_ref() else {
+            return;
+        };
+        let radius = if world.get_radius() > 0 { world.get_radius() as f32 } else { default_radius };
+        let world_entity_id = world_entity.get_id();
+
+        // Cells that are loaded or loading, by index in the world
+        let mut loaded = world_entity
+            .children()
+            .into_iter()
+            .filter(|child| child.has::<TiledCell>())
+            .map(|mut child| (child.get::<TiledCell>().get_index(), child))
+            .collect::<Vec<(u32, Entity)>>();
+
+        loaded.retain(|(index, cell_entity)| {
+            let keep = maps
+                .get(*index as usize)
+                .map_or(false, |cell| distance_to_cell(focus_x, focus_y, cell) <= radius * UNLOAD_MARGIN);
+            if !keep {
+                unload_world_cell(world_entity_id, cell_entity);
+            }
+            keep
+        });
+
+        maps.iter().enumerate().for_each(|(index, cell)| {
+            let index = index as u32;
+            if distance_to_cell(focus_x, focus_y, cell) > radius || loaded.iter().any(|(loaded_index, _)| *loaded_index == index) {
+                return;
+            }
+            load_world_cell(world_entity_id, index, cell);
+        });
+    });
+}
+
+pub fn init() {
+    System::dsl("TiledWorld", None, world_streaming_system)
+        .interval(0.25)
+        .build();
+}
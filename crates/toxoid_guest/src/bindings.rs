@@ -210,8 +210,7 @@ pub mod toxoid_component {
                     }
                 }
             }
-            #[repr(u8)]
-            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            #[derive(Clone, Copy)]
             pub enum Event {
                 OnSet,
                 OnAdd,
@@ -220,6 +219,7 @@ pub mod toxoid_component {
                 OnDeleteTarget,
                 OnTableCreate,
                 OnTableDelete,
+                Custom(EcsEntityT),
             }
             impl ::core::fmt::Debug for Event {
                 fn fmt(
@@ -240,24 +240,52 @@ pub mod toxoid_component {
                         Event::OnTableDelete => {
                             f.debug_tuple("Event::OnTableDelete").finish()
                         }
+                        Event::Custom(e) => {
+                            f.debug_tuple("Event::Custom").field(e).finish()
+                        }
                     }
                 }
             }
-            impl Event {
-                #[doc(hidden)]
-                pub unsafe fn _lift(val: u8) -> Event {
-                    if !cfg!(debug_assertions) {
-                        return ::core::mem::transmute(val);
-                    }
-                    match val {
-                        0 => Event::OnSet,
-                        1 => Event::OnAdd,
-                        2 => Event::OnRemove,
-                        3 => Event::OnDelete,
-                        4 => Event::OnDeleteTarget,
-                        5 => Event::OnTableCreate,
-                        6 => Event::OnTableDelete,
-                        _ => panic!("invalid enum discriminant"),
+            /// Structural change applied by `submit` in a single deferred scope
+            #[derive(Clone, Copy)]
+            pub enum Command {
+                Add((EcsEntityT, EcsEntityT)),
+                Remove((EcsEntityT, EcsEntityT)),
+                AddRelationship((EcsEntityT, Relationship, EcsEntityT)),
+                RemoveRelationship((EcsEntityT, Relationship, EcsEntityT)),
+                Enable(EcsEntityT),
+                Disable(EcsEntityT),
+                Delete(EcsEntityT),
+            }
+            impl ::core::fmt::Debug for Command {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Command::Add(e) => {
+                            f.debug_tuple("Command::Add").field(e).finish()
+                        }
+                        Command::Remove(e) => {
+                            f.debug_tuple("Command::Remove").field(e).finish()
+                        }
+                        Command::AddRelationship(e) => {
+                            f.debug_tuple("Command::AddRelationship").field(e).finish()
+                        }
+                        Command::RemoveRelationship(e) => {
+                            f.debug_tuple("Command::RemoveRelationship")
+                                .field(e)
+                                .finish()
+                        }
+                        Command::Enable(e) => {
+                            f.debug_tuple("Command::Enable").field(e).finish()
+                        }
+                        Command::Disable(e) => {
+                            f.debug_tuple("Command::Disable").field(e).finish()
+                        }
+                        Command::Delete(e) => {
+                            f.debug_tuple("Command::Delete").field(e).finish()
+                        }
                     }
                 }
             }
@@ -570,6 +598,11 @@ pub mod toxoid_component {
                 pub callback: Callback,
                 pub query_desc: QueryDesc,
                 pub is_guest: bool,
+                pub phase: Option<EcsEntityT>,
+                pub interval: Option<f32>,
+                pub tick_source: Option<EcsEntityT>,
+                pub multi_threaded: bool,
+                pub immediate: bool,
             }
             impl ::core::fmt::Debug for SystemDesc {
                 fn fmt(
@@ -582,6 +615,11 @@ pub mod toxoid_component {
                         .field("callback", &self.callback)
                         .field("query-desc", &self.query_desc)
                         .field("is-guest", &self.is_guest)
+                        .field("phase", &self.phase)
+                        .field("interval", &self.interval)
+                        .field("tick-source", &self.tick_source)
+                        .field("multi-threaded", &self.multi_threaded)
+                        .field("immediate", &self.immediate)
                         .finish()
                 }
             }
@@ -719,6 +757,80 @@ pub mod toxoid_component {
             }
             #[derive(Debug)]
             #[repr(transparent)]
+            pub struct Prefab {
+                handle: _rt::Resource<Prefab>,
+            }
+            impl Prefab {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: _rt::Resource::from_handle(handle),
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for Prefab {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[resource-drop]prefab"]
+                            fn drop(_: u32);
+                        }
+                        drop(_handle);
+                    }
+                }
+            }
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct Timer {
+                handle: _rt::Resource<Timer>,
+            }
+            impl Timer {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: _rt::Resource::from_handle(handle),
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for Timer {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[resource-drop]timer"]
+                            fn drop(_: u32);
+                        }
+                        drop(_handle);
+                    }
+                }
+            }
+            #[derive(Debug)]
+            #[repr(transparent)]
             pub struct Observer {
                 handle: _rt::Resource<Observer>,
             }
@@ -2467,6 +2579,222 @@ pub mod toxoid_component {
                     }
                 }
             }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_path(&self) -> _rt::String {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 8],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.get-path"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = *ptr0.add(0).cast::<*mut u8>();
+                        let l2 = *ptr0.add(4).cast::<usize>();
+                        let len3 = l2;
+                        let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                        _rt::string_lift(bytes3)
+                    }
+                }
+            }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn lookup(&self, path: &str) -> Option<Entity> {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 8],
+                        );
+                        let vec0 = path;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.lookup"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l3 = *ptr1.add(4).cast::<i32>();
+                                    Entity::from_handle(l3 as u32)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn target(
+                    &self,
+                    relationship: Relationship,
+                    index: i32,
+                ) -> Option<Entity> {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 8],
+                        );
+                        let (result0_0, result0_1) = match relationship {
+                            Relationship::IsA => (0i32, 0i64),
+                            Relationship::ChildOf => (1i32, 0i64),
+                            Relationship::Custom(e) => (2i32, _rt::as_i64(e)),
+                        };
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.target"]
+                            fn wit_import(_: i32, _: i32, _: i64, _: i32, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32, _: i64, _: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import(
+                            (self).handle() as i32,
+                            result0_0,
+                            result0_1,
+                            _rt::as_i32(&index),
+                            ptr1,
+                        );
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l3 = *ptr1.add(4).cast::<i32>();
+                                    Entity::from_handle(l3 as u32)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn targets(&self, relationship: Relationship) -> _rt::Vec<Entity> {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 8],
+                        );
+                        let (result0_0, result0_1) = match relationship {
+                            Relationship::IsA => (0i32, 0i64),
+                            Relationship::ChildOf => (1i32, 0i64),
+                            Relationship::Custom(e) => (2i32, _rt::as_i64(e)),
+                        };
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.targets"]
+                            fn wit_import(_: i32, _: i32, _: i64, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32, _: i64, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result0_0, result0_1, ptr1);
+                        let l2 = *ptr1.add(0).cast::<*mut u8>();
+                        let l3 = *ptr1.add(4).cast::<usize>();
+                        let base5 = l2;
+                        let len5 = l3;
+                        let mut result5 = _rt::Vec::with_capacity(len5);
+                        for i in 0..len5 {
+                            let base = base5.add(i * 4);
+                            let e5 = {
+                                let l4 = *base.add(0).cast::<i32>();
+                                Entity::from_handle(l4 as u32)
+                            };
+                            result5.push(e5);
+                        }
+                        _rt::cabi_dealloc(base5, len5 * 4, 4);
+                        result5
+                    }
+                }
+            }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn set_json(&self, component: EcsEntityT, json: &str) -> bool {
+                    unsafe {
+                        let vec0 = json;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.set-json"]
+                            fn wit_import(_: i32, _: i64, _: *mut u8, _: usize) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i64, _: *mut u8, _: usize) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i64(component),
+                            ptr0.cast_mut(),
+                            len0,
+                        );
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
+            impl Entity {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn from_json(&self, json: &str) -> bool {
+                    unsafe {
+                        let vec0 = json;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]entity.from-json"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                        );
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
             impl Entity {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn disable(&self) {
@@ -2691,33 +3019,32 @@ pub mod toxoid_component {
                     }
                 }
             }
-            impl Iter {
+            impl Query {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn new(ptr: u64) -> Self {
+                pub fn detect_changes(&self) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[constructor]iter"]
-                            fn wit_import(_: i64) -> i32;
+                            #[link_name = "[method]query.detect-changes"]
+                            fn wit_import(_: i32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i64) -> i32 {
+                        fn wit_import(_: i32) {
                             unreachable!()
                         }
-                        let ret = wit_import(_rt::as_i64(&ptr));
-                        Iter::from_handle(ret as u32)
+                        wit_import((self).handle() as i32);
                     }
                 }
             }
-            impl Iter {
+            impl Query {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn next(&self) -> bool {
+                pub fn changed(&self) -> bool {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]iter.next"]
+                            #[link_name = "[method]query.changed"]
                             fn wit_import(_: i32) -> i32;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
@@ -2729,11 +3056,145 @@ pub mod toxoid_component {
                     }
                 }
             }
-            impl Iter {
+            impl Query {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn count(&self) -> i32 {
+                pub fn set_var(&self, name: &str, value: EcsEntityT) {
                     unsafe {
-                        #[cfg(target_arch = "wasm32")]
+                        let vec0 = name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]query.set-var"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i64);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i64) {
+                            unreachable!()
+                        }
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                            _rt::as_i64(value),
+                        );
+                    }
+                }
+            }
+            impl Query {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_var(&self, name: &str) -> EcsEntityT {
+                    unsafe {
+                        let vec0 = name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]query.get-var"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                        );
+                        ret as u64
+                    }
+                }
+            }
+            impl Query {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn is_set(&self, index: i8) -> bool {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]query.is-set"]
+                            fn wit_import(_: i32, _: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i32(&index),
+                        );
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
+            impl Query {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn field_src(&self, index: i8) -> EcsEntityT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]query.field-src"]
+                            fn wit_import(_: i32, _: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i32(&index),
+                        );
+                        ret as u64
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(ptr: u64) -> Self {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[constructor]iter"]
+                            fn wit_import(_: i64) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i64) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(_rt::as_i64(&ptr));
+                        Iter::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn next(&self) -> bool {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]iter.next"]
+                            fn wit_import(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn count(&self) -> i32 {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
                             #[link_name = "[method]iter.count"]
@@ -2815,6 +3276,95 @@ pub mod toxoid_component {
                     }
                 }
             }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_var(&self, name: &str) -> EcsEntityT {
+                    unsafe {
+                        let vec0 = name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]iter.get-var"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                        );
+                        ret as u64
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn is_set(&self, index: i8) -> bool {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]iter.is-set"]
+                            fn wit_import(_: i32, _: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i32(&index),
+                        );
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn field_src(&self, index: i8) -> EcsEntityT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]iter.field-src"]
+                            fn wit_import(_: i32, _: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i32(&index),
+                        );
+                        ret as u64
+                    }
+                }
+            }
+            impl Iter {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn changed(&self) -> bool {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]iter.changed"]
+                            fn wit_import(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
             impl Callback {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn new(handle: u64) -> Self {
@@ -2848,72 +3398,596 @@ pub mod toxoid_component {
                         fn wit_import(_: i32, _: i32) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, (&iter).take_handle() as i32);
+                        wit_import((self).handle() as i32, (&iter).take_handle() as i32);
+                    }
+                }
+            }
+            impl Callback {
+                #[allow(unused_unsafe, clippy::all)]
+                /// This is prefixed because resources already have a `handle` method.
+                pub fn cb_handle(&self) -> PointerT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]callback.cb-handle"]
+                            fn wit_import(_: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret as u64
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(desc: SystemDesc) -> Self {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 88]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 88],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        let SystemDesc {
+                            name: name1,
+                            tick_rate: tick_rate1,
+                            callback: callback1,
+                            query_desc: query_desc1,
+                            is_guest: is_guest1,
+                            phase: phase1,
+                            interval: interval1,
+                            tick_source: tick_source1,
+                            multi_threaded: multi_threaded1,
+                            immediate: immediate1,
+                        } = &desc;
+                        match name1 {
+                            Some(e) => {
+                                *ptr0.add(0).cast::<u8>() = (1i32) as u8;
+                                let vec2 = e;
+                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                let len2 = vec2.len();
+                                *ptr0.add(8).cast::<usize>() = len2;
+                                *ptr0.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            }
+                            None => {
+                                *ptr0.add(0).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        match tick_rate1 {
+                            Some(e) => {
+                                *ptr0.add(12).cast::<u8>() = (1i32) as u8;
+                                *ptr0.add(16).cast::<i32>() = _rt::as_i32(e);
+                            }
+                            None => {
+                                *ptr0.add(12).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        *ptr0.add(20).cast::<i32>() = (callback1).take_handle() as i32;
+                        let QueryDesc { expr: expr3 } = query_desc1;
+                        let vec4 = expr3;
+                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                        let len4 = vec4.len();
+                        *ptr0.add(28).cast::<usize>() = len4;
+                        *ptr0.add(24).cast::<*mut u8>() = ptr4.cast_mut();
+                        *ptr0.add(32).cast::<u8>() = (match is_guest1 {
+                            true => 1,
+                            false => 0,
+                        }) as u8;
+                        match phase1 {
+                            Some(e) => {
+                                *ptr0.add(40).cast::<u8>() = (1i32) as u8;
+                                *ptr0.add(48).cast::<i64>() = _rt::as_i64(e);
+                            }
+                            None => {
+                                *ptr0.add(40).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        match interval1 {
+                            Some(e) => {
+                                *ptr0.add(56).cast::<u8>() = (1i32) as u8;
+                                *ptr0.add(60).cast::<f32>() = _rt::as_f32(e);
+                            }
+                            None => {
+                                *ptr0.add(56).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        match tick_source1 {
+                            Some(e) => {
+                                *ptr0.add(64).cast::<u8>() = (1i32) as u8;
+                                *ptr0.add(72).cast::<i64>() = _rt::as_i64(e);
+                            }
+                            None => {
+                                *ptr0.add(64).cast::<u8>() = (0i32) as u8;
+                            }
+                        };
+                        *ptr0.add(80).cast::<u8>() = (match multi_threaded1 {
+                            true => 1,
+                            false => 0,
+                        }) as u8;
+                        *ptr0.add(81).cast::<u8>() = (match immediate1 {
+                            true => 1,
+                            false => 0,
+                        }) as u8;
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[constructor]system"]
+                            fn wit_import(_: *mut u8) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: *mut u8) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(ptr0);
+                        System::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_id(&self) -> EcsEntityT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.get-id"]
+                            fn wit_import(_: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret as u64
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn build(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.build"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn order_by(&self, sorting: SortingDesc) {
+                    unsafe {
+                        let SortingDesc { id: id0, callback: callback0 } = &sorting;
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.order-by"]
+                            fn wit_import(_: i32, _: i64, _: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i64, _: i32) {
+                            unreachable!()
+                        }
+                        wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i64(id0),
+                            (callback0).take_handle() as i32,
+                        );
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn callback(&self) -> Callback {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.callback"]
+                            fn wit_import(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        Callback::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn disable(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.disable"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn enable(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.enable"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn phase(&self, phase: Phases) {
+                    unsafe {
+                        let (result0_0, result0_1) = match phase {
+                            Phases::OnStart => (0i32, 0i64),
+                            Phases::OnLoad => (1i32, 0i64),
+                            Phases::PostLoad => (2i32, 0i64),
+                            Phases::PreUpdate => (3i32, 0i64),
+                            Phases::OnUpdate => (4i32, 0i64),
+                            Phases::OnValidate => (5i32, 0i64),
+                            Phases::PostUpdate => (6i32, 0i64),
+                            Phases::PreStore => (7i32, 0i64),
+                            Phases::OnStore => (8i32, 0i64),
+                            Phases::Custom(e) => (9i32, _rt::as_i64(e)),
+                        };
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.phase"]
+                            fn wit_import(_: i32, _: i32, _: i64);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32, _: i64) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result0_0, result0_1);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn interval(&self, seconds: f32) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.interval"]
+                            fn wit_import(_: i32, _: f32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: f32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, _rt::as_f32(&seconds));
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn multi_threaded(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.multi-threaded"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn immediate(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.immediate"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl System {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn tick_source(&self, source: EcsEntityT) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]system.tick-source"]
+                            fn wit_import(_: i32, _: i64);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i64) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, _rt::as_i64(source));
+                    }
+                }
+            }
+            impl Phase {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(name: &str) -> Self {
+                    unsafe {
+                        let vec0 = name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[constructor]phase"]
+                            fn wit_import(_: *mut u8, _: usize) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: *mut u8, _: usize) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(ptr0.cast_mut(), len0);
+                        Phase::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl Phase {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn depends_on(&self, phase: Phases) {
+                    unsafe {
+                        let (result0_0, result0_1) = match phase {
+                            Phases::OnStart => (0i32, 0i64),
+                            Phases::OnLoad => (1i32, 0i64),
+                            Phases::PostLoad => (2i32, 0i64),
+                            Phases::PreUpdate => (3i32, 0i64),
+                            Phases::OnUpdate => (4i32, 0i64),
+                            Phases::OnValidate => (5i32, 0i64),
+                            Phases::PostUpdate => (6i32, 0i64),
+                            Phases::PreStore => (7i32, 0i64),
+                            Phases::OnStore => (8i32, 0i64),
+                            Phases::Custom(e) => (9i32, _rt::as_i64(e)),
+                        };
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]phase.depends-on"]
+                            fn wit_import(_: i32, _: i32, _: i64);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32, _: i64) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result0_0, result0_1);
+                    }
+                }
+            }
+            impl Phase {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_id(&self) -> EcsEntityT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]phase.get-id"]
+                            fn wit_import(_: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret as u64
+                    }
+                }
+            }
+            impl Pipeline {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(desc: &PipelineDesc) -> Self {
+                    unsafe {
+                        let PipelineDesc {
+                            name: name0,
+                            query_desc: query_desc0,
+                            phases: phases0,
+                        } = desc;
+                        let vec1 = name0;
+                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                        let len1 = vec1.len();
+                        let QueryDesc { expr: expr2 } = query_desc0;
+                        let vec3 = expr2;
+                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                        let len3 = vec3.len();
+                        let vec4 = phases0;
+                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                        let len4 = vec4.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[constructor]pipeline"]
+                            fn wit_import(
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                            ) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                        ) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import(
+                            ptr1.cast_mut(),
+                            len1,
+                            ptr3.cast_mut(),
+                            len3,
+                            ptr4.cast_mut(),
+                            len4,
+                        );
+                        Pipeline::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl Pipeline {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn build(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]pipeline.build"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
+                    }
+                }
+            }
+            impl Pipeline {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn add_phase(&self, phase: EcsEntityT) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]pipeline.add-phase"]
+                            fn wit_import(_: i32, _: i64);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i64) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, _rt::as_i64(phase));
+                    }
+                }
+            }
+            impl Pipeline {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_id(&self) -> EcsEntityT {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]pipeline.get-id"]
+                            fn wit_import(_: i32) -> i64;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> i64 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret as u64
+                    }
+                }
+            }
+            impl Pipeline {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn disable(&self) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]pipeline.disable"]
+                            fn wit_import(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32);
                     }
                 }
             }
-            impl Callback {
+            impl Pipeline {
                 #[allow(unused_unsafe, clippy::all)]
-                /// This is prefixed because resources already have a `handle` method.
-                pub fn cb_handle(&self) -> PointerT {
+                pub fn enable(&self) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]callback.cb-handle"]
-                            fn wit_import(_: i32) -> i64;
+                            #[link_name = "[method]pipeline.enable"]
+                            fn wit_import(_: i32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i64 {
+                        fn wit_import(_: i32) {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
-                        ret as u64
+                        wit_import((self).handle() as i32);
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn new(desc: SystemDesc) -> Self {
+                pub fn new(name: Option<&str>, parent: Option<EcsEntityT>) -> Self {
                     unsafe {
-                        let SystemDesc {
-                            name: name0,
-                            tick_rate: tick_rate0,
-                            callback: callback0,
-                            query_desc: query_desc0,
-                            is_guest: is_guest0,
-                        } = &desc;
-                        let (result2_0, result2_1, result2_2) = match name0 {
+                        let (result1_0, result1_1, result1_2) = match name {
                             Some(e) => {
-                                let vec1 = e;
-                                let ptr1 = vec1.as_ptr().cast::<u8>();
-                                let len1 = vec1.len();
-                                (1i32, ptr1.cast_mut(), len1)
+                                let vec0 = e;
+                                let ptr0 = vec0.as_ptr().cast::<u8>();
+                                let len0 = vec0.len();
+                                (1i32, ptr0.cast_mut(), len0)
                             }
                             None => (0i32, ::core::ptr::null_mut(), 0usize),
                         };
-                        let (result3_0, result3_1) = match tick_rate0 {
-                            Some(e) => (1i32, _rt::as_i32(e)),
-                            None => (0i32, 0i32),
+                        let (result2_0, result2_1) = match parent {
+                            Some(e) => (1i32, _rt::as_i64(e)),
+                            None => (0i32, 0i64),
                         };
-                        let QueryDesc { expr: expr4 } = query_desc0;
-                        let vec5 = expr4;
-                        let ptr5 = vec5.as_ptr().cast::<u8>();
-                        let len5 = vec5.len();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[constructor]system"]
+                            #[link_name = "[constructor]prefab"]
                             fn wit_import(
                                 _: i32,
                                 _: *mut u8,
                                 _: usize,
                                 _: i32,
-                                _: i32,
-                                _: i32,
-                                _: *mut u8,
-                                _: usize,
-                                _: i32,
+                                _: i64,
                             ) -> i32;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
@@ -2922,40 +3996,29 @@ pub mod toxoid_component {
                             _: *mut u8,
                             _: usize,
                             _: i32,
-                            _: i32,
-                            _: i32,
-                            _: *mut u8,
-                            _: usize,
-                            _: i32,
+                            _: i64,
                         ) -> i32 {
                             unreachable!()
                         }
                         let ret = wit_import(
+                            result1_0,
+                            result1_1,
+                            result1_2,
                             result2_0,
                             result2_1,
-                            result2_2,
-                            result3_0,
-                            result3_1,
-                            (callback0).take_handle() as i32,
-                            ptr5.cast_mut(),
-                            len5,
-                            match is_guest0 {
-                                true => 1,
-                                false => 0,
-                            },
                         );
-                        System::from_handle(ret as u32)
+                        Prefab::from_handle(ret as u32)
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn get_id(&self) -> EcsEntityT {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.get-id"]
+                            #[link_name = "[method]prefab.get-id"]
                             fn wit_import(_: i32) -> i64;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
@@ -2967,162 +4030,175 @@ pub mod toxoid_component {
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn build(&self) {
+                pub fn add(&self, component: EcsEntityT) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.build"]
-                            fn wit_import(_: i32);
+                            #[link_name = "[method]prefab.add"]
+                            fn wit_import(_: i32, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        fn wit_import(_: i32, _: i64) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        wit_import((self).handle() as i32, _rt::as_i64(component));
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn order_by(&self, sorting: SortingDesc) {
+                pub fn get(&self, component: EcsEntityT) -> Component {
                     unsafe {
-                        let SortingDesc { id: id0, callback: callback0 } = &sorting;
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.order-by"]
-                            fn wit_import(_: i32, _: i64, _: i32);
+                            #[link_name = "[method]prefab.get"]
+                            fn wit_import(_: i32, _: i64) -> i32;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: i32) {
+                        fn wit_import(_: i32, _: i64) -> i32 {
                             unreachable!()
                         }
-                        wit_import(
+                        let ret = wit_import(
                             (self).handle() as i32,
-                            _rt::as_i64(id0),
-                            (callback0).take_handle() as i32,
+                            _rt::as_i64(component),
                         );
+                        Component::from_handle(ret as u32)
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn callback(&self) -> Callback {
+                pub fn override_component(&self, component: EcsEntityT) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.callback"]
-                            fn wit_import(_: i32) -> i32;
+                            #[link_name = "[method]prefab.override-component"]
+                            fn wit_import(_: i32, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i32 {
+                        fn wit_import(_: i32, _: i64) {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
-                        Callback::from_handle(ret as u32)
+                        wit_import((self).handle() as i32, _rt::as_i64(component));
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn disable(&self) {
+                pub fn inherit_component(&self, component: EcsEntityT) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.disable"]
-                            fn wit_import(_: i32);
+                            #[link_name = "[method]prefab.inherit-component"]
+                            fn wit_import(_: i32, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        fn wit_import(_: i32, _: i64) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        wit_import((self).handle() as i32, _rt::as_i64(component));
                     }
                 }
             }
-            impl System {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn enable(&self) {
+                pub fn is_a(&self, base: EcsEntityT) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]system.enable"]
-                            fn wit_import(_: i32);
+                            #[link_name = "[method]prefab.is-a"]
+                            fn wit_import(_: i32, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        fn wit_import(_: i32, _: i64) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        wit_import((self).handle() as i32, _rt::as_i64(base));
                     }
                 }
             }
-            impl Phase {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn new(name: &str) -> Self {
+                /// False when the prefab has no parent to be a slot of
+                pub fn slot(&self) -> bool {
                     unsafe {
-                        let vec0 = name;
-                        let ptr0 = vec0.as_ptr().cast::<u8>();
-                        let len0 = vec0.len();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[constructor]phase"]
-                            fn wit_import(_: *mut u8, _: usize) -> i32;
+                            #[link_name = "[method]prefab.slot"]
+                            fn wit_import(_: i32) -> i32;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: *mut u8, _: usize) -> i32 {
+                        fn wit_import(_: i32) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import(ptr0.cast_mut(), len0);
-                        Phase::from_handle(ret as u32)
+                        let ret = wit_import((self).handle() as i32);
+                        _rt::bool_lift(ret as u8)
                     }
                 }
             }
-            impl Phase {
+            impl Prefab {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn depends_on(&self, phase: Phases) {
+                pub fn instantiate(&self, count: u32) -> _rt::Vec<EcsEntityT> {
                     unsafe {
-                        let (result0_0, result0_1) = match phase {
-                            Phases::OnStart => (0i32, 0i64),
-                            Phases::OnLoad => (1i32, 0i64),
-                            Phases::PostLoad => (2i32, 0i64),
-                            Phases::PreUpdate => (3i32, 0i64),
-                            Phases::OnUpdate => (4i32, 0i64),
-                            Phases::OnValidate => (5i32, 0i64),
-                            Phases::PostUpdate => (6i32, 0i64),
-                            Phases::PreStore => (7i32, 0i64),
-                            Phases::OnStore => (8i32, 0i64),
-                            Phases::Custom(e) => (9i32, _rt::as_i64(e)),
-                        };
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 8],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]phase.depends-on"]
-                            fn wit_import(_: i32, _: i32, _: i64);
+                            #[link_name = "[method]prefab.instantiate"]
+                            fn wit_import(_: i32, _: i32, _: *mut u8);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32, _: i64) {
+                        fn wit_import(_: i32, _: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, result0_0, result0_1);
+                        wit_import((self).handle() as i32, _rt::as_i32(&count), ptr0);
+                        let l1 = *ptr0.add(0).cast::<*mut u8>();
+                        let l2 = *ptr0.add(4).cast::<usize>();
+                        let len3 = l2;
+                        _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
                     }
                 }
             }
-            impl Phase {
+            impl Timer {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new() -> Self {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[constructor]timer"]
+                            fn wit_import() -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import() -> i32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import();
+                        Timer::from_handle(ret as u32)
+                    }
+                }
+            }
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn get_id(&self) -> EcsEntityT {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]phase.get-id"]
+                            #[link_name = "[method]timer.get-id"]
                             fn wit_import(_: i32) -> i64;
                         }
                         #[cfg(not(target_arch = "wasm32"))]
@@ -3134,124 +4210,154 @@ pub mod toxoid_component {
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn new(desc: &PipelineDesc) -> Self {
+                /// One-shot timer, ticks once after the timeout expires
+                pub fn set_timeout(&self, seconds: f32) {
                     unsafe {
-                        let PipelineDesc {
-                            name: name0,
-                            query_desc: query_desc0,
-                            phases: phases0,
-                        } = desc;
-                        let vec1 = name0;
-                        let ptr1 = vec1.as_ptr().cast::<u8>();
-                        let len1 = vec1.len();
-                        let QueryDesc { expr: expr2 } = query_desc0;
-                        let vec3 = expr2;
-                        let ptr3 = vec3.as_ptr().cast::<u8>();
-                        let len3 = vec3.len();
-                        let vec4 = phases0;
-                        let ptr4 = vec4.as_ptr().cast::<u8>();
-                        let len4 = vec4.len();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[constructor]pipeline"]
-                            fn wit_import(
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                                _: usize,
-                            ) -> i32;
+                            #[link_name = "[method]timer.set-timeout"]
+                            fn wit_import(_: i32, _: f32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                        ) -> i32 {
+                        fn wit_import(_: i32, _: f32) {
                             unreachable!()
                         }
-                        let ret = wit_import(
-                            ptr1.cast_mut(),
-                            len1,
-                            ptr3.cast_mut(),
-                            len3,
-                            ptr4.cast_mut(),
-                            len4,
-                        );
-                        Pipeline::from_handle(ret as u32)
+                        wit_import((self).handle() as i32, _rt::as_f32(&seconds));
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn build(&self) {
+                pub fn get_timeout(&self) -> f32 {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]pipeline.build"]
-                            fn wit_import(_: i32);
+                            #[link_name = "[method]timer.get-timeout"]
+                            fn wit_import(_: i32) -> f32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> f32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret
+                    }
+                }
+            }
+            impl Timer {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Repeating timer, ticks every time the interval expires
+                pub fn set_interval(&self, seconds: f32) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]timer.set-interval"]
+                            fn wit_import(_: i32, _: f32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: f32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, _rt::as_f32(&seconds));
+                    }
+                }
+            }
+            impl Timer {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_interval(&self) -> f32 {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]timer.get-interval"]
+                            fn wit_import(_: i32) -> f32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32) -> f32 {
+                            unreachable!()
+                        }
+                        let ret = wit_import((self).handle() as i32);
+                        ret
+                    }
+                }
+            }
+            impl Timer {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Rate filter, ticks every `rate` ticks of the source (frames if no source)
+                pub fn set_rate(&self, rate: i32, source: Option<EcsEntityT>) {
+                    unsafe {
+                        let (result0_0, result0_1) = match source {
+                            Some(e) => (1i32, _rt::as_i64(e)),
+                            None => (0i32, 0i64),
+                        };
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                        extern "C" {
+                            #[link_name = "[method]timer.set-rate"]
+                            fn wit_import(_: i32, _: i32, _: i32, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        fn wit_import(_: i32, _: i32, _: i32, _: i64) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i32(&rate),
+                            result0_0,
+                            result0_1,
+                        );
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn add_phase(&self, phase: EcsEntityT) {
+                pub fn start(&self) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]pipeline.add-phase"]
-                            fn wit_import(_: i32, _: i64);
+                            #[link_name = "[method]timer.start"]
+                            fn wit_import(_: i32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64) {
+                        fn wit_import(_: i32) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(phase));
+                        wit_import((self).handle() as i32);
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn get_id(&self) -> EcsEntityT {
+                pub fn stop(&self) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]pipeline.get-id"]
-                            fn wit_import(_: i32) -> i64;
+                            #[link_name = "[method]timer.stop"]
+                            fn wit_import(_: i32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i64 {
+                        fn wit_import(_: i32) {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
-                        ret as u64
+                        wit_import((self).handle() as i32);
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn disable(&self) {
+                pub fn reset(&self) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]pipeline.disable"]
+                            #[link_name = "[method]timer.reset"]
                             fn wit_import(_: i32);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
@@ -3262,21 +4368,26 @@ pub mod toxoid_component {
                     }
                 }
             }
-            impl Pipeline {
+            impl Timer {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn enable(&self) {
+                /// Emit a custom event on the target entity every time the timer ticks
+                pub fn fire(&self, event: EcsEntityT, target: EcsEntityT) {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "toxoid-component:component/ecs")]
                         extern "C" {
-                            #[link_name = "[method]pipeline.enable"]
-                            fn wit_import(_: i32);
+                            #[link_name = "[method]timer.fire"]
+                            fn wit_import(_: i32, _: i64, _: i64);
                         }
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        fn wit_import(_: i32, _: i64, _: i64) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        wit_import(
+                            (self).handle() as i32,
+                            _rt::as_i64(event),
+                            _rt::as_i64(target),
+                        );
                     }
                 }
             }
@@ -3307,8 +4418,8 @@ pub mod toxoid_component {
                         let vec5 = events0;
                         let len5 = vec5.len();
                         let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
-                            vec5.len() * 1,
-                            1,
+                            vec5.len() * 16,
+                            8,
                         );
                         let result5 = if layout5.size() != 0 {
                             let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
@@ -3320,9 +4431,35 @@ pub mod toxoid_component {
                             ::core::ptr::null_mut()
                         };
                         for (i, e) in vec5.into_iter().enumerate() {
-                            let base = result5.add(i * 1);
+                            let base = result5.add(i * 16);
                             {
-                                *base.add(0).cast::<u8>() = (e.clone() as i32) as u8;
+                                match e {
+                                    Event::OnSet => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    }
+                                    Event::OnAdd => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    }
+                                    Event::OnRemove => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    }
+                                    Event::OnDelete => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    }
+                                    Event::OnDeleteTarget => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    }
+                                    Event::OnTableCreate => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    }
+                                    Event::OnTableDelete => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    }
+                                    Event::Custom(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                    }
+                                }
                             }
                         }
                         #[cfg(target_arch = "wasm32")]
@@ -3495,8 +4632,73 @@ pub mod toxoid_component {
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
-            /// get-entity: func(entity: ecs-entity-t) -> entity;
-            /// get-entity-named: func(name: string) -> entity;
+            pub fn get_entity(entity: EcsEntityT) -> Option<Entity> {
+                unsafe {
+                    #[repr(align(4))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "get-entity"]
+                        fn wit_import(_: i64, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(_rt::as_i64(entity), ptr0);
+                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                    match l1 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l2 = *ptr0.add(4).cast::<i32>();
+                                Entity::from_handle(l2 as u32)
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn get_entity_named(name: &str) -> Option<Entity> {
+                unsafe {
+                    #[repr(align(4))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    let vec0 = name;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "get-entity-named"]
+                        fn wit_import(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(ptr0.cast_mut(), len0, ptr1);
+                    let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                    match l2 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l3 = *ptr1.add(4).cast::<i32>();
+                                Entity::from_handle(l3 as u32)
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
             pub fn has_entity_named(name: &str) -> bool {
                 unsafe {
                     let vec0 = name;
@@ -3517,6 +4719,40 @@ pub mod toxoid_component {
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
+            pub fn set_scope(scope: EcsEntityT) -> EcsEntityT {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "set-scope"]
+                        fn wit_import(_: i64) -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64) -> i64 {
+                        unreachable!()
+                    }
+                    let ret = wit_import(_rt::as_i64(scope));
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn get_scope() -> EcsEntityT {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "get-scope"]
+                        fn wit_import() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = wit_import();
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
             pub fn get_component_id(component_name: &str) -> EcsEntityT {
                 unsafe {
                     let vec0 = component_name;
@@ -3536,6 +4772,321 @@ pub mod toxoid_component {
                     ret as u64
                 }
             }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn make_pair(
+                relationship: EcsEntityT,
+                target: EcsEntityT,
+            ) -> EcsEntityT {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "make-pair"]
+                        fn wit_import(_: i64, _: i64) -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64, _: i64) -> i64 {
+                        unreachable!()
+                    }
+                    let ret = wit_import(_rt::as_i64(relationship), _rt::as_i64(target));
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn spawn_batch(
+                count: u32,
+                components: &[EcsEntityT],
+            ) -> _rt::Vec<EcsEntityT> {
+                unsafe {
+                    #[repr(align(4))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    let vec0 = components;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "spawn-batch"]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(_rt::as_i32(&count), ptr0.cast_mut(), len0, ptr1);
+                    let l2 = *ptr1.add(0).cast::<*mut u8>();
+                    let l3 = *ptr1.add(4).cast::<usize>();
+                    let len4 = l3;
+                    _rt::Vec::from_raw_parts(l2.cast(), len4, len4)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn delete_with(component: EcsEntityT) {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "delete-with"]
+                        fn wit_import(_: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64) {
+                        unreachable!()
+                    }
+                    wit_import(_rt::as_i64(component));
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn script_init(filename: &str, code: &str) -> EcsEntityT {
+                unsafe {
+                    let vec0 = filename;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec1 = code;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "script-init"]
+                        fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize) -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize) -> i64 {
+                        unreachable!()
+                    }
+                    let ret = wit_import(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1);
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn script_update(script: EcsEntityT, code: &str) -> bool {
+                unsafe {
+                    let vec0 = code;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "script-update"]
+                        fn wit_import(_: i64, _: *mut u8, _: usize) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64, _: *mut u8, _: usize) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import(_rt::as_i64(script), ptr0.cast_mut(), len0);
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn script_clear(script: EcsEntityT) {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "script-clear"]
+                        fn wit_import(_: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i64) {
+                        unreachable!()
+                    }
+                    wit_import(_rt::as_i64(script));
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn defer_begin() -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "defer-begin"]
+                        fn wit_import() -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import();
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn defer_end() -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "defer-end"]
+                        fn wit_import() -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import();
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn is_deferred() -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "is-deferred"]
+                        fn wit_import() -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import();
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn readonly_begin(multi_threaded: bool) -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "readonly-begin"]
+                        fn wit_import(_: i32) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import(
+                        match &multi_threaded {
+                            true => 1,
+                            false => 0,
+                        },
+                    );
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn readonly_end() {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "readonly-end"]
+                        fn wit_import();
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() {
+                        unreachable!()
+                    }
+                    wit_import();
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn submit(commands: &[Command]) {
+                unsafe {
+                    let vec4 = commands;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 40,
+                        8,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 40);
+                        {
+                            match e {
+                                Command::Add(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let (t0_0, t0_1) = e;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(t0_0);
+                                    *base.add(16).cast::<i64>() = _rt::as_i64(t0_1);
+                                }
+                                Command::Remove(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let (t1_0, t1_1) = e;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(t1_0);
+                                    *base.add(16).cast::<i64>() = _rt::as_i64(t1_1);
+                                }
+                                Command::AddRelationship(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    let (t2_0, t2_1, t2_2) = e;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(t2_0);
+                                    match t2_1 {
+                                        Relationship::IsA => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                        Relationship::ChildOf => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        Relationship::Custom(e) => {
+                                            *base.add(16).cast::<u8>() = (2i32) as u8;
+                                            *base.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                    }
+                                    *base.add(32).cast::<i64>() = _rt::as_i64(t2_2);
+                                }
+                                Command::RemoveRelationship(e) => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    let (t3_0, t3_1, t3_2) = e;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(t3_0);
+                                    match t3_1 {
+                                        Relationship::IsA => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                        Relationship::ChildOf => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        Relationship::Custom(e) => {
+                                            *base.add(16).cast::<u8>() = (2i32) as u8;
+                                            *base.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                    }
+                                    *base.add(32).cast::<i64>() = _rt::as_i64(t3_2);
+                                }
+                                Command::Enable(e) => {
+                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Command::Disable(e) => {
+                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Command::Delete(e) => {
+                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    }
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "toxoid-component:component/ecs")]
+                    extern "C" {
+                        #[link_name = "submit"]
+                        fn wit_import(_: *mut u8, _: usize);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8, _: usize) {
+                        unreachable!()
+                    }
+                    wit_import(result4, len4);
+                    if layout4.size() != 0 {
+                        _rt::alloc::dealloc(result4.cast(), layout4);
+                    }
+                }
+            }
         }
     }
 }
@@ -3802,6 +5353,13 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            core::hint::unreachable_unchecked()
+        }
+    }
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -3844,9 +5402,9 @@ pub use __export_toxoid_component_world_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.35.0:toxoid-component:component:toxoid-component-world:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 6778] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xed3\x01A\x02\x01A\x07\
-\x01B\x9a\x02\x01w\x04\0\x0cecs-entity-t\x03\0\0\x01w\x04\0\x09pointer-t\x03\0\x02\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 9212] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xefF\x01A\x02\x01A\x07\
+\x01B\x8a\x03\x01w\x04\0\x0cecs-entity-t\x03\0\0\x01w\x04\0\x09pointer-t\x03\0\x02\
 \x01q\x03\x04is-a\0\0\x08child-of\0\0\x06custom\x01\x01\0\x04\0\x0crelationship\x03\
 \0\x04\x01q\x0a\x08on-start\0\0\x07on-load\0\0\x09post-load\0\0\x0apre-update\0\0\
 \x09on-update\0\0\x0bon-validate\0\0\x0bpost-update\0\0\x09pre-store\0\0\x08on-s\
@@ -3854,132 +5412,187 @@ tore\0\0\x06custom\x01\x01\0\x04\0\x06phases\x03\0\x06\x01m\x18\x04u8-t\x05u16-t
 \x05u32-t\x05u64-t\x04i8-t\x05i16-t\x05i32-t\x05i64-t\x05f32-t\x05f64-t\x06bool-\
 t\x08string-t\x06list-t\x08u8list-t\x09u16list-t\x09u32list-t\x09u64list-t\x08i8\
 list-t\x09i16list-t\x09i32list-t\x09i64list-t\x09f32list-t\x09f64list-t\x09point\
-er-t\x04\0\x0bmember-type\x03\0\x08\x01m\x07\x06on-set\x06on-add\x09on-remove\x09\
-on-delete\x10on-delete-target\x0fon-table-create\x0fon-table-delete\x04\0\x05eve\
-nt\x03\0\x0a\x01ps\x01p}\x01r\x03\x04names\x0cmember-names\x0c\x0cmember-types\x0d\
-\x04\0\x0ecomponent-desc\x03\0\x0e\x01ks\x01p\x01\x01k\x11\x01r\x03\x04name\x10\x03\
-add\x12\x06prefab\x7f\x04\0\x0bentity-desc\x03\0\x13\x01r\x01\x04exprs\x04\0\x0a\
-query-desc\x03\0\x15\x04\0\x0ecomponent-type\x03\x01\x01r\x03\x04names\x0aquery-\
-desc\x16\x06phases\x11\x04\0\x0dpipeline-desc\x03\0\x18\x04\0\x09component\x03\x01\
-\x04\0\x06entity\x03\x01\x04\0\x05query\x03\x01\x04\0\x04iter\x03\x01\x04\0\x08c\
-allback\x03\x01\x01i\x1e\x01r\x02\x02id\x01\x08callback\x1f\x04\0\x0csorting-des\
-c\x03\0\x20\x01kz\x01r\x05\x04name\x10\x09tick-rate\"\x08callback\x1f\x0aquery-d\
-esc\x16\x08is-guest\x7f\x04\0\x0bsystem-desc\x03\0#\x01p\x0b\x01r\x05\x04name\x10\
-\x0aquery-desc\x16\x06events%\x08callback\x1f\x08is-guest\x7f\x04\0\x0dobserver-\
-desc\x03\0&\x04\0\x06system\x03\x01\x04\0\x05phase\x03\x01\x04\0\x08pipeline\x03\
-\x01\x04\0\x08observer\x03\x01\x01i\x17\x01@\x01\x04init\x0f\0,\x04\0\x1b[constr\
-uctor]component-type\x01-\x01h\x17\x01@\x01\x04self.\0\x01\x04\0\x1d[method]comp\
-onent-type.get-id\x01/\x01i\x1a\x01@\x03\x03ptr\x03\x06entity\x01\x0ecomponent-t\
-ype\x01\00\x04\0\x16[constructor]component\x011\x01@\x01\x03ptr\x03\00\x04\0\x1a\
-[static]component.from-ptr\x012\x01h\x1a\x01@\x03\x04self3\x06offsety\x05value}\x01\
-\0\x04\0\x1f[method]component.set-member-u8\x014\x01@\x02\x04self3\x06offsety\0}\
-\x04\0\x1f[method]component.get-member-u8\x015\x01@\x03\x04self3\x06offsety\x05v\
-alue{\x01\0\x04\0\x20[method]component.set-member-u16\x016\x01@\x02\x04self3\x06\
-offsety\0{\x04\0\x20[method]component.get-member-u16\x017\x01@\x03\x04self3\x06o\
-ffsety\x05valuey\x01\0\x04\0\x20[method]component.set-member-u32\x018\x01@\x02\x04\
-self3\x06offsety\0y\x04\0\x20[method]component.get-member-u32\x019\x01@\x03\x04s\
-elf3\x06offsety\x05valuew\x01\0\x04\0\x20[method]component.set-member-u64\x01:\x01\
-@\x02\x04self3\x06offsety\0w\x04\0\x20[method]component.get-member-u64\x01;\x01@\
-\x03\x04self3\x06offsety\x05value~\x01\0\x04\0\x1f[method]component.set-member-i\
-8\x01<\x01@\x02\x04self3\x06offsety\0~\x04\0\x1f[method]component.get-member-i8\x01\
-=\x01@\x03\x04self3\x06offsety\x05value|\x01\0\x04\0\x20[method]component.set-me\
-mber-i16\x01>\x01@\x02\x04self3\x06offsety\0|\x04\0\x20[method]component.get-mem\
-ber-i16\x01?\x01@\x03\x04self3\x06offsety\x05valuez\x01\0\x04\0\x20[method]compo\
-nent.set-member-i32\x01@\x01@\x02\x04self3\x06offsety\0z\x04\0\x20[method]compon\
-ent.get-member-i32\x01A\x01@\x03\x04self3\x06offsety\x05valuex\x01\0\x04\0\x20[m\
-ethod]component.set-member-i64\x01B\x01@\x02\x04self3\x06offsety\0x\x04\0\x20[me\
-thod]component.get-member-i64\x01C\x01@\x03\x04self3\x06offsety\x05valuev\x01\0\x04\
-\0\x20[method]component.set-member-f32\x01D\x01@\x02\x04self3\x06offsety\0v\x04\0\
-\x20[method]component.get-member-f32\x01E\x01@\x03\x04self3\x06offsety\x05valueu\
-\x01\0\x04\0\x20[method]component.set-member-f64\x01F\x01@\x02\x04self3\x06offse\
-ty\0u\x04\0\x20[method]component.get-member-f64\x01G\x01@\x03\x04self3\x06offset\
-y\x05value\x7f\x01\0\x04\0![method]component.set-member-bool\x01H\x01@\x02\x04se\
-lf3\x06offsety\0\x7f\x04\0![method]component.get-member-bool\x01I\x01@\x03\x04se\
-lf3\x06offsety\x05values\x01\0\x04\0#[method]component.set-member-string\x01J\x01\
-@\x02\x04self3\x06offsety\0s\x04\0#[method]component.get-member-string\x01K\x01@\
-\x03\x04self3\x06offsety\x05value\x0d\x01\0\x04\0#[method]component.set-member-u\
-8list\x01L\x01@\x02\x04self3\x06offsety\0\x0d\x04\0#[method]component.get-member\
--u8list\x01M\x01p{\x01@\x03\x04self3\x06offsety\x05value\xce\0\x01\0\x04\0$[meth\
-od]component.set-member-u16list\x01O\x01@\x02\x04self3\x06offsety\0\xce\0\x04\0$\
-[method]component.get-member-u16list\x01P\x01py\x01@\x03\x04self3\x06offsety\x05\
-value\xd1\0\x01\0\x04\0$[method]component.set-member-u32list\x01R\x01@\x02\x04se\
-lf3\x06offsety\0\xd1\0\x04\0$[method]component.get-member-u32list\x01S\x01pw\x01\
-@\x03\x04self3\x06offsety\x05value\xd4\0\x01\0\x04\0$[method]component.set-membe\
-r-u64list\x01U\x01@\x02\x04self3\x06offsety\0\xd4\0\x04\0$[method]component.get-\
-member-u64list\x01V\x01p~\x01@\x03\x04self3\x06offsety\x05value\xd7\0\x01\0\x04\0\
-#[method]component.set-member-i8list\x01X\x01@\x02\x04self3\x06offsety\0\xd7\0\x04\
-\0#[method]component.get-member-i8list\x01Y\x01p|\x01@\x03\x04self3\x06offsety\x05\
-value\xda\0\x01\0\x04\0$[method]component.set-member-i16list\x01[\x01@\x02\x04se\
-lf3\x06offsety\0\xda\0\x04\0$[method]component.get-member-i16list\x01\\\x01pz\x01\
-@\x03\x04self3\x06offsety\x05value\xdd\0\x01\0\x04\0$[method]component.set-membe\
-r-i32list\x01^\x01@\x02\x04self3\x06offsety\0\xdd\0\x04\0$[method]component.get-\
-member-i32list\x01_\x01px\x01@\x03\x04self3\x06offsety\x05value\xe0\0\x01\0\x04\0\
-$[method]component.set-member-i64list\x01a\x01@\x02\x04self3\x06offsety\0\xe0\0\x04\
-\0$[method]component.get-member-i64list\x01b\x01pv\x01@\x03\x04self3\x06offsety\x05\
-value\xe3\0\x01\0\x04\0$[method]component.set-member-f32list\x01d\x01@\x02\x04se\
-lf3\x06offsety\0\xe3\0\x04\0$[method]component.get-member-f32list\x01e\x01pu\x01\
-@\x03\x04self3\x06offsety\x05value\xe6\0\x01\0\x04\0$[method]component.set-membe\
-r-f64list\x01g\x01@\x02\x04self3\x06offsety\0\xe6\0\x04\0$[method]component.get-\
-member-f64list\x01h\x04\0$[method]component.set-member-pointer\x01:\x04\0$[metho\
-d]component.get-member-pointer\x01;\x01k\x01\x01i\x1b\x01@\x02\x04init\x14\x08in\
-herits\xe9\0\0\xea\0\x04\0\x13[constructor]entity\x01k\x01@\x01\x02idw\0\xea\0\x04\
-\0\x16[static]entity.from-id\x01l\x01h\x1b\x01@\x01\x04self\xed\0\0\x01\x04\0\x15\
-[method]entity.get-id\x01n\x01@\x01\x04self\xed\0\0s\x04\0\x17[method]entity.get\
--name\x01o\x01@\x02\x04self\xed\0\x04names\x01\0\x04\0\x17[method]entity.set-nam\
-e\x01p\x01@\x02\x04self\xed\0\x09component\x01\00\x04\0\x12[method]entity.get\x01\
-q\x01@\x02\x04self\xed\0\x09component\x01\x01\0\x04\0\x12[method]entity.add\x01r\
-\x01@\x02\x04self\xed\0\x09component\x01\0\x7f\x04\0\x12[method]entity.has\x01s\x04\
-\0\x15[method]entity.remove\x01r\x01@\x03\x04self\xed\0\x0crelationship\x05\x06t\
-arget\x01\x01\0\x04\0\x1f[method]entity.add-relationship\x01t\x04\0\"[method]ent\
-ity.remove-relationship\x01t\x01@\x02\x04self\xed\0\x06target\x01\x01\0\x04\0\x18\
-[method]entity.parent-of\x01u\x04\0\x17[method]entity.child-of\x01u\x01@\x01\x04\
-self\xed\0\0\xea\0\x04\0\x15[method]entity.parent\x01v\x01p\xea\0\x01@\x01\x04se\
-lf\xed\0\0\xf7\0\x04\0\x17[method]entity.children\x01x\x04\0\x1c[method]entity.r\
-elationships\x01x\x01@\x01\x04self\xed\0\x01\0\x04\0\x16[method]entity.disable\x01\
-y\x04\0\x15[method]entity.enable\x01y\x01i\x1c\x01@\x01\x04desc\x16\0\xfa\0\x04\0\
-\x12[constructor]query\x01{\x01h\x1c\x01@\x01\x04self\xfc\0\x01\0\x04\0\x13[meth\
-od]query.build\x01}\x01@\x02\x04self\xfc\0\x07sorting!\x01\0\x04\0\x16[method]qu\
-ery.order-by\x01~\x01i\x1d\x01@\x01\x04self\xfc\0\0\xff\0\x04\0\x12[method]query\
-.iter\x01\x80\x01\x01@\x01\x04self\xfc\0\0\x7f\x04\0\x12[method]query.next\x01\x81\
-\x01\x01@\x01\x04self\xfc\0\0z\x04\0\x13[method]query.count\x01\x82\x01\x01@\x01\
-\x04self\xfc\0\0\xf7\0\x04\0\x16[method]query.entities\x01\x83\x01\x01p\x03\x01@\
-\x02\x04self\xfc\0\x05index~\0\x84\x01\x04\0\x18[method]query.components\x01\x85\
-\x01\x01@\x01\x03ptrw\0\xff\0\x04\0\x11[constructor]iter\x01\x86\x01\x01h\x1d\x01\
-@\x01\x04self\x87\x01\0\x7f\x04\0\x11[method]iter.next\x01\x88\x01\x01@\x01\x04s\
-elf\x87\x01\0z\x04\0\x12[method]iter.count\x01\x89\x01\x01@\x01\x04self\x87\x01\0\
-\xf7\0\x04\0\x15[method]iter.entities\x01\x8a\x01\x01@\x02\x04self\x87\x01\x05in\
-dex~\0\x84\x01\x04\0\x17[method]iter.components\x01\x8b\x01\x01@\x01\x06handlew\0\
-\x1f\x04\0\x15[constructor]callback\x01\x8c\x01\x01h\x1e\x01@\x02\x04self\x8d\x01\
-\x04iter\xff\0\x01\0\x04\0\x14[method]callback.run\x01\x8e\x01\x01@\x01\x04self\x8d\
-\x01\0\x03\x04\0\x1a[method]callback.cb-handle\x01\x8f\x01\x01i(\x01@\x01\x04des\
-c$\0\x90\x01\x04\0\x13[constructor]system\x01\x91\x01\x01h(\x01@\x01\x04self\x92\
-\x01\0\x01\x04\0\x15[method]system.get-id\x01\x93\x01\x01@\x01\x04self\x92\x01\x01\
-\0\x04\0\x14[method]system.build\x01\x94\x01\x01@\x02\x04self\x92\x01\x07sorting\
-!\x01\0\x04\0\x17[method]system.order-by\x01\x95\x01\x01@\x01\x04self\x92\x01\0\x1f\
-\x04\0\x17[method]system.callback\x01\x96\x01\x04\0\x16[method]system.disable\x01\
-\x94\x01\x04\0\x15[method]system.enable\x01\x94\x01\x01i)\x01@\x01\x04names\0\x97\
-\x01\x04\0\x12[constructor]phase\x01\x98\x01\x01h)\x01@\x02\x04self\x99\x01\x05p\
-hase\x07\x01\0\x04\0\x18[method]phase.depends-on\x01\x9a\x01\x01@\x01\x04self\x99\
-\x01\0\x01\x04\0\x14[method]phase.get-id\x01\x9b\x01\x01i*\x01@\x01\x04desc\x19\0\
-\x9c\x01\x04\0\x15[constructor]pipeline\x01\x9d\x01\x01h*\x01@\x01\x04self\x9e\x01\
-\x01\0\x04\0\x16[method]pipeline.build\x01\x9f\x01\x01@\x02\x04self\x9e\x01\x05p\
-hase\x01\x01\0\x04\0\x1a[method]pipeline.add-phase\x01\xa0\x01\x01@\x01\x04self\x9e\
-\x01\0\x01\x04\0\x17[method]pipeline.get-id\x01\xa1\x01\x04\0\x18[method]pipelin\
-e.disable\x01\x9f\x01\x04\0\x17[method]pipeline.enable\x01\x9f\x01\x01i+\x01@\x01\
-\x04desc'\0\xa2\x01\x04\0\x15[constructor]observer\x01\xa3\x01\x01h+\x01@\x01\x04\
-self\xa4\x01\x01\0\x04\0\x16[method]observer.build\x01\xa5\x01\x01@\x01\x04self\xa4\
-\x01\0\x1f\x04\0\x19[method]observer.callback\x01\xa6\x01\x01@\x01\x09component\x01\
-\x01\0\x04\0\x0dadd-singleton\x01\xa7\x01\x01@\x01\x09component\x01\00\x04\0\x0d\
-get-singleton\x01\xa8\x01\x04\0\x10remove-singleton\x01\xa7\x01\x01@\x01\x06enti\
-ty\x01\x01\0\x04\0\x0aadd-entity\x01\xa9\x01\x04\0\x0dremove-entity\x01\xa9\x01\x01\
-@\x01\x04names\0\x7f\x04\0\x10has-entity-named\x01\xaa\x01\x01@\x01\x0ecomponent\
--names\0\x01\x04\0\x10get-component-id\x01\xab\x01\x03\0\x1etoxoid-component:com\
-ponent/ecs\x05\0\x01@\0\x01\0\x04\0\x04init\x01\x01\x02\x03\0\0\x04iter\x01B\x05\
-\x02\x03\x02\x01\x02\x04\0\x04iter\x03\0\0\x01i\x01\x01@\x02\x04iter\x02\x06hand\
-lew\x01\0\x04\0\x03run\x01\x03\x04\0$toxoid-component:component/callbacks\x05\x03\
-\x04\01toxoid-component:component/toxoid-component-world\x04\0\x0b\x1c\x01\0\x16\
-toxoid-component-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-co\
-mponent\x070.220.0\x10wit-bindgen-rust\x060.35.0";
+er-t\x04\0\x0bmember-type\x03\0\x08\x01q\x08\x06on-set\0\0\x06on-add\0\0\x09on-r\
+emove\0\0\x09on-delete\0\0\x10on-delete-target\0\0\x0fon-table-create\0\0\x0fon-\
+table-delete\0\0\x06custom\x01\x01\0\x04\0\x05event\x03\0\x0a\x01o\x02\x01\x01\x01\
+o\x03\x01\x05\x01\x01q\x07\x03add\x01\x0c\0\x06remove\x01\x0c\0\x10add-relations\
+hip\x01\x0d\0\x13remove-relationship\x01\x0d\0\x06enable\x01\x01\0\x07disable\x01\
+\x01\0\x06delete\x01\x01\0\x04\0\x07command\x03\0\x0e\x01ps\x01p}\x01r\x03\x04na\
+mes\x0cmember-names\x10\x0cmember-types\x11\x04\0\x0ecomponent-desc\x03\0\x12\x01\
+ks\x01p\x01\x01k\x15\x01r\x03\x04name\x14\x03add\x16\x06prefab\x7f\x04\0\x0benti\
+ty-desc\x03\0\x17\x01r\x01\x04exprs\x04\0\x0aquery-desc\x03\0\x19\x04\0\x0ecompo\
+nent-type\x03\x01\x01r\x03\x04names\x0aquery-desc\x1a\x06phases\x15\x04\0\x0dpip\
+eline-desc\x03\0\x1c\x04\0\x09component\x03\x01\x04\0\x06entity\x03\x01\x04\0\x05\
+query\x03\x01\x04\0\x04iter\x03\x01\x04\0\x08callback\x03\x01\x01i\"\x01r\x02\x02\
+id\x01\x08callback#\x04\0\x0csorting-desc\x03\0$\x01kz\x01k\x01\x01kv\x01r\x0a\x04\
+name\x14\x09tick-rate&\x08callback#\x0aquery-desc\x1a\x08is-guest\x7f\x05phase'\x08\
+interval(\x0btick-source'\x0emulti-threaded\x7f\x09immediate\x7f\x04\0\x0bsystem\
+-desc\x03\0)\x01p\x0b\x01r\x05\x04name\x14\x0aquery-desc\x1a\x06events+\x08callb\
+ack#\x08is-guest\x7f\x04\0\x0dobserver-desc\x03\0,\x04\0\x06system\x03\x01\x04\0\
+\x05phase\x03\x01\x04\0\x08pipeline\x03\x01\x04\0\x06prefab\x03\x01\x04\0\x05tim\
+er\x03\x01\x04\0\x08observer\x03\x01\x01i\x1b\x01@\x01\x04init\x13\04\x04\0\x1b[\
+constructor]component-type\x015\x01h\x1b\x01@\x01\x04self6\0\x01\x04\0\x1d[metho\
+d]component-type.get-id\x017\x01i\x1e\x01@\x03\x03ptr\x03\x06entity\x01\x0ecompo\
+nent-type\x01\08\x04\0\x16[constructor]component\x019\x01@\x01\x03ptr\x03\08\x04\
+\0\x1a[static]component.from-ptr\x01:\x01h\x1e\x01@\x03\x04self;\x06offsety\x05v\
+alue}\x01\0\x04\0\x1f[method]component.set-member-u8\x01<\x01@\x02\x04self;\x06o\
+ffsety\0}\x04\0\x1f[method]component.get-member-u8\x01=\x01@\x03\x04self;\x06off\
+sety\x05value{\x01\0\x04\0\x20[method]component.set-member-u16\x01>\x01@\x02\x04\
+self;\x06offsety\0{\x04\0\x20[method]component.get-member-u16\x01?\x01@\x03\x04s\
+elf;\x06offsety\x05valuey\x01\0\x04\0\x20[method]component.set-member-u32\x01@\x01\
+@\x02\x04self;\x06offsety\0y\x04\0\x20[method]component.get-member-u32\x01A\x01@\
+\x03\x04self;\x06offsety\x05valuew\x01\0\x04\0\x20[method]component.set-member-u\
+64\x01B\x01@\x02\x04self;\x06offsety\0w\x04\0\x20[method]component.get-member-u6\
+4\x01C\x01@\x03\x04self;\x06offsety\x05value~\x01\0\x04\0\x1f[method]component.s\
+et-member-i8\x01D\x01@\x02\x04self;\x06offsety\0~\x04\0\x1f[method]component.get\
+-member-i8\x01E\x01@\x03\x04self;\x06offsety\x05value|\x01\0\x04\0\x20[method]co\
+mponent.set-member-i16\x01F\x01@\x02\x04self;\x06offsety\0|\x04\0\x20[method]com\
+ponent.get-member-i16\x01G\x01@\x03\x04self;\x06offsety\x05valuez\x01\0\x04\0\x20\
+[method]component.set-member-i32\x01H\x01@\x02\x04self;\x06offsety\0z\x04\0\x20[\
+method]component.get-member-i32\x01I\x01@\x03\x04self;\x06offsety\x05valuex\x01\0\
+\x04\0\x20[method]component.set-member-i64\x01J\x01@\x02\x04self;\x06offsety\0x\x04\
+\0\x20[method]component.get-member-i64\x01K\x01@\x03\x04self;\x06offsety\x05valu\
+ev\x01\0\x04\0\x20[method]component.set-member-f32\x01L\x01@\x02\x04self;\x06off\
+sety\0v\x04\0\x20[method]component.get-member-f32\x01M\x01@\x03\x04self;\x06offs\
+ety\x05valueu\x01\0\x04\0\x20[method]component.set-member-f64\x01N\x01@\x02\x04s\
+elf;\x06offsety\0u\x04\0\x20[method]component.get-member-f64\x01O\x01@\x03\x04se\
+lf;\x06offsety\x05value\x7f\x01\0\x04\0![method]component.set-member-bool\x01P\x01\
+@\x02\x04self;\x06offsety\0\x7f\x04\0![method]component.get-member-bool\x01Q\x01\
+@\x03\x04self;\x06offsety\x05values\x01\0\x04\0#[method]component.set-member-str\
+ing\x01R\x01@\x02\x04self;\x06offsety\0s\x04\0#[method]component.get-member-stri\
+ng\x01S\x01@\x03\x04self;\x06offsety\x05value\x11\x01\0\x04\0#[method]component.\
+set-member-u8list\x01T\x01@\x02\x04self;\x06offsety\0\x11\x04\0#[method]componen\
+t.get-member-u8list\x01U\x01p{\x01@\x03\x04self;\x06offsety\x05value\xd6\0\x01\0\
+\x04\0$[method]component.set-member-u16list\x01W\x01@\x02\x04self;\x06offsety\0\xd6\
+\0\x04\0$[method]component.get-member-u16list\x01X\x01py\x01@\x03\x04self;\x06of\
+fsety\x05value\xd9\0\x01\0\x04\0$[method]component.set-member-u32list\x01Z\x01@\x02\
+\x04self;\x06offsety\0\xd9\0\x04\0$[method]component.get-member-u32list\x01[\x01\
+pw\x01@\x03\x04self;\x06offsety\x05value\xdc\0\x01\0\x04\0$[method]component.set\
+-member-u64list\x01]\x01@\x02\x04self;\x06offsety\0\xdc\0\x04\0$[method]componen\
+t.get-member-u64list\x01^\x01p~\x01@\x03\x04self;\x06offsety\x05value\xdf\0\x01\0\
+\x04\0#[method]component.set-member-i8list\x01`\x01@\x02\x04self;\x06offsety\0\xdf\
+\0\x04\0#[method]component.get-member-i8list\x01a\x01p|\x01@\x03\x04self;\x06off\
+sety\x05value\xe2\0\x01\0\x04\0$[method]component.set-member-i16list\x01c\x01@\x02\
+\x04self;\x06offsety\0\xe2\0\x04\0$[method]component.get-member-i16list\x01d\x01\
+pz\x01@\x03\x04self;\x06offsety\x05value\xe5\0\x01\0\x04\0$[method]component.set\
+-member-i32list\x01f\x01@\x02\x04self;\x06offsety\0\xe5\0\x04\0$[method]componen\
+t.get-member-i32list\x01g\x01px\x01@\x03\x04self;\x06offsety\x05value\xe8\0\x01\0\
+\x04\0$[method]component.set-member-i64list\x01i\x01@\x02\x04self;\x06offsety\0\xe8\
+\0\x04\0$[method]component.get-member-i64list\x01j\x01pv\x01@\x03\x04self;\x06of\
+fsety\x05value\xeb\0\x01\0\x04\0$[method]component.set-member-f32list\x01l\x01@\x02\
+\x04self;\x06offsety\0\xeb\0\x04\0$[method]component.get-member-f32list\x01m\x01\
+pu\x01@\x03\x04self;\x06offsety\x05value\xee\0\x01\0\x04\0$[method]component.set\
+-member-f64list\x01o\x01@\x02\x04self;\x06offsety\0\xee\0\x04\0$[method]componen\
+t.get-member-f64list\x01p\x04\0$[method]component.set-member-pointer\x01B\x04\0$\
+[method]component.get-member-pointer\x01C\x01i\x1f\x01@\x02\x04init\x18\x08inher\
+its'\0\xf1\0\x04\0\x13[constructor]entity\x01r\x01@\x01\x02idw\0\xf1\0\x04\0\x16\
+[static]entity.from-id\x01s\x01h\x1f\x01@\x01\x04self\xf4\0\0\x01\x04\0\x15[meth\
+od]entity.get-id\x01u\x01@\x01\x04self\xf4\0\0s\x04\0\x17[method]entity.get-name\
+\x01v\x01@\x02\x04self\xf4\0\x04names\x01\0\x04\0\x17[method]entity.set-name\x01\
+w\x01@\x02\x04self\xf4\0\x09component\x01\08\x04\0\x12[method]entity.get\x01x\x01\
+@\x02\x04self\xf4\0\x09component\x01\x01\0\x04\0\x12[method]entity.add\x01y\x01@\
+\x02\x04self\xf4\0\x09component\x01\0\x7f\x04\0\x12[method]entity.has\x01z\x04\0\
+\x15[method]entity.remove\x01y\x01@\x03\x04self\xf4\0\x0crelationship\x05\x06tar\
+get\x01\x01\0\x04\0\x1f[method]entity.add-relationship\x01{\x04\0\"[method]entit\
+y.remove-relationship\x01{\x01@\x02\x04self\xf4\0\x06target\x01\x01\0\x04\0\x18[\
+method]entity.parent-of\x01|\x04\0\x17[method]entity.child-of\x01|\x01@\x01\x04s\
+elf\xf4\0\0\xf1\0\x04\0\x15[method]entity.parent\x01}\x01p\xf1\0\x01@\x01\x04sel\
+f\xf4\0\0\xfe\0\x04\0\x17[method]entity.children\x01\x7f\x04\0\x1c[method]entity\
+.relationships\x01\x7f\x04\0\x17[method]entity.get-path\x01v\x01k\xf1\0\x01@\x02\
+\x04self\xf4\0\x04paths\0\x80\x01\x04\0\x15[method]entity.lookup\x01\x81\x01\x01\
+@\x03\x04self\xf4\0\x0crelationship\x05\x05indexz\0\x80\x01\x04\0\x15[method]ent\
+ity.target\x01\x82\x01\x01@\x02\x04self\xf4\0\x0crelationship\x05\0\xfe\0\x04\0\x16\
+[method]entity.targets\x01\x83\x01\x01@\x03\x04self\xf4\0\x09component\x01\x04js\
+ons\0\x7f\x04\0\x17[method]entity.set-json\x01\x84\x01\x01@\x02\x04self\xf4\0\x04\
+jsons\0\x7f\x04\0\x18[method]entity.from-json\x01\x85\x01\x01@\x01\x04self\xf4\0\
+\x01\0\x04\0\x16[method]entity.disable\x01\x86\x01\x04\0\x15[method]entity.enabl\
+e\x01\x86\x01\x01i\x20\x01@\x01\x04desc\x1a\0\x87\x01\x04\0\x12[constructor]quer\
+y\x01\x88\x01\x01h\x20\x01@\x01\x04self\x89\x01\x01\0\x04\0\x13[method]query.bui\
+ld\x01\x8a\x01\x01@\x02\x04self\x89\x01\x07sorting%\x01\0\x04\0\x16[method]query\
+.order-by\x01\x8b\x01\x01i!\x01@\x01\x04self\x89\x01\0\x8c\x01\x04\0\x12[method]\
+query.iter\x01\x8d\x01\x01@\x01\x04self\x89\x01\0\x7f\x04\0\x12[method]query.nex\
+t\x01\x8e\x01\x01@\x01\x04self\x89\x01\0z\x04\0\x13[method]query.count\x01\x8f\x01\
+\x01@\x01\x04self\x89\x01\0\xfe\0\x04\0\x16[method]query.entities\x01\x90\x01\x01\
+p\x03\x01@\x02\x04self\x89\x01\x05index~\0\x91\x01\x04\0\x18[method]query.compon\
+ents\x01\x92\x01\x04\0\x1c[method]query.detect-changes\x01\x8a\x01\x04\0\x15[met\
+hod]query.changed\x01\x8e\x01\x01@\x03\x04self\x89\x01\x04names\x05value\x01\x01\
+\0\x04\0\x15[method]query.set-var\x01\x93\x01\x01@\x02\x04self\x89\x01\x04names\0\
+\x01\x04\0\x15[method]query.get-var\x01\x94\x01\x01@\x02\x04self\x89\x01\x05inde\
+x~\0\x7f\x04\0\x14[method]query.is-set\x01\x95\x01\x01@\x02\x04self\x89\x01\x05i\
+ndex~\0\x01\x04\0\x17[method]query.field-src\x01\x96\x01\x01@\x01\x03ptrw\0\x8c\x01\
+\x04\0\x11[constructor]iter\x01\x97\x01\x01h!\x01@\x01\x04self\x98\x01\0\x7f\x04\
+\0\x11[method]iter.next\x01\x99\x01\x01@\x01\x04self\x98\x01\0z\x04\0\x12[method\
+]iter.count\x01\x9a\x01\x01@\x01\x04self\x98\x01\0\xfe\0\x04\0\x15[method]iter.e\
+ntities\x01\x9b\x01\x01@\x02\x04self\x98\x01\x05index~\0\x91\x01\x04\0\x17[metho\
+d]iter.components\x01\x9c\x01\x01@\x02\x04self\x98\x01\x04names\0\x01\x04\0\x14[\
+method]iter.get-var\x01\x9d\x01\x01@\x02\x04self\x98\x01\x05index~\0\x7f\x04\0\x13\
+[method]iter.is-set\x01\x9e\x01\x01@\x02\x04self\x98\x01\x05index~\0\x01\x04\0\x16\
+[method]iter.field-src\x01\x9f\x01\x04\0\x14[method]iter.changed\x01\x99\x01\x01\
+@\x01\x06handlew\0#\x04\0\x15[constructor]callback\x01\xa0\x01\x01h\"\x01@\x02\x04\
+self\xa1\x01\x04iter\x8c\x01\x01\0\x04\0\x14[method]callback.run\x01\xa2\x01\x01\
+@\x01\x04self\xa1\x01\0\x03\x04\0\x1a[method]callback.cb-handle\x01\xa3\x01\x01i\
+.\x01@\x01\x04desc*\0\xa4\x01\x04\0\x13[constructor]system\x01\xa5\x01\x01h.\x01\
+@\x01\x04self\xa6\x01\0\x01\x04\0\x15[method]system.get-id\x01\xa7\x01\x01@\x01\x04\
+self\xa6\x01\x01\0\x04\0\x14[method]system.build\x01\xa8\x01\x01@\x02\x04self\xa6\
+\x01\x07sorting%\x01\0\x04\0\x17[method]system.order-by\x01\xa9\x01\x01@\x01\x04\
+self\xa6\x01\0#\x04\0\x17[method]system.callback\x01\xaa\x01\x04\0\x16[method]sy\
+stem.disable\x01\xa8\x01\x04\0\x15[method]system.enable\x01\xa8\x01\x01@\x02\x04\
+self\xa6\x01\x05phase\x07\x01\0\x04\0\x14[method]system.phase\x01\xab\x01\x01@\x02\
+\x04self\xa6\x01\x07secondsv\x01\0\x04\0\x17[method]system.interval\x01\xac\x01\x04\
+\0\x1d[method]system.multi-threaded\x01\xa8\x01\x04\0\x18[method]system.immediat\
+e\x01\xa8\x01\x01@\x02\x04self\xa6\x01\x06source\x01\x01\0\x04\0\x1a[method]syst\
+em.tick-source\x01\xad\x01\x01i/\x01@\x01\x04names\0\xae\x01\x04\0\x12[construct\
+or]phase\x01\xaf\x01\x01h/\x01@\x02\x04self\xb0\x01\x05phase\x07\x01\0\x04\0\x18\
+[method]phase.depends-on\x01\xb1\x01\x01@\x01\x04self\xb0\x01\0\x01\x04\0\x14[me\
+thod]phase.get-id\x01\xb2\x01\x01i0\x01@\x01\x04desc\x1d\0\xb3\x01\x04\0\x15[con\
+structor]pipeline\x01\xb4\x01\x01h0\x01@\x01\x04self\xb5\x01\x01\0\x04\0\x16[met\
+hod]pipeline.build\x01\xb6\x01\x01@\x02\x04self\xb5\x01\x05phase\x01\x01\0\x04\0\
+\x1a[method]pipeline.add-phase\x01\xb7\x01\x01@\x01\x04self\xb5\x01\0\x01\x04\0\x17\
+[method]pipeline.get-id\x01\xb8\x01\x04\0\x18[method]pipeline.disable\x01\xb6\x01\
+\x04\0\x17[method]pipeline.enable\x01\xb6\x01\x01i1\x01@\x02\x04name\x14\x06pare\
+nt'\0\xb9\x01\x04\0\x13[constructor]prefab\x01\xba\x01\x01h1\x01@\x01\x04self\xbb\
+\x01\0\x01\x04\0\x15[method]prefab.get-id\x01\xbc\x01\x01@\x02\x04self\xbb\x01\x09\
+component\x01\x01\0\x04\0\x12[method]prefab.add\x01\xbd\x01\x01@\x02\x04self\xbb\
+\x01\x09component\x01\08\x04\0\x12[method]prefab.get\x01\xbe\x01\x04\0![method]p\
+refab.override-component\x01\xbd\x01\x04\0\x20[method]prefab.inherit-component\x01\
+\xbd\x01\x01@\x02\x04self\xbb\x01\x04base\x01\x01\0\x04\0\x13[method]prefab.is-a\
+\x01\xbf\x01\x01@\x01\x04self\xbb\x01\0\x7f\x04\0\x13[method]prefab.slot\x01\xc0\
+\x01\x01@\x02\x04self\xbb\x01\x05county\0\x15\x04\0\x1a[method]prefab.instantiat\
+e\x01\xc1\x01\x01i2\x01@\0\0\xc2\x01\x04\0\x12[constructor]timer\x01\xc3\x01\x01\
+h2\x01@\x01\x04self\xc4\x01\0\x01\x04\0\x14[method]timer.get-id\x01\xc5\x01\x01@\
+\x02\x04self\xc4\x01\x07secondsv\x01\0\x04\0\x19[method]timer.set-timeout\x01\xc6\
+\x01\x01@\x01\x04self\xc4\x01\0v\x04\0\x19[method]timer.get-timeout\x01\xc7\x01\x04\
+\0\x1a[method]timer.set-interval\x01\xc6\x01\x04\0\x1a[method]timer.get-interval\
+\x01\xc7\x01\x01@\x03\x04self\xc4\x01\x04ratez\x06source'\x01\0\x04\0\x16[method\
+]timer.set-rate\x01\xc8\x01\x01@\x01\x04self\xc4\x01\x01\0\x04\0\x13[method]time\
+r.start\x01\xc9\x01\x04\0\x12[method]timer.stop\x01\xc9\x01\x04\0\x13[method]tim\
+er.reset\x01\xc9\x01\x01@\x03\x04self\xc4\x01\x05event\x01\x06target\x01\x01\0\x04\
+\0\x12[method]timer.fire\x01\xca\x01\x01i3\x01@\x01\x04desc-\0\xcb\x01\x04\0\x15\
+[constructor]observer\x01\xcc\x01\x01h3\x01@\x01\x04self\xcd\x01\x01\0\x04\0\x16\
+[method]observer.build\x01\xce\x01\x01@\x01\x04self\xcd\x01\0#\x04\0\x19[method]\
+observer.callback\x01\xcf\x01\x01@\x01\x09component\x01\x01\0\x04\0\x0dadd-singl\
+eton\x01\xd0\x01\x01@\x01\x09component\x01\08\x04\0\x0dget-singleton\x01\xd1\x01\
+\x04\0\x10remove-singleton\x01\xd0\x01\x01@\x01\x06entity\x01\x01\0\x04\0\x0aadd\
+-entity\x01\xd2\x01\x04\0\x0dremove-entity\x01\xd2\x01\x01@\x01\x06entity\x01\0\x80\
+\x01\x04\0\x0aget-entity\x01\xd3\x01\x01@\x01\x04names\0\x80\x01\x04\0\x10get-en\
+tity-named\x01\xd4\x01\x01@\x01\x04names\0\x7f\x04\0\x10has-entity-named\x01\xd5\
+\x01\x01@\x01\x05scope\x01\0\x01\x04\0\x09set-scope\x01\xd6\x01\x01@\0\0\x01\x04\
+\0\x09get-scope\x01\xd7\x01\x01@\x01\x0ecomponent-names\0\x01\x04\0\x10get-compo\
+nent-id\x01\xd8\x01\x01@\x02\x0crelationship\x01\x06target\x01\0\x01\x04\0\x09ma\
+ke-pair\x01\xd9\x01\x01@\x02\x05county\x0acomponents\x15\0\x15\x04\0\x0bspawn-ba\
+tch\x01\xda\x01\x04\0\x0bdelete-with\x01\xd0\x01\x01@\x02\x08filenames\x04codes\0\
+\x01\x04\0\x0bscript-init\x01\xdb\x01\x01@\x02\x06script\x01\x04codes\0\x7f\x04\0\
+\x0dscript-update\x01\xdc\x01\x01@\x01\x06script\x01\x01\0\x04\0\x0cscript-clear\
+\x01\xdd\x01\x01@\0\0\x7f\x04\0\x0bdefer-begin\x01\xde\x01\x04\0\x09defer-end\x01\
+\xde\x01\x04\0\x0bis-deferred\x01\xde\x01\x01@\x01\x0emulti-threaded\x7f\0\x7f\x04\
+\0\x0ereadonly-begin\x01\xdf\x01\x01@\0\x01\0\x04\0\x0creadonly-end\x01\xe0\x01\x01\
+p\x0f\x01@\x01\x08commands\xe1\x01\x01\0\x04\0\x06submit\x01\xe2\x01\x03\0\x1eto\
+xoid-component:component/ecs\x05\0\x01@\0\x01\0\x04\0\x04init\x01\x01\x02\x03\0\0\
+\x04iter\x01B\x05\x02\x03\x02\x01\x02\x04\0\x04iter\x03\0\0\x01i\x01\x01@\x02\x04\
+iter\x02\x06handlew\x01\0\x04\0\x03run\x01\x03\x04\0$toxoid-component:component/\
+callbacks\x05\x03\x04\01toxoid-component:component/toxoid-component-world\x04\0\x0b\
+\x1c\x01\0\x16toxoid-component-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\
+\x02\x0dwit-component\x070.220.0\x10wit-bindgen-rust\x060.35.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -222,8 +222,7 @@ pub mod exports {
                         }
                     }
                 }
-                #[repr(u8)]
-                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                #[derive(Clone, Copy)]
                 pub enum Event {
                     OnSet,
                     OnAdd,
@@ -232,6 +231,7 @@ pub mod exports {
                     OnDeleteTarget,
                     OnTableCreate,
                     OnTableDelete,
+                    Custom(EcsEntityT),
                 }
                 impl ::core::fmt::Debug for Event {
                     fn fmt(
@@ -252,24 +252,52 @@ pub mod exports {
                             Event::OnTableDelete => {
                                 f.debug_tuple("Event::OnTableDelete").finish()
                             }
+                            Event::Custom(e) => {
+                                f.debug_tuple("Event::Custom").field(e).finish()
+                            }
                         }
                     }
                 }
-                impl Event {
-                    #[doc(hidden)]
-                    pub unsafe fn _lift(val: u8) -> Event {
-                        if !cfg!(debug_assertions) {
-                            return ::core::mem::transmute(val);
-                        }
-                        match val {
-                            0 => Event::OnSet,
-                            1 => Event::OnAdd,
-                            2 => Event::OnRemove,
-                            3 => Event::OnDelete,
-                            4 => Event::OnDeleteTarget,
-                            5 => Event::OnTableCreate,
-                            6 => Event::OnTableDelete,
-                            _ => panic!("invalid enum discriminant"),
+                /// Structural change applied by `submit` in a single deferred scope
+                #[derive(Clone, Copy)]
+                pub enum Command {
+                    Add((EcsEntityT, EcsEntityT)),
+                    Remove((EcsEntityT, EcsEntityT)),
+                    AddRelationship((EcsEntityT, Relationship, EcsEntityT)),
+                    RemoveRelationship((EcsEntityT, Relationship, EcsEntityT)),
+                    Enable(EcsEntityT),
+                    Disable(EcsEntityT),
+                    Delete(EcsEntityT),
+                }
+                impl ::core::fmt::Debug for Command {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Command::Add(e) => {
+                                f.debug_tuple("Command::Add").field(e).finish()
+                            }
+                            Command::Remove(e) => {
+                                f.debug_tuple("Command::Remove").field(e).finish()
+                            }
+                            Command::AddRelationship(e) => {
+                                f.debug_tuple("Command::AddRelationship").field(e).finish()
+                            }
+                            Command::RemoveRelationship(e) => {
+                                f.debug_tuple("Command::RemoveRelationship")
+                                    .field(e)
+                                    .finish()
+                            }
+                            Command::Enable(e) => {
+                                f.debug_tuple("Command::Enable").field(e).finish()
+                            }
+                            Command::Disable(e) => {
+                                f.debug_tuple("Command::Disable").field(e).finish()
+                            }
+                            Command::Delete(e) => {
+                                f.debug_tuple("Command::Delete").field(e).finish()
+                            }
                         }
                     }
                 }
@@ -345,6 +373,11 @@ pub mod exports {
                     pub callback: PointerT,
                     pub query_desc: QueryDesc,
                     pub is_guest: bool,
+                    pub phase: Option<EcsEntityT>,
+                    pub interval: Option<f32>,
+                    pub tick_source: Option<EcsEntityT>,
+                    pub multi_threaded: bool,
+                    pub immediate: bool,
                 }
                 impl ::core::fmt::Debug for SystemDesc {
                     fn fmt(
@@ -357,6 +390,11 @@ pub mod exports {
                             .field("callback", &self.callback)
                             .field("query-desc", &self.query_desc)
                             .field("is-guest", &self.is_guest)
+                            .field("phase", &self.phase)
+                            .field("interval", &self.interval)
+                            .field("tick-source", &self.tick_source)
+                            .field("multi-threaded", &self.multi_threaded)
+                            .field("immediate", &self.immediate)
                             .finish()
                     }
                 }
@@ -1480,6 +1518,244 @@ pub mod exports {
                 }
                 #[derive(Debug)]
                 #[repr(transparent)]
+                pub struct Prefab {
+                    handle: _rt::Resource<Prefab>,
+                }
+                type _PrefabRep<T> = Option<T>;
+                impl Prefab {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Prefab`.
+                    pub fn new<T: GuestPrefab>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _PrefabRep<T> = Some(val);
+                        let ptr: *mut _PrefabRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestPrefab>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestPrefab>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestPrefab>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _PrefabRep<T>);
+                    }
+                    fn as_ptr<T: GuestPrefab>(&self) -> *mut _PrefabRep<T> {
+                        Prefab::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Prefab`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct PrefabBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Prefab>,
+                }
+                impl<'a> PrefabBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestPrefab>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _PrefabRep<T> {
+                        Prefab::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Prefab {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]prefab"]
+                                fn drop(_: u32);
+                            }
+                            drop(_handle);
+                        }
+                    }
+                }
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Timer {
+                    handle: _rt::Resource<Timer>,
+                }
+                type _TimerRep<T> = Option<T>;
+                impl Timer {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Timer`.
+                    pub fn new<T: GuestTimer>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _TimerRep<T> = Some(val);
+                        let ptr: *mut _TimerRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestTimer>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestTimer>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestTimer>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _TimerRep<T>);
+                    }
+                    fn as_ptr<T: GuestTimer>(&self) -> *mut _TimerRep<T> {
+                        Timer::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Timer`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct TimerBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Timer>,
+                }
+                impl<'a> TimerBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestTimer>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _TimerRep<T> {
+                        Timer::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Timer {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]timer"]
+                                fn drop(_: u32);
+                            }
+                            drop(_handle);
+                        }
+                    }
+                }
+                #[derive(Debug)]
+                #[repr(transparent)]
                 pub struct Observer {
                     handle: _rt::Resource<Observer>,
                 }
@@ -2744,38 +3020,207 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_entity_disable_cabi<T: GuestEntity>(
+                pub unsafe fn _export_method_entity_get_path_cabi<T: GuestEntity>(
                     arg0: *mut u8,
-                ) {
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::disable(EntityBorrow::lift(arg0 as u32 as usize).get());
+                    let result0 = T::get_path(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_entity_enable_cabi<T: GuestEntity>(
+                pub unsafe fn __post_return_method_entity_get_path<T: GuestEntity>(
                     arg0: *mut u8,
                 ) {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::enable(EntityBorrow::lift(arg0 as u32 as usize).get());
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_constructor_query_cabi<T: GuestQuery>(
+                pub unsafe fn _export_method_entity_lookup_cabi<T: GuestEntity>(
                     arg0: *mut u8,
-                    arg1: usize,
-                ) -> i32 {
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let result1 = Query::new(
-                        T::new(QueryDesc {
-                            expr: _rt::string_lift(bytes0),
-                        }),
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::lookup(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
                     );
-                    (result1).take_handle() as i32
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Some(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        None => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_target_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i64,
+                    arg3: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let v0 = match arg1 {
+                        0 => Relationship::IsA,
+                        1 => Relationship::ChildOf,
+                        n => {
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            let e0 = arg2 as u64;
+                            Relationship::Custom(e0)
+                        }
+                    };
+                    let result1 = T::target(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                        v0,
+                        arg3,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Some(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        None => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_targets_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let v0 = match arg1 {
+                        0 => Relationship::IsA,
+                        1 => Relationship::ChildOf,
+                        n => {
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            let e0 = arg2 as u64;
+                            Relationship::Custom(e0)
+                        }
+                    };
+                    let result1 = T::targets(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                        v0,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec3 = (result1).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2.add(4).cast::<usize>() = len3;
+                    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_entity_targets<T: GuestEntity>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 8);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_set_json_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                    arg2: *mut u8,
+                    arg3: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg3;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg2.cast(), len0, len0);
+                    let result1 = T::set_json(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                        _rt::string_lift(bytes0),
+                    );
+                    match result1 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_from_json_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::from_json(
+                        EntityBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    match result1 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_disable_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::disable(EntityBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_entity_enable_cabi<T: GuestEntity>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::enable(EntityBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_query_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = Query::new(
+                        T::new(QueryDesc {
+                            expr: _rt::string_lift(bytes0),
+                        }),
+                    );
+                    (result1).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_method_query_build_cabi<T: GuestQuery>(
                     arg0: *mut u8,
                 ) {
@@ -2892,6 +3337,90 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_detect_changes_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::detect_changes(QueryBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_changed_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::changed(
+                        QueryBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_set_var_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    T::set_var(
+                        QueryBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_get_var_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get_var(
+                        QueryBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    _rt::as_i64(result1)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_is_set_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::is_set(
+                        QueryBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as i8,
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_query_field_src_cabi<T: GuestQuery>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::field_src(
+                        QueryBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as i8,
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_iter_cabi<T: GuestIter>(
                     arg0: i64,
                 ) -> i32 {
@@ -2982,6 +3511,65 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_method_iter_get_var_cabi<T: GuestIter>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get_var(
+                        IterBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    _rt::as_i64(result1)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_iter_is_set_cabi<T: GuestIter>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::is_set(
+                        IterBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as i8,
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_iter_field_src_cabi<T: GuestIter>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::field_src(
+                        IterBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as i8,
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_iter_changed_cabi<T: GuestIter>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::changed(
+                        IterBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_callback_cabi<T: GuestCallback>(
                     arg0: i64,
                 ) -> i32 {
@@ -3015,53 +3603,97 @@ pub mod exports {
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_system_cabi<T: GuestSystem>(
-                    arg0: i32,
-                    arg1: *mut u8,
-                    arg2: usize,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i64,
-                    arg6: *mut u8,
-                    arg7: usize,
-                    arg8: i32,
+                    arg0: *mut u8,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let len1 = arg7;
-                    let bytes1 = _rt::Vec::from_raw_parts(arg6.cast(), len1, len1);
-                    let result2 = System::new(
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l4 = i32::from(*arg0.add(12).cast::<u8>());
+                    let l6 = *arg0.add(24).cast::<i64>();
+                    let l7 = *arg0.add(32).cast::<*mut u8>();
+                    let l8 = *arg0.add(36).cast::<usize>();
+                    let len9 = l8;
+                    let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                    let l10 = i32::from(*arg0.add(40).cast::<u8>());
+                    let l11 = i32::from(*arg0.add(48).cast::<u8>());
+                    let l13 = i32::from(*arg0.add(64).cast::<u8>());
+                    let l15 = i32::from(*arg0.add(72).cast::<u8>());
+                    let l17 = i32::from(*arg0.add(88).cast::<u8>());
+                    let l18 = i32::from(*arg0.add(89).cast::<u8>());
+                    let result19 = System::new(
                         T::new(SystemDesc {
-                            name: match arg0 {
+                            name: match l0 {
                                 0 => None,
                                 1 => {
                                     let e = {
-                                        let len0 = arg2;
-                                        let bytes0 = _rt::Vec::from_raw_parts(
-                                            arg1.cast(),
-                                            len0,
-                                            len0,
+                                        let l1 = *arg0.add(4).cast::<*mut u8>();
+                                        let l2 = *arg0.add(8).cast::<usize>();
+                                        let len3 = l2;
+                                        let bytes3 = _rt::Vec::from_raw_parts(
+                                            l1.cast(),
+                                            len3,
+                                            len3,
                                         );
-                                        _rt::string_lift(bytes0)
+                                        _rt::string_lift(bytes3)
                                     };
                                     Some(e)
                                 }
                                 _ => _rt::invalid_enum_discriminant(),
                             },
-                            tick_rate: match arg3 {
+                            tick_rate: match l4 {
                                 0 => None,
                                 1 => {
-                                    let e = arg4;
+                                    let e = {
+                                        let l5 = *arg0.add(16).cast::<i32>();
+                                        l5
+                                    };
                                     Some(e)
                                 }
                                 _ => _rt::invalid_enum_discriminant(),
                             },
-                            callback: arg5 as u64,
+                            callback: l6 as u64,
                             query_desc: QueryDesc {
-                                expr: _rt::string_lift(bytes1),
+                                expr: _rt::string_lift(bytes9),
                             },
-                            is_guest: _rt::bool_lift(arg8 as u8),
+                            is_guest: _rt::bool_lift(l10 as u8),
+                            phase: match l11 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let l12 = *arg0.add(56).cast::<i64>();
+                                        l12 as u64
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            interval: match l13 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let l14 = *arg0.add(68).cast::<f32>();
+                                        l14
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            tick_source: match l15 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let l16 = *arg0.add(80).cast::<i64>();
+                                        l16 as u64
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            multi_threaded: _rt::bool_lift(l17 as u8),
+                            immediate: _rt::bool_lift(l18 as u8),
                         }),
                     );
-                    (result2).take_handle() as i32
+                    _rt::cabi_dealloc(arg0, 96, 8);
+                    (result19).take_handle() as i32
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -3127,6 +3759,69 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_method_system_phase_cabi<T: GuestSystem>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let v0 = match arg1 {
+                        0 => Phases::OnStart,
+                        1 => Phases::OnLoad,
+                        2 => Phases::PostLoad,
+                        3 => Phases::PreUpdate,
+                        4 => Phases::OnUpdate,
+                        5 => Phases::OnValidate,
+                        6 => Phases::PostUpdate,
+                        7 => Phases::PreStore,
+                        8 => Phases::OnStore,
+                        n => {
+                            debug_assert_eq!(n, 9, "invalid enum discriminant");
+                            let e0 = arg2 as u64;
+                            Phases::Custom(e0)
+                        }
+                    };
+                    T::phase(SystemBorrow::lift(arg0 as u32 as usize).get(), v0);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_system_interval_cabi<T: GuestSystem>(
+                    arg0: *mut u8,
+                    arg1: f32,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::interval(SystemBorrow::lift(arg0 as u32 as usize).get(), arg1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_system_multi_threaded_cabi<T: GuestSystem>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::multi_threaded(SystemBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_system_immediate_cabi<T: GuestSystem>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::immediate(SystemBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_system_tick_source_cabi<T: GuestSystem>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::tick_source(
+                        SystemBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_phase_cabi<T: GuestPhase>(
                     arg0: *mut u8,
                     arg1: usize,
@@ -3250,6 +3945,273 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_prefab_cabi<T: GuestPrefab>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: i64,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result1 = Prefab::new(
+                        T::new(
+                            match arg0 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len0 = arg2;
+                                        let bytes0 = _rt::Vec::from_raw_parts(
+                                            arg1.cast(),
+                                            len0,
+                                            len0,
+                                        );
+                                        _rt::string_lift(bytes0)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            match arg3 {
+                                0 => None,
+                                1 => {
+                                    let e = arg4 as u64;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        ),
+                    );
+                    (result1).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_get_id_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_id(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_add_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::add(PrefabBorrow::lift(arg0 as u32 as usize).get(), arg1 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_get_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_override_component_cabi<
+                    T: GuestPrefab,
+                >(arg0: *mut u8, arg1: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::override_component(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_inherit_component_cabi<
+                    T: GuestPrefab,
+                >(arg0: *mut u8, arg1: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::inherit_component(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_is_a_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::is_a(PrefabBorrow::lift(arg0 as u32 as usize).get(), arg1 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_slot_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::slot(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_prefab_instantiate_cabi<T: GuestPrefab>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::instantiate(
+                        PrefabBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_prefab_instantiate<T: GuestPrefab>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 8);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_timer_cabi<T: GuestTimer>() -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = Timer::new(T::new());
+                    (result0).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_get_id_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_id(
+                        TimerBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_set_timeout_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                    arg1: f32,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::set_timeout(TimerBorrow::lift(arg0 as u32 as usize).get(), arg1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_get_timeout_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) -> f32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_timeout(
+                        TimerBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    _rt::as_f32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_set_interval_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                    arg1: f32,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::set_interval(TimerBorrow::lift(arg0 as u32 as usize).get(), arg1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_get_interval_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) -> f32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_interval(
+                        TimerBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    _rt::as_f32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_set_rate_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::set_rate(
+                        TimerBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1,
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = arg3 as u64;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_start_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::start(TimerBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_stop_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::stop(TimerBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_reset_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::reset(TimerBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_timer_fire_cabi<T: GuestTimer>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                    arg2: i64,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::fire(
+                        TimerBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                        arg2 as u64,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_observer_cabi<T: GuestObserver>(
                     arg0: i32,
                     arg1: *mut u8,
@@ -3264,19 +4226,36 @@ pub mod exports {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let len1 = arg4;
                     let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
-                    let base3 = arg5;
-                    let len3 = arg6;
-                    let mut result3 = _rt::Vec::with_capacity(len3);
-                    for i in 0..len3 {
-                        let base = base3.add(i * 1);
-                        let e3 = {
+                    let base5 = arg5;
+                    let len5 = arg6;
+                    let mut result5 = _rt::Vec::with_capacity(len5);
+                    for i in 0..len5 {
+                        let base = base5.add(i * 16);
+                        let e5 = {
                             let l2 = i32::from(*base.add(0).cast::<u8>());
-                            Event::_lift(l2 as u8)
+                            let v4 = match l2 {
+                                0 => Event::OnSet,
+                                1 => Event::OnAdd,
+                                2 => Event::OnRemove,
+                                3 => Event::OnDelete,
+                                4 => Event::OnDeleteTarget,
+                                5 => Event::OnTableCreate,
+                                6 => Event::OnTableDelete,
+                                n => {
+                                    debug_assert_eq!(n, 7, "invalid enum discriminant");
+                                    let e4 = {
+                                        let l3 = *base.add(8).cast::<i64>();
+                                        l3 as u64
+                                    };
+                                    Event::Custom(e4)
+                                }
+                            };
+                            v4
                         };
-                        result3.push(e3);
+                        result5.push(e5);
                     }
-                    _rt::cabi_dealloc(base3, len3 * 1, 1);
-                    let result4 = Observer::new(
+                    _rt::cabi_dealloc(base5, len5 * 16, 8);
+                    let result6 = Observer::new(
                         T::new(ObserverDesc {
                             name: match arg0 {
                                 0 => None,
@@ -3297,89 +4276,379 @@ pub mod exports {
                             query_desc: QueryDesc {
                                 expr: _rt::string_lift(bytes1),
                             },
-                            events: result3,
+                            events: result5,
                             callback: arg7 as u64,
                             is_guest: _rt::bool_lift(arg8 as u8),
                         }),
                     );
-                    (result4).take_handle() as i32
+                    (result6).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_observer_build_cabi<T: GuestObserver>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::build(ObserverBorrow::lift(arg0 as u32 as usize).get());
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_observer_callback_cabi<T: GuestObserver>(
+                    arg0: *mut u8,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::callback(
+                        ObserverBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_singleton_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::add_singleton(arg0 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_singleton_cabi<T: Guest>(arg0: i64) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_singleton(arg0 as u64);
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_remove_singleton_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::remove_singleton(arg0 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_entity_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::add_entity(arg0 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_remove_entity_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::remove_entity(arg0 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_entity_cabi<T: Guest>(arg0: i64) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_entity(arg0 as u64);
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_entity_named_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::get_entity_named(_rt::string_lift(bytes0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Some(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        None => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_has_entity_named_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::has_entity_named(_rt::string_lift(bytes0));
+                    match result1 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_scope_cabi<T: Guest>(arg0: i64) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::set_scope(arg0 as u64);
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_scope_cabi<T: Guest>() -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_scope();
+                    _rt::as_i64(result0)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_observer_build_cabi<T: GuestObserver>(
+                pub unsafe fn _export_get_component_id_cabi<T: Guest>(
                     arg0: *mut u8,
-                ) {
+                    arg1: usize,
+                ) -> i64 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::build(ObserverBorrow::lift(arg0 as u32 as usize).get());
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::get_component_id(_rt::string_lift(bytes0));
+                    _rt::as_i64(result1)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_observer_callback_cabi<T: GuestObserver>(
+                pub unsafe fn _export_make_pair_cabi<T: Guest>(
+                    arg0: i64,
+                    arg1: i64,
+                ) -> i64 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::make_pair(arg0 as u64, arg1 as u64);
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_spawn_batch_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::spawn_batch(
+                        arg0 as u32,
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec3 = (result1).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2.add(4).cast::<usize>() = len3;
+                    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_spawn_batch<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 8);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_delete_with_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::delete_with(arg0 as u64);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_script_init_cabi<T: Guest>(
                     arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
                 ) -> i64 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::callback(
-                        ObserverBorrow::lift(arg0 as u32 as usize).get(),
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let len1 = arg3;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+                    let result2 = T::script_init(
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
                     );
-                    _rt::as_i64(result0)
+                    _rt::as_i64(result2)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_add_singleton_cabi<T: Guest>(arg0: i64) {
+                pub unsafe fn _export_script_update_cabi<T: Guest>(
+                    arg0: i64,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::add_singleton(arg0 as u64);
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::script_update(
+                        arg0 as u64,
+                        _rt::string_lift(bytes0),
+                    );
+                    match result1 {
+                        true => 1,
+                        false => 0,
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_get_singleton_cabi<T: Guest>(arg0: i64) -> i64 {
+                pub unsafe fn _export_script_clear_cabi<T: Guest>(arg0: i64) {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::get_singleton(arg0 as u64);
-                    _rt::as_i64(result0)
+                    T::script_clear(arg0 as u64);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_remove_singleton_cabi<T: Guest>(arg0: i64) {
+                pub unsafe fn _export_defer_begin_cabi<T: Guest>() -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::remove_singleton(arg0 as u64);
+                    let result0 = T::defer_begin();
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_add_entity_cabi<T: Guest>(arg0: i64) {
+                pub unsafe fn _export_defer_end_cabi<T: Guest>() -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::add_entity(arg0 as u64);
+                    let result0 = T::defer_end();
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_remove_entity_cabi<T: Guest>(arg0: i64) {
+                pub unsafe fn _export_is_deferred_cabi<T: Guest>() -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::remove_entity(arg0 as u64);
+                    let result0 = T::is_deferred();
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_has_entity_named_cabi<T: Guest>(
-                    arg0: *mut u8,
-                    arg1: usize,
-                ) -> i32 {
+                pub unsafe fn _export_readonly_begin_cabi<T: Guest>(arg0: i32) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let result1 = T::has_entity_named(_rt::string_lift(bytes0));
-                    match result1 {
+                    let result0 = T::readonly_begin(_rt::bool_lift(arg0 as u8));
+                    match result0 {
                         true => 1,
                         false => 0,
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_get_component_id_cabi<T: Guest>(
-                    arg0: *mut u8,
-                    arg1: usize,
-                ) -> i64 {
+                pub unsafe fn _export_readonly_end_cabi<T: Guest>() {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let result1 = T::get_component_id(_rt::string_lift(bytes0));
-                    _rt::as_i64(result1)
+                    T::readonly_end();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_submit_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base19 = arg0;
+                    let len19 = arg1;
+                    let mut result19 = _rt::Vec::with_capacity(len19);
+                    for i in 0..len19 {
+                        let base = base19.add(i * 40);
+                        let e19 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let v18 = match l0 {
+                                0 => {
+                                    let e18 = {
+                                        let l1 = *base.add(8).cast::<i64>();
+                                        let l2 = *base.add(16).cast::<i64>();
+                                        (l1 as u64, l2 as u64)
+                                    };
+                                    Command::Add(e18)
+                                }
+                                1 => {
+                                    let e18 = {
+                                        let l3 = *base.add(8).cast::<i64>();
+                                        let l4 = *base.add(16).cast::<i64>();
+                                        (l3 as u64, l4 as u64)
+                                    };
+                                    Command::Remove(e18)
+                                }
+                                2 => {
+                                    let e18 = {
+                                        let l5 = *base.add(8).cast::<i64>();
+                                        let l6 = i32::from(*base.add(16).cast::<u8>());
+                                        let v8 = match l6 {
+                                            0 => Relationship::IsA,
+                                            1 => Relationship::ChildOf,
+                                            n => {
+                                                debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                                let e8 = {
+                                                    let l7 = *base.add(24).cast::<i64>();
+                                                    l7 as u64
+                                                };
+                                                Relationship::Custom(e8)
+                                            }
+                                        };
+                                        let l9 = *base.add(32).cast::<i64>();
+                                        (l5 as u64, v8, l9 as u64)
+                                    };
+                                    Command::AddRelationship(e18)
+                                }
+                                3 => {
+                                    let e18 = {
+                                        let l10 = *base.add(8).cast::<i64>();
+                                        let l11 = i32::from(*base.add(16).cast::<u8>());
+                                        let v13 = match l11 {
+                                            0 => Relationship::IsA,
+                                            1 => Relationship::ChildOf,
+                                            n => {
+                                                debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                                let e13 = {
+                                                    let l12 = *base.add(24).cast::<i64>();
+                                                    l12 as u64
+                                                };
+                                                Relationship::Custom(e13)
+                                            }
+                                        };
+                                        let l14 = *base.add(32).cast::<i64>();
+                                        (l10 as u64, v13, l14 as u64)
+                                    };
+                                    Command::RemoveRelationship(e18)
+                                }
+                                4 => {
+                                    let e18 = {
+                                        let l15 = *base.add(8).cast::<i64>();
+                                        l15 as u64
+                                    };
+                                    Command::Enable(e18)
+                                }
+                                5 => {
+                                    let e18 = {
+                                        let l16 = *base.add(8).cast::<i64>();
+                                        l16 as u64
+                                    };
+                                    Command::Disable(e18)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 6, "invalid enum discriminant");
+                                    let e18 = {
+                                        let l17 = *base.add(8).cast::<i64>();
+                                        l17 as u64
+                                    };
+                                    Command::Delete(e18)
+                                }
+                            };
+                            v18
+                        };
+                        result19.push(e19);
+                    }
+                    _rt::cabi_dealloc(base19, len19 * 40, 8);
+                    T::submit(result19);
                 }
                 pub trait Guest {
                     type ComponentType: GuestComponentType;
@@ -3391,16 +4660,41 @@ pub mod exports {
                     type System: GuestSystem;
                     type Phase: GuestPhase;
                     type Pipeline: GuestPipeline;
+                    type Prefab: GuestPrefab;
+                    type Timer: GuestTimer;
                     type Observer: GuestObserver;
                     fn add_singleton(component_id: EcsEntityT);
                     fn get_singleton(component_id: EcsEntityT) -> u64;
                     fn remove_singleton(component_id: EcsEntityT);
                     fn add_entity(entity_id: EcsEntityT);
                     fn remove_entity(entity_id: EcsEntityT);
-                    /// get-entity: func(entity-id: ecs-entity-t) -> u64;
-                    /// get-entity-named: func(name: string) -> u64;
+                    fn get_entity(entity_id: EcsEntityT) -> Option<EcsEntityT>;
+                    fn get_entity_named(name: _rt::String) -> Option<EcsEntityT>;
                     fn has_entity_named(name: _rt::String) -> bool;
+                    fn set_scope(scope: EcsEntityT) -> EcsEntityT;
+                    fn get_scope() -> EcsEntityT;
                     fn get_component_id(component_name: _rt::String) -> EcsEntityT;
+                    fn make_pair(
+                        relationship: EcsEntityT,
+                        target: EcsEntityT,
+                    ) -> EcsEntityT;
+                    fn spawn_batch(
+                        count: u32,
+                        components: _rt::Vec<EcsEntityT>,
+                    ) -> _rt::Vec<EcsEntityT>;
+                    fn delete_with(component: EcsEntityT);
+                    fn script_init(
+                        filename: _rt::String,
+                        code: _rt::String,
+                    ) -> EcsEntityT;
+                    fn script_update(script: EcsEntityT, code: _rt::String) -> bool;
+                    fn script_clear(script: EcsEntityT);
+                    fn defer_begin() -> bool;
+                    fn defer_end() -> bool;
+                    fn is_deferred() -> bool;
+                    fn readonly_begin(multi_threaded: bool) -> bool;
+                    fn readonly_end();
+                    fn submit(commands: _rt::Vec<Command>);
                 }
                 pub trait GuestComponentType: 'static {
                     #[doc(hidden)]
@@ -3607,6 +4901,19 @@ pub mod exports {
                     fn parent(&self) -> EcsEntityT;
                     fn children(&self) -> _rt::Vec<EcsEntityT>;
                     fn relationships(&self) -> _rt::Vec<EcsEntityT>;
+                    fn get_path(&self) -> _rt::String;
+                    fn lookup(&self, path: _rt::String) -> Option<EcsEntityT>;
+                    fn target(
+                        &self,
+                        relationship: Relationship,
+                        index: i32,
+                    ) -> Option<EcsEntityT>;
+                    fn targets(
+                        &self,
+                        relationship: Relationship,
+                    ) -> _rt::Vec<EcsEntityT>;
+                    fn set_json(&self, component: EcsEntityT, json: _rt::String) -> bool;
+                    fn from_json(&self, json: _rt::String) -> bool;
                     fn disable(&self);
                     fn enable(&self);
                 }
@@ -3659,6 +4966,12 @@ pub mod exports {
                     fn count(&self) -> i32;
                     fn entities(&self) -> _rt::Vec<EcsEntityT>;
                     fn components(&self, index: i8) -> _rt::Vec<PointerT>;
+                    fn detect_changes(&self);
+                    fn changed(&self) -> bool;
+                    fn set_var(&self, name: _rt::String, value: EcsEntityT);
+                    fn get_var(&self, name: _rt::String) -> EcsEntityT;
+                    fn is_set(&self, index: i8) -> bool;
+                    fn field_src(&self, index: i8) -> EcsEntityT;
                 }
                 pub trait GuestIter: 'static {
                     #[doc(hidden)]
@@ -3706,6 +5019,10 @@ pub mod exports {
                     fn count(&self) -> i32;
                     fn entities(&self) -> _rt::Vec<EcsEntityT>;
                     fn components(&self, index: i8) -> _rt::Vec<PointerT>;
+                    fn get_var(&self, name: _rt::String) -> EcsEntityT;
+                    fn is_set(&self, index: i8) -> bool;
+                    fn field_src(&self, index: i8) -> EcsEntityT;
+                    fn changed(&self) -> bool;
                 }
                 pub trait GuestCallback: 'static {
                     #[doc(hidden)]
@@ -3801,6 +5118,11 @@ pub mod exports {
                     fn callback(&self) -> PointerT;
                     fn disable(&self);
                     fn enable(&self);
+                    fn phase(&self, phase: Phases);
+                    fn interval(&self, seconds: f32);
+                    fn multi_threaded(&self);
+                    fn immediate(&self);
+                    fn tick_source(&self, source: EcsEntityT);
                 }
                 pub trait GuestPhase: 'static {
                     #[doc(hidden)]
@@ -3895,6 +5217,118 @@ pub mod exports {
                     fn disable(&self);
                     fn enable(&self);
                 }
+                pub trait GuestPrefab: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-new]prefab"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]prefab"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    fn new(
+                        name: Option<_rt::String>,
+                        parent: Option<EcsEntityT>,
+                    ) -> Self;
+                    fn get_id(&self) -> EcsEntityT;
+                    fn add(&self, component: EcsEntityT);
+                    fn get(&self, component: EcsEntityT) -> PointerT;
+                    fn override_component(&self, component: EcsEntityT);
+                    fn inherit_component(&self, component: EcsEntityT);
+                    fn is_a(&self, base: EcsEntityT);
+                    /// False when the prefab has no parent to be a slot of
+                    fn slot(&self) -> bool;
+                    fn instantiate(&self, count: u32) -> _rt::Vec<EcsEntityT>;
+                }
+                pub trait GuestTimer: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-new]timer"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]toxoid:engine/ecs")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]timer"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    fn new() -> Self;
+                    fn get_id(&self) -> EcsEntityT;
+                    /// One-shot timer, ticks once after the timeout expires
+                    fn set_timeout(&self, seconds: f32);
+                    fn get_timeout(&self) -> f32;
+                    /// Repeating timer, ticks every time the interval expires
+                    fn set_interval(&self, seconds: f32);
+                    fn get_interval(&self) -> f32;
+                    /// Rate filter, ticks every `rate` ticks of the source (frames if no source)
+                    fn set_rate(&self, rate: i32, source: Option<EcsEntityT>);
+                    fn start(&self);
+                    fn stop(&self);
+                    fn reset(&self);
+                    /// Emit a custom event on the target entity every time the timer ticks
+                    fn fire(&self, event: EcsEntityT, target: EcsEntityT);
+                }
                 pub trait GuestObserver: 'static {
                     #[doc(hidden)]
                     unsafe fn _resource_new(val: *mut u8) -> u32
@@ -4414,10 +5848,50 @@ pub mod exports {
                         $($path_to_types)*::
                         __post_return_method_entity_relationships::<<$ty as
                         $($path_to_types)*:: Guest >::Entity > (arg0) } #[export_name =
-                        "toxoid:engine/ecs#[method]entity.disable"] unsafe extern "C" fn
-                        export_method_entity_disable(arg0 : * mut u8,) {
-                        $($path_to_types)*:: _export_method_entity_disable_cabi::<<$ty as
+                        "toxoid:engine/ecs#[method]entity.get-path"] unsafe extern "C" fn
+                        export_method_entity_get_path(arg0 : * mut u8,) -> * mut u8 {
+                        $($path_to_types)*:: _export_method_entity_get_path_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::Entity > (arg0) } #[export_name
+                        = "cabi_post_toxoid:engine/ecs#[method]entity.get-path"] unsafe
+                        extern "C" fn _post_return_method_entity_get_path(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_entity_get_path::<<$ty as
+                        $($path_to_types)*:: Guest >::Entity > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]entity.lookup"] unsafe extern "C" fn
+                        export_method_entity_lookup(arg0 : * mut u8, arg1 : * mut u8,
+                        arg2 : usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_entity_lookup_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Entity > (arg0, arg1, arg2) } #[export_name =
+                        "toxoid:engine/ecs#[method]entity.target"] unsafe extern "C" fn
+                        export_method_entity_target(arg0 : * mut u8, arg1 : i32, arg2 :
+                        i64, arg3 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_entity_target_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Entity > (arg0, arg1, arg2, arg3) } #[export_name =
+                        "toxoid:engine/ecs#[method]entity.targets"] unsafe extern "C" fn
+                        export_method_entity_targets(arg0 : * mut u8, arg1 : i32, arg2 :
+                        i64,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_entity_targets_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Entity > (arg0, arg1, arg2) } #[export_name =
+                        "cabi_post_toxoid:engine/ecs#[method]entity.targets"] unsafe
+                        extern "C" fn _post_return_method_entity_targets(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_entity_targets::<<$ty as
                         $($path_to_types)*:: Guest >::Entity > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]entity.set-json"] unsafe extern "C" fn
+                        export_method_entity_set_json(arg0 : * mut u8, arg1 : i64, arg2 :
+                        * mut u8, arg3 : usize,) -> i32 { $($path_to_types)*::
+                        _export_method_entity_set_json_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Entity > (arg0, arg1, arg2, arg3) }
+                        #[export_name = "toxoid:engine/ecs#[method]entity.from-json"]
+                        unsafe extern "C" fn export_method_entity_from_json(arg0 : * mut
+                        u8, arg1 : * mut u8, arg2 : usize,) -> i32 { $($path_to_types)*::
+                        _export_method_entity_from_json_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Entity > (arg0, arg1, arg2) }
+                        #[export_name = "toxoid:engine/ecs#[method]entity.disable"]
+                        unsafe extern "C" fn export_method_entity_disable(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        _export_method_entity_disable_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Entity > (arg0) } #[export_name =
                         "toxoid:engine/ecs#[method]entity.enable"] unsafe extern "C" fn
                         export_method_entity_enable(arg0 : * mut u8,) {
                         $($path_to_types)*:: _export_method_entity_enable_cabi::<<$ty as
@@ -4467,8 +5941,36 @@ pub mod exports {
                         u8,) { $($path_to_types)*::
                         __post_return_method_query_components::<<$ty as
                         $($path_to_types)*:: Guest >::Query > (arg0) } #[export_name =
-                        "toxoid:engine/ecs#[constructor]iter"] unsafe extern "C" fn
-                        export_constructor_iter(arg0 : i64,) -> i32 {
+                        "toxoid:engine/ecs#[method]query.detect-changes"] unsafe extern
+                        "C" fn export_method_query_detect_changes(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        _export_method_query_detect_changes_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Query > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]query.changed"] unsafe extern "C" fn
+                        export_method_query_changed(arg0 : * mut u8,) -> i32 {
+                        $($path_to_types)*:: _export_method_query_changed_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Query > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]query.set-var"] unsafe extern "C" fn
+                        export_method_query_set_var(arg0 : * mut u8, arg1 : * mut u8,
+                        arg2 : usize, arg3 : i64,) { $($path_to_types)*::
+                        _export_method_query_set_var_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Query > (arg0, arg1, arg2, arg3) } #[export_name =
+                        "toxoid:engine/ecs#[method]query.get-var"] unsafe extern "C" fn
+                        export_method_query_get_var(arg0 : * mut u8, arg1 : * mut u8,
+                        arg2 : usize,) -> i64 { $($path_to_types)*::
+                        _export_method_query_get_var_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Query > (arg0, arg1, arg2) } #[export_name =
+                        "toxoid:engine/ecs#[method]query.is-set"] unsafe extern "C" fn
+                        export_method_query_is_set(arg0 : * mut u8, arg1 : i32,) -> i32 {
+                        $($path_to_types)*:: _export_method_query_is_set_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Query > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[method]query.field-src"]
+                        unsafe extern "C" fn export_method_query_field_src(arg0 : * mut
+                        u8, arg1 : i32,) -> i64 { $($path_to_types)*::
+                        _export_method_query_field_src_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Query > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[constructor]iter"] unsafe
+                        extern "C" fn export_constructor_iter(arg0 : i64,) -> i32 {
                         $($path_to_types)*:: _export_constructor_iter_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Iter > (arg0) } #[export_name =
                         "toxoid:engine/ecs#[method]iter.next"] unsafe extern "C" fn
@@ -4497,6 +5999,24 @@ pub mod exports {
                         u8,) { $($path_to_types)*::
                         __post_return_method_iter_components::<<$ty as
                         $($path_to_types)*:: Guest >::Iter > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]iter.get-var"] unsafe extern "C" fn
+                        export_method_iter_get_var(arg0 : * mut u8, arg1 : * mut u8, arg2
+                        : usize,) -> i64 { $($path_to_types)*::
+                        _export_method_iter_get_var_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Iter > (arg0, arg1, arg2) } #[export_name =
+                        "toxoid:engine/ecs#[method]iter.is-set"] unsafe extern "C" fn
+                        export_method_iter_is_set(arg0 : * mut u8, arg1 : i32,) -> i32 {
+                        $($path_to_types)*:: _export_method_iter_is_set_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Iter > (arg0, arg1) } #[export_name
+                        = "toxoid:engine/ecs#[method]iter.field-src"] unsafe extern "C"
+                        fn export_method_iter_field_src(arg0 : * mut u8, arg1 : i32,) ->
+                        i64 { $($path_to_types)*::
+                        _export_method_iter_field_src_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Iter > (arg0, arg1) } #[export_name =
+                        "toxoid:engine/ecs#[method]iter.changed"] unsafe extern "C" fn
+                        export_method_iter_changed(arg0 : * mut u8,) -> i32 {
+                        $($path_to_types)*:: _export_method_iter_changed_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Iter > (arg0) } #[export_name =
                         "toxoid:engine/ecs#[constructor]callback"] unsafe extern "C" fn
                         export_constructor_callback(arg0 : i64,) -> i32 {
                         $($path_to_types)*:: _export_constructor_callback_cabi::<<$ty as
@@ -4511,12 +6031,9 @@ pub mod exports {
                         _export_method_callback_cb_handle_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Callback > (arg0) } #[export_name =
                         "toxoid:engine/ecs#[constructor]system"] unsafe extern "C" fn
-                        export_constructor_system(arg0 : i32, arg1 : * mut u8, arg2 :
-                        usize, arg3 : i32, arg4 : i32, arg5 : i64, arg6 : * mut u8, arg7
-                        : usize, arg8 : i32,) -> i32 { $($path_to_types)*::
-                        _export_constructor_system_cabi::<<$ty as $($path_to_types)*::
-                        Guest >::System > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
-                        arg7, arg8) } #[export_name =
+                        export_constructor_system(arg0 : * mut u8,) -> i32 {
+                        $($path_to_types)*:: _export_constructor_system_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::System > (arg0) } #[export_name =
                         "toxoid:engine/ecs#[method]system.get-id"] unsafe extern "C" fn
                         export_method_system_get_id(arg0 : * mut u8,) -> i64 {
                         $($path_to_types)*:: _export_method_system_get_id_cabi::<<$ty as
@@ -4543,13 +6060,38 @@ pub mod exports {
                         export_method_system_enable(arg0 : * mut u8,) {
                         $($path_to_types)*:: _export_method_system_enable_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::System > (arg0) } #[export_name =
-                        "toxoid:engine/ecs#[constructor]phase"] unsafe extern "C" fn
-                        export_constructor_phase(arg0 : * mut u8, arg1 : usize,) -> i32 {
-                        $($path_to_types)*:: _export_constructor_phase_cabi::<<$ty as
-                        $($path_to_types)*:: Guest >::Phase > (arg0, arg1) }
-                        #[export_name = "toxoid:engine/ecs#[method]phase.depends-on"]
-                        unsafe extern "C" fn export_method_phase_depends_on(arg0 : * mut
-                        u8, arg1 : i32, arg2 : i64,) { $($path_to_types)*::
+                        "toxoid:engine/ecs#[method]system.phase"] unsafe extern "C" fn
+                        export_method_system_phase(arg0 : * mut u8, arg1 : i32, arg2 :
+                        i64,) { $($path_to_types)*::
+                        _export_method_system_phase_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::System > (arg0, arg1, arg2) } #[export_name =
+                        "toxoid:engine/ecs#[method]system.interval"] unsafe extern "C" fn
+                        export_method_system_interval(arg0 : * mut u8, arg1 : f32,) {
+                        $($path_to_types)*:: _export_method_system_interval_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::System > (arg0, arg1) }
+                        #[export_name =
+                        "toxoid:engine/ecs#[method]system.multi-threaded"] unsafe extern
+                        "C" fn export_method_system_multi_threaded(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        _export_method_system_multi_threaded_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::System > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]system.immediate"] unsafe extern "C"
+                        fn export_method_system_immediate(arg0 : * mut u8,) {
+                        $($path_to_types)*:: _export_method_system_immediate_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::System > (arg0) } #[export_name
+                        = "toxoid:engine/ecs#[method]system.tick-source"] unsafe extern
+                        "C" fn export_method_system_tick_source(arg0 : * mut u8, arg1 :
+                        i64,) { $($path_to_types)*::
+                        _export_method_system_tick_source_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::System > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[constructor]phase"] unsafe
+                        extern "C" fn export_constructor_phase(arg0 : * mut u8, arg1 :
+                        usize,) -> i32 { $($path_to_types)*::
+                        _export_constructor_phase_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Phase > (arg0, arg1) } #[export_name =
+                        "toxoid:engine/ecs#[method]phase.depends-on"] unsafe extern "C"
+                        fn export_method_phase_depends_on(arg0 : * mut u8, arg1 : i32,
+                        arg2 : i64,) { $($path_to_types)*::
                         _export_method_phase_depends_on_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Phase > (arg0, arg1, arg2) }
                         #[export_name = "toxoid:engine/ecs#[method]phase.get-id"] unsafe
@@ -4585,6 +6127,105 @@ pub mod exports {
                         u8,) { $($path_to_types)*::
                         _export_method_pipeline_enable_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Pipeline > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[constructor]prefab"] unsafe extern "C" fn
+                        export_constructor_prefab(arg0 : i32, arg1 : * mut u8, arg2 :
+                        usize, arg3 : i32, arg4 : i64,) -> i32 { $($path_to_types)*::
+                        _export_constructor_prefab_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Prefab > (arg0, arg1, arg2, arg3, arg4) } #[export_name
+                        = "toxoid:engine/ecs#[method]prefab.get-id"] unsafe extern "C" fn
+                        export_method_prefab_get_id(arg0 : * mut u8,) -> i64 {
+                        $($path_to_types)*:: _export_method_prefab_get_id_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]prefab.add"] unsafe extern "C" fn
+                        export_method_prefab_add(arg0 : * mut u8, arg1 : i64,) {
+                        $($path_to_types)*:: _export_method_prefab_add_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[method]prefab.get"] unsafe
+                        extern "C" fn export_method_prefab_get(arg0 : * mut u8, arg1 :
+                        i64,) -> i64 { $($path_to_types)*::
+                        _export_method_prefab_get_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Prefab > (arg0, arg1) } #[export_name =
+                        "toxoid:engine/ecs#[method]prefab.override-component"] unsafe
+                        extern "C" fn export_method_prefab_override_component(arg0 : *
+                        mut u8, arg1 : i64,) { $($path_to_types)*::
+                        _export_method_prefab_override_component_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0, arg1) }
+                        #[export_name =
+                        "toxoid:engine/ecs#[method]prefab.inherit-component"] unsafe
+                        extern "C" fn export_method_prefab_inherit_component(arg0 : * mut
+                        u8, arg1 : i64,) { $($path_to_types)*::
+                        _export_method_prefab_inherit_component_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[method]prefab.is-a"] unsafe
+                        extern "C" fn export_method_prefab_is_a(arg0 : * mut u8, arg1 :
+                        i64,) { $($path_to_types)*::
+                        _export_method_prefab_is_a_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Prefab > (arg0, arg1) } #[export_name =
+                        "toxoid:engine/ecs#[method]prefab.slot"] unsafe extern "C" fn
+                        export_method_prefab_slot(arg0 : * mut u8,) -> i32 {
+                        $($path_to_types)*:: _export_method_prefab_slot_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]prefab.instantiate"] unsafe extern "C"
+                        fn export_method_prefab_instantiate(arg0 : * mut u8, arg1 : i32,)
+                        -> * mut u8 { $($path_to_types)*::
+                        _export_method_prefab_instantiate_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0, arg1) }
+                        #[export_name =
+                        "cabi_post_toxoid:engine/ecs#[method]prefab.instantiate"] unsafe
+                        extern "C" fn _post_return_method_prefab_instantiate(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_prefab_instantiate::<<$ty as
+                        $($path_to_types)*:: Guest >::Prefab > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[constructor]timer"] unsafe extern "C" fn
+                        export_constructor_timer() -> i32 { $($path_to_types)*::
+                        _export_constructor_timer_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Timer > () } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.get-id"] unsafe extern "C" fn
+                        export_method_timer_get_id(arg0 : * mut u8,) -> i64 {
+                        $($path_to_types)*:: _export_method_timer_get_id_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.set-timeout"] unsafe extern "C"
+                        fn export_method_timer_set_timeout(arg0 : * mut u8, arg1 : f32,)
+                        { $($path_to_types)*::
+                        _export_method_timer_set_timeout_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[method]timer.get-timeout"]
+                        unsafe extern "C" fn export_method_timer_get_timeout(arg0 : * mut
+                        u8,) -> f32 { $($path_to_types)*::
+                        _export_method_timer_get_timeout_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.set-interval"] unsafe extern "C"
+                        fn export_method_timer_set_interval(arg0 : * mut u8, arg1 : f32,)
+                        { $($path_to_types)*::
+                        _export_method_timer_set_interval_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0, arg1) }
+                        #[export_name = "toxoid:engine/ecs#[method]timer.get-interval"]
+                        unsafe extern "C" fn export_method_timer_get_interval(arg0 : *
+                        mut u8,) -> f32 { $($path_to_types)*::
+                        _export_method_timer_get_interval_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.set-rate"] unsafe extern "C" fn
+                        export_method_timer_set_rate(arg0 : * mut u8, arg1 : i32, arg2 :
+                        i32, arg3 : i64,) { $($path_to_types)*::
+                        _export_method_timer_set_rate_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Timer > (arg0, arg1, arg2, arg3) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.start"] unsafe extern "C" fn
+                        export_method_timer_start(arg0 : * mut u8,) {
+                        $($path_to_types)*:: _export_method_timer_start_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.stop"] unsafe extern "C" fn
+                        export_method_timer_stop(arg0 : * mut u8,) { $($path_to_types)*::
+                        _export_method_timer_stop_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.reset"] unsafe extern "C" fn
+                        export_method_timer_reset(arg0 : * mut u8,) {
+                        $($path_to_types)*:: _export_method_timer_reset_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Timer > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#[method]timer.fire"] unsafe extern "C" fn
+                        export_method_timer_fire(arg0 : * mut u8, arg1 : i64, arg2 :
+                        i64,) { $($path_to_types)*::
+                        _export_method_timer_fire_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Timer > (arg0, arg1, arg2) } #[export_name =
                         "toxoid:engine/ecs#[constructor]observer"] unsafe extern "C" fn
                         export_constructor_observer(arg0 : i32, arg1 : * mut u8, arg2 :
                         usize, arg3 : * mut u8, arg4 : usize, arg5 : * mut u8, arg6 :
@@ -4615,14 +6256,68 @@ pub mod exports {
                         "toxoid:engine/ecs#remove-entity"] unsafe extern "C" fn
                         export_remove_entity(arg0 : i64,) { $($path_to_types)*::
                         _export_remove_entity_cabi::<$ty > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#get-entity"] unsafe extern "C" fn
+                        export_get_entity(arg0 : i64,) -> * mut u8 { $($path_to_types)*::
+                        _export_get_entity_cabi::<$ty > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#get-entity-named"] unsafe extern "C" fn
+                        export_get_entity_named(arg0 : * mut u8, arg1 : usize,) -> * mut
+                        u8 { $($path_to_types)*:: _export_get_entity_named_cabi::<$ty >
+                        (arg0, arg1) } #[export_name =
                         "toxoid:engine/ecs#has-entity-named"] unsafe extern "C" fn
                         export_has_entity_named(arg0 : * mut u8, arg1 : usize,) -> i32 {
                         $($path_to_types)*:: _export_has_entity_named_cabi::<$ty > (arg0,
-                        arg1) } #[export_name = "toxoid:engine/ecs#get-component-id"]
-                        unsafe extern "C" fn export_get_component_id(arg0 : * mut u8,
-                        arg1 : usize,) -> i64 { $($path_to_types)*::
-                        _export_get_component_id_cabi::<$ty > (arg0, arg1) } const _ : ()
-                        = { #[doc(hidden)] #[export_name =
+                        arg1) } #[export_name = "toxoid:engine/ecs#set-scope"] unsafe
+                        extern "C" fn export_set_scope(arg0 : i64,) -> i64 {
+                        $($path_to_types)*:: _export_set_scope_cabi::<$ty > (arg0) }
+                        #[export_name = "toxoid:engine/ecs#get-scope"] unsafe extern "C"
+                        fn export_get_scope() -> i64 { $($path_to_types)*::
+                        _export_get_scope_cabi::<$ty > () } #[export_name =
+                        "toxoid:engine/ecs#get-component-id"] unsafe extern "C" fn
+                        export_get_component_id(arg0 : * mut u8, arg1 : usize,) -> i64 {
+                        $($path_to_types)*:: _export_get_component_id_cabi::<$ty > (arg0,
+                        arg1) } #[export_name = "toxoid:engine/ecs#make-pair"] unsafe
+                        extern "C" fn export_make_pair(arg0 : i64, arg1 : i64,) -> i64 {
+                        $($path_to_types)*:: _export_make_pair_cabi::<$ty > (arg0, arg1)
+                        } #[export_name = "toxoid:engine/ecs#spawn-batch"] unsafe extern
+                        "C" fn export_spawn_batch(arg0 : i32, arg1 : * mut u8, arg2 :
+                        usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_spawn_batch_cabi::<$ty > (arg0, arg1, arg2) }
+                        #[export_name = "cabi_post_toxoid:engine/ecs#spawn-batch"] unsafe
+                        extern "C" fn _post_return_spawn_batch(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_spawn_batch::<$ty > (arg0) }
+                        #[export_name = "toxoid:engine/ecs#delete-with"] unsafe extern
+                        "C" fn export_delete_with(arg0 : i64,) { $($path_to_types)*::
+                        _export_delete_with_cabi::<$ty > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#script-init"] unsafe extern "C" fn
+                        export_script_init(arg0 : * mut u8, arg1 : usize, arg2 : * mut
+                        u8, arg3 : usize,) -> i64 { $($path_to_types)*::
+                        _export_script_init_cabi::<$ty > (arg0, arg1, arg2, arg3) }
+                        #[export_name = "toxoid:engine/ecs#script-update"] unsafe extern
+                        "C" fn export_script_update(arg0 : i64, arg1 : * mut u8, arg2 :
+                        usize,) -> i32 { $($path_to_types)*::
+                        _export_script_update_cabi::<$ty > (arg0, arg1, arg2) }
+                        #[export_name = "toxoid:engine/ecs#script-clear"] unsafe extern
+                        "C" fn export_script_clear(arg0 : i64,) { $($path_to_types)*::
+                        _export_script_clear_cabi::<$ty > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#defer-begin"] unsafe extern "C" fn
+                        export_defer_begin() -> i32 { $($path_to_types)*::
+                        _export_defer_begin_cabi::<$ty > () } #[export_name =
+                        "toxoid:engine/ecs#defer-end"] unsafe extern "C" fn
+                        export_defer_end() -> i32 { $($path_to_types)*::
+                        _export_defer_end_cabi::<$ty > () } #[export_name =
+                        "toxoid:engine/ecs#is-deferred"] unsafe extern "C" fn
+                        export_is_deferred() -> i32 { $($path_to_types)*::
+                        _export_is_deferred_cabi::<$ty > () } #[export_name =
+                        "toxoid:engine/ecs#readonly-begin"] unsafe extern "C" fn
+                        export_readonly_begin(arg0 : i32,) -> i32 { $($path_to_types)*::
+                        _export_readonly_begin_cabi::<$ty > (arg0) } #[export_name =
+                        "toxoid:engine/ecs#readonly-end"] unsafe extern "C" fn
+                        export_readonly_end() { $($path_to_types)*::
+                        _export_readonly_end_cabi::<$ty > () } #[export_name =
+                        "toxoid:engine/ecs#submit"] unsafe extern "C" fn
+                        export_submit(arg0 : * mut u8, arg1 : usize,) {
+                        $($path_to_types)*:: _export_submit_cabi::<$ty > (arg0, arg1) }
+                        const _ : () = { #[doc(hidden)] #[export_name =
                         "toxoid:engine/ecs#[dtor]component-type"]
                         #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
                         u8) { $($path_to_types)*:: ComponentType::dtor::< <$ty as
@@ -4661,6 +6356,14 @@ pub mod exports {
                         unsafe extern "C" fn dtor(rep : * mut u8) { $($path_to_types)*::
                         Pipeline::dtor::< <$ty as $($path_to_types)*:: Guest >::Pipeline
                         > (rep) } }; const _ : () = { #[doc(hidden)] #[export_name =
+                        "toxoid:engine/ecs#[dtor]prefab"] #[allow(non_snake_case)] unsafe
+                        extern "C" fn dtor(rep : * mut u8) { $($path_to_types)*::
+                        Prefab::dtor::< <$ty as $($path_to_types)*:: Guest >::Prefab >
+                        (rep) } }; const _ : () = { #[doc(hidden)] #[export_name =
+                        "toxoid:engine/ecs#[dtor]timer"] #[allow(non_snake_case)] unsafe
+                        extern "C" fn dtor(rep : * mut u8) { $($path_to_types)*::
+                        Timer::dtor::< <$ty as $($path_to_types)*:: Guest >::Timer >
+                        (rep) } }; const _ : () = { #[doc(hidden)] #[export_name =
                         "toxoid:engine/ecs#[dtor]observer"] #[allow(non_snake_case)]
                         unsafe extern "C" fn dtor(rep : * mut u8) { $($path_to_types)*::
                         Observer::dtor::< <$ty as $($path_to_types)*:: Guest >::Observer
@@ -4669,10 +6372,10 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_toxoid_engine_ecs_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 16]);
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 8],
+                    [::core::mem::MaybeUninit::uninit(); 16],
                 );
             }
         }
@@ -4947,9 +6650,9 @@ pub(crate) use __export_toxoid_engine_world_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.35.0:toxoid:engine:toxoid-engine-world:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 6612] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xca2\x01A\x02\x01A\x02\
-\x01B\x98\x02\x01w\x04\0\x0cecs-entity-t\x03\0\0\x01w\x04\0\x09pointer-t\x03\0\x02\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 9057] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xd7E\x01A\x02\x01A\x02\
+\x01B\x88\x03\x01w\x04\0\x0cecs-entity-t\x03\0\0\x01w\x04\0\x09pointer-t\x03\0\x02\
 \x01q\x03\x04is-a\0\0\x08child-of\0\0\x06custom\x01\x01\0\x04\0\x0crelationship\x03\
 \0\x04\x01q\x0a\x08on-start\0\0\x07on-load\0\0\x09post-load\0\0\x0apre-update\0\0\
 \x09on-update\0\0\x0bon-validate\0\0\x0bpost-update\0\0\x09pre-store\0\0\x08on-s\
@@ -4957,128 +6660,183 @@ tore\0\0\x06custom\x01\x01\0\x04\0\x06phases\x03\0\x06\x01m\x18\x04u8-t\x05u16-t
 \x05u32-t\x05u64-t\x04i8-t\x05i16-t\x05i32-t\x05i64-t\x05f32-t\x05f64-t\x06bool-\
 t\x08string-t\x06list-t\x08u8list-t\x09u16list-t\x09u32list-t\x09u64list-t\x08i8\
 list-t\x09i16list-t\x09i32list-t\x09i64list-t\x09f32list-t\x09f64list-t\x09point\
-er-t\x04\0\x0bmember-type\x03\0\x08\x01m\x07\x06on-set\x06on-add\x09on-remove\x09\
-on-delete\x10on-delete-target\x0fon-table-create\x0fon-table-delete\x04\0\x05eve\
-nt\x03\0\x0a\x01ps\x01p}\x01r\x03\x04names\x0cmember-names\x0c\x0cmember-types\x0d\
-\x04\0\x0ecomponent-desc\x03\0\x0e\x01ks\x01p\x01\x01k\x11\x01r\x03\x04name\x10\x03\
-add\x12\x06prefab\x7f\x04\0\x0bentity-desc\x03\0\x13\x01r\x01\x04exprs\x04\0\x0a\
-query-desc\x03\0\x15\x01r\x02\x02id\x01\x08callback\x03\x04\0\x0csorting-desc\x03\
-\0\x17\x01kz\x01r\x05\x04name\x10\x09tick-rate\x19\x08callback\x03\x0aquery-desc\
-\x16\x08is-guest\x7f\x04\0\x0bsystem-desc\x03\0\x1a\x01r\x03\x04names\x0aquery-d\
-esc\x16\x06phases\x11\x04\0\x0dpipeline-desc\x03\0\x1c\x01p\x0b\x01r\x05\x04name\
-\x10\x0aquery-desc\x16\x06events\x1e\x08callback\x03\x08is-guest\x7f\x04\0\x0dob\
-server-desc\x03\0\x1f\x04\0\x0ecomponent-type\x03\x01\x04\0\x09component\x03\x01\
-\x04\0\x06entity\x03\x01\x04\0\x05query\x03\x01\x04\0\x04iter\x03\x01\x04\0\x08c\
-allback\x03\x01\x04\0\x06system\x03\x01\x04\0\x05phase\x03\x01\x04\0\x08pipeline\
-\x03\x01\x04\0\x08observer\x03\x01\x01i!\x01@\x01\x04desc\x0f\0+\x04\0\x1b[const\
-ructor]component-type\x01,\x01h!\x01@\x01\x04self-\0\x01\x04\0\x1d[method]compon\
-ent-type.get-id\x01.\x01i\"\x01@\x03\x03ptr\x03\x06entity\x01\x0ecomponent-type\x01\
-\0/\x04\0\x16[constructor]component\x010\x01@\x01\x03ptr\x03\0\x03\x04\0\x1a[sta\
-tic]component.from-ptr\x011\x01h\"\x01@\x03\x04self2\x06offsety\x05value}\x01\0\x04\
-\0\x1f[method]component.set-member-u8\x013\x01@\x02\x04self2\x06offsety\0}\x04\0\
-\x1f[method]component.get-member-u8\x014\x01@\x03\x04self2\x06offsety\x05value{\x01\
-\0\x04\0\x20[method]component.set-member-u16\x015\x01@\x02\x04self2\x06offsety\0\
-{\x04\0\x20[method]component.get-member-u16\x016\x01@\x03\x04self2\x06offsety\x05\
-valuey\x01\0\x04\0\x20[method]component.set-member-u32\x017\x01@\x02\x04self2\x06\
-offsety\0y\x04\0\x20[method]component.get-member-u32\x018\x01@\x03\x04self2\x06o\
-ffsety\x05valuew\x01\0\x04\0\x20[method]component.set-member-u64\x019\x01@\x02\x04\
-self2\x06offsety\0w\x04\0\x20[method]component.get-member-u64\x01:\x01@\x03\x04s\
-elf2\x06offsety\x05value~\x01\0\x04\0\x1f[method]component.set-member-i8\x01;\x01\
-@\x02\x04self2\x06offsety\0~\x04\0\x1f[method]component.get-member-i8\x01<\x01@\x03\
-\x04self2\x06offsety\x05value|\x01\0\x04\0\x20[method]component.set-member-i16\x01\
-=\x01@\x02\x04self2\x06offsety\0|\x04\0\x20[method]component.get-member-i16\x01>\
-\x01@\x03\x04self2\x06offsety\x05valuez\x01\0\x04\0\x20[method]component.set-mem\
-ber-i32\x01?\x01@\x02\x04self2\x06offsety\0z\x04\0\x20[method]component.get-memb\
-er-i32\x01@\x01@\x03\x04self2\x06offsety\x05valuex\x01\0\x04\0\x20[method]compon\
-ent.set-member-i64\x01A\x01@\x02\x04self2\x06offsety\0x\x04\0\x20[method]compone\
-nt.get-member-i64\x01B\x01@\x03\x04self2\x06offsety\x05valuev\x01\0\x04\0\x20[me\
-thod]component.set-member-f32\x01C\x01@\x02\x04self2\x06offsety\0v\x04\0\x20[met\
-hod]component.get-member-f32\x01D\x01@\x03\x04self2\x06offsety\x05valueu\x01\0\x04\
-\0\x20[method]component.set-member-f64\x01E\x01@\x02\x04self2\x06offsety\0u\x04\0\
-\x20[method]component.get-member-f64\x01F\x01@\x03\x04self2\x06offsety\x05value\x7f\
-\x01\0\x04\0![method]component.set-member-bool\x01G\x01@\x02\x04self2\x06offsety\
-\0\x7f\x04\0![method]component.get-member-bool\x01H\x01@\x03\x04self2\x06offsety\
-\x05values\x01\0\x04\0#[method]component.set-member-string\x01I\x01@\x02\x04self\
-2\x06offsety\0s\x04\0#[method]component.get-member-string\x01J\x01@\x03\x04self2\
-\x06offsety\x05value\x0d\x01\0\x04\0#[method]component.set-member-u8list\x01K\x01\
-@\x02\x04self2\x06offsety\0\x0d\x04\0#[method]component.get-member-u8list\x01L\x01\
-p{\x01@\x03\x04self2\x06offsety\x05value\xcd\0\x01\0\x04\0$[method]component.set\
--member-u16list\x01N\x01@\x02\x04self2\x06offsety\0\xcd\0\x04\0$[method]componen\
-t.get-member-u16list\x01O\x01py\x01@\x03\x04self2\x06offsety\x05value\xd0\0\x01\0\
-\x04\0$[method]component.set-member-u32list\x01Q\x01@\x02\x04self2\x06offsety\0\xd0\
-\0\x04\0$[method]component.get-member-u32list\x01R\x01pw\x01@\x03\x04self2\x06of\
-fsety\x05value\xd3\0\x01\0\x04\0$[method]component.set-member-u64list\x01T\x01@\x02\
-\x04self2\x06offsety\0\xd3\0\x04\0$[method]component.get-member-u64list\x01U\x01\
-p~\x01@\x03\x04self2\x06offsety\x05value\xd6\0\x01\0\x04\0#[method]component.set\
--member-i8list\x01W\x01@\x02\x04self2\x06offsety\0\xd6\0\x04\0#[method]component\
-.get-member-i8list\x01X\x01p|\x01@\x03\x04self2\x06offsety\x05value\xd9\0\x01\0\x04\
-\0$[method]component.set-member-i16list\x01Z\x01@\x02\x04self2\x06offsety\0\xd9\0\
-\x04\0$[method]component.get-member-i16list\x01[\x01pz\x01@\x03\x04self2\x06offs\
-ety\x05value\xdc\0\x01\0\x04\0$[method]component.set-member-i32list\x01]\x01@\x02\
-\x04self2\x06offsety\0\xdc\0\x04\0$[method]component.get-member-i32list\x01^\x01\
-px\x01@\x03\x04self2\x06offsety\x05value\xdf\0\x01\0\x04\0$[method]component.set\
--member-i64list\x01`\x01@\x02\x04self2\x06offsety\0\xdf\0\x04\0$[method]componen\
-t.get-member-i64list\x01a\x01pv\x01@\x03\x04self2\x06offsety\x05value\xe2\0\x01\0\
-\x04\0$[method]component.set-member-f32list\x01c\x01@\x02\x04self2\x06offsety\0\xe2\
-\0\x04\0$[method]component.get-member-f32list\x01d\x01pu\x01@\x03\x04self2\x06of\
-fsety\x05value\xe5\0\x01\0\x04\0$[method]component.set-member-f64list\x01f\x01@\x02\
-\x04self2\x06offsety\0\xe5\0\x04\0$[method]component.get-member-f64list\x01g\x04\
-\0$[method]component.set-member-pointer\x019\x04\0$[method]component.get-member-\
-pointer\x01:\x01k\x01\x01i#\x01@\x02\x04desc\x14\x08inherits\xe8\0\0\xe9\0\x04\0\
-\x13[constructor]entity\x01j\x01h#\x01@\x01\x04self\xeb\0\0\x01\x04\0\x15[method\
-]entity.get-id\x01l\x01@\x01\x04self\xeb\0\0s\x04\0\x17[method]entity.get-name\x01\
-m\x01@\x02\x04self\xeb\0\x04names\x01\0\x04\0\x17[method]entity.set-name\x01n\x01\
-@\x01\x02idw\0\x03\x04\0\x16[static]entity.from-id\x01o\x01@\x02\x04self\xeb\0\x09\
-component\x01\0\x03\x04\0\x12[method]entity.get\x01p\x01@\x02\x04self\xeb\0\x09c\
-omponent\x01\x01\0\x04\0\x12[method]entity.add\x01q\x01@\x02\x04self\xeb\0\x09co\
-mponent\x01\0\x7f\x04\0\x12[method]entity.has\x01r\x04\0\x15[method]entity.remov\
-e\x01q\x01@\x03\x04self\xeb\0\x0crelationship\x05\x06target\x01\x01\0\x04\0\x1f[\
-method]entity.add-relationship\x01s\x04\0\"[method]entity.remove-relationship\x01\
-s\x01@\x02\x04self\xeb\0\x06target\x01\x01\0\x04\0\x18[method]entity.parent-of\x01\
-t\x04\0\x17[method]entity.child-of\x01t\x04\0\x15[method]entity.parent\x01l\x01@\
-\x01\x04self\xeb\0\0\x11\x04\0\x17[method]entity.children\x01u\x04\0\x1c[method]\
-entity.relationships\x01u\x01@\x01\x04self\xeb\0\x01\0\x04\0\x16[method]entity.d\
-isable\x01v\x04\0\x15[method]entity.enable\x01v\x01i$\x01@\x01\x04desc\x16\0\xf7\
-\0\x04\0\x12[constructor]query\x01x\x01h$\x01@\x01\x04self\xf9\0\x01\0\x04\0\x13\
-[method]query.build\x01z\x01@\x02\x04self\xf9\0\x04desc\x18\x01\0\x04\0\x16[meth\
-od]query.order-by\x01{\x01@\x01\x04self\xf9\0\0\x03\x04\0\x12[method]query.iter\x01\
-|\x01@\x01\x04self\xf9\0\0\x7f\x04\0\x12[method]query.next\x01}\x01@\x01\x04self\
-\xf9\0\0z\x04\0\x13[method]query.count\x01~\x01@\x01\x04self\xf9\0\0\x11\x04\0\x16\
-[method]query.entities\x01\x7f\x01p\x03\x01@\x02\x04self\xf9\0\x05index~\0\x80\x01\
-\x04\0\x18[method]query.components\x01\x81\x01\x01i%\x01@\x01\x03ptrw\0\x82\x01\x04\
-\0\x11[constructor]iter\x01\x83\x01\x01h%\x01@\x01\x04self\x84\x01\0\x7f\x04\0\x11\
-[method]iter.next\x01\x85\x01\x01@\x01\x04self\x84\x01\0z\x04\0\x12[method]iter.\
-count\x01\x86\x01\x01@\x01\x04self\x84\x01\0\x11\x04\0\x15[method]iter.entities\x01\
-\x87\x01\x01@\x02\x04self\x84\x01\x05index~\0\x80\x01\x04\0\x17[method]iter.comp\
-onents\x01\x88\x01\x01i&\x01@\x01\x06handlew\0\x89\x01\x04\0\x15[constructor]cal\
-lback\x01\x8a\x01\x01h&\x01@\x02\x04self\x8b\x01\x04iter\x82\x01\x01\0\x04\0\x14\
-[method]callback.run\x01\x8c\x01\x01@\x01\x04self\x8b\x01\0\x03\x04\0\x1a[method\
-]callback.cb-handle\x01\x8d\x01\x01i'\x01@\x01\x04desc\x1b\0\x8e\x01\x04\0\x13[c\
-onstructor]system\x01\x8f\x01\x01h'\x01@\x01\x04self\x90\x01\0\x01\x04\0\x15[met\
-hod]system.get-id\x01\x91\x01\x01@\x01\x04self\x90\x01\x01\0\x04\0\x14[method]sy\
-stem.build\x01\x92\x01\x01@\x02\x04self\x90\x01\x04desc\x18\x01\0\x04\0\x17[meth\
-od]system.order-by\x01\x93\x01\x01@\x01\x04self\x90\x01\0\x03\x04\0\x17[method]s\
-ystem.callback\x01\x94\x01\x04\0\x16[method]system.disable\x01\x92\x01\x04\0\x15\
-[method]system.enable\x01\x92\x01\x01i(\x01@\x01\x04names\0\x95\x01\x04\0\x12[co\
-nstructor]phase\x01\x96\x01\x01h(\x01@\x02\x04self\x97\x01\x05phase\x07\x01\0\x04\
-\0\x18[method]phase.depends-on\x01\x98\x01\x01@\x01\x04self\x97\x01\0\x01\x04\0\x14\
-[method]phase.get-id\x01\x99\x01\x01i)\x01@\x01\x04desc\x1d\0\x9a\x01\x04\0\x15[\
-constructor]pipeline\x01\x9b\x01\x01h)\x01@\x01\x04self\x9c\x01\x01\0\x04\0\x16[\
-method]pipeline.build\x01\x9d\x01\x01@\x02\x04self\x9c\x01\x05phase\x01\x01\0\x04\
-\0\x1a[method]pipeline.add-phase\x01\x9e\x01\x01@\x01\x04self\x9c\x01\0\x01\x04\0\
-\x17[method]pipeline.get-id\x01\x9f\x01\x04\0\x18[method]pipeline.disable\x01\x9d\
-\x01\x04\0\x17[method]pipeline.enable\x01\x9d\x01\x01i*\x01@\x01\x04desc\x20\0\xa0\
-\x01\x04\0\x15[constructor]observer\x01\xa1\x01\x01h*\x01@\x01\x04self\xa2\x01\x01\
-\0\x04\0\x16[method]observer.build\x01\xa3\x01\x01@\x01\x04self\xa2\x01\0\x03\x04\
-\0\x19[method]observer.callback\x01\xa4\x01\x01@\x01\x0ccomponent-id\x01\x01\0\x04\
-\0\x0dadd-singleton\x01\xa5\x01\x01@\x01\x0ccomponent-id\x01\0w\x04\0\x0dget-sin\
-gleton\x01\xa6\x01\x04\0\x10remove-singleton\x01\xa5\x01\x01@\x01\x09entity-id\x01\
-\x01\0\x04\0\x0aadd-entity\x01\xa7\x01\x04\0\x0dremove-entity\x01\xa7\x01\x01@\x01\
-\x04names\0\x7f\x04\0\x10has-entity-named\x01\xa8\x01\x01@\x01\x0ecomponent-name\
-s\0\x01\x04\0\x10get-component-id\x01\xa9\x01\x04\0\x11toxoid:engine/ecs\x05\0\x04\
-\0!toxoid:engine/toxoid-engine-world\x04\0\x0b\x19\x01\0\x13toxoid-engine-world\x03\
-\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.220.0\x10wit-\
-bindgen-rust\x060.35.0";
+er-t\x04\0\x0bmember-type\x03\0\x08\x01q\x08\x06on-set\0\0\x06on-add\0\0\x09on-r\
+emove\0\0\x09on-delete\0\0\x10on-delete-target\0\0\x0fon-table-create\0\0\x0fon-\
+table-delete\0\0\x06custom\x01\x01\0\x04\0\x05event\x03\0\x0a\x01o\x02\x01\x01\x01\
+o\x03\x01\x05\x01\x01q\x07\x03add\x01\x0c\0\x06remove\x01\x0c\0\x10add-relations\
+hip\x01\x0d\0\x13remove-relationship\x01\x0d\0\x06enable\x01\x01\0\x07disable\x01\
+\x01\0\x06delete\x01\x01\0\x04\0\x07command\x03\0\x0e\x01ps\x01p}\x01r\x03\x04na\
+mes\x0cmember-names\x10\x0cmember-types\x11\x04\0\x0ecomponent-desc\x03\0\x12\x01\
+ks\x01p\x01\x01k\x15\x01r\x03\x04name\x14\x03add\x16\x06prefab\x7f\x04\0\x0benti\
+ty-desc\x03\0\x17\x01r\x01\x04exprs\x04\0\x0aquery-desc\x03\0\x19\x01r\x02\x02id\
+\x01\x08callback\x03\x04\0\x0csorting-desc\x03\0\x1b\x01kz\x01k\x01\x01kv\x01r\x0a\
+\x04name\x14\x09tick-rate\x1d\x08callback\x03\x0aquery-desc\x1a\x08is-guest\x7f\x05\
+phase\x1e\x08interval\x1f\x0btick-source\x1e\x0emulti-threaded\x7f\x09immediate\x7f\
+\x04\0\x0bsystem-desc\x03\0\x20\x01r\x03\x04names\x0aquery-desc\x1a\x06phases\x15\
+\x04\0\x0dpipeline-desc\x03\0\"\x01p\x0b\x01r\x05\x04name\x14\x0aquery-desc\x1a\x06\
+events$\x08callback\x03\x08is-guest\x7f\x04\0\x0dobserver-desc\x03\0%\x04\0\x0ec\
+omponent-type\x03\x01\x04\0\x09component\x03\x01\x04\0\x06entity\x03\x01\x04\0\x05\
+query\x03\x01\x04\0\x04iter\x03\x01\x04\0\x08callback\x03\x01\x04\0\x06system\x03\
+\x01\x04\0\x05phase\x03\x01\x04\0\x08pipeline\x03\x01\x04\0\x06prefab\x03\x01\x04\
+\0\x05timer\x03\x01\x04\0\x08observer\x03\x01\x01i'\x01@\x01\x04desc\x13\03\x04\0\
+\x1b[constructor]component-type\x014\x01h'\x01@\x01\x04self5\0\x01\x04\0\x1d[met\
+hod]component-type.get-id\x016\x01i(\x01@\x03\x03ptr\x03\x06entity\x01\x0ecompon\
+ent-type\x01\07\x04\0\x16[constructor]component\x018\x01@\x01\x03ptr\x03\0\x03\x04\
+\0\x1a[static]component.from-ptr\x019\x01h(\x01@\x03\x04self:\x06offsety\x05valu\
+e}\x01\0\x04\0\x1f[method]component.set-member-u8\x01;\x01@\x02\x04self:\x06offs\
+ety\0}\x04\0\x1f[method]component.get-member-u8\x01<\x01@\x03\x04self:\x06offset\
+y\x05value{\x01\0\x04\0\x20[method]component.set-member-u16\x01=\x01@\x02\x04sel\
+f:\x06offsety\0{\x04\0\x20[method]component.get-member-u16\x01>\x01@\x03\x04self\
+:\x06offsety\x05valuey\x01\0\x04\0\x20[method]component.set-member-u32\x01?\x01@\
+\x02\x04self:\x06offsety\0y\x04\0\x20[method]component.get-member-u32\x01@\x01@\x03\
+\x04self:\x06offsety\x05valuew\x01\0\x04\0\x20[method]component.set-member-u64\x01\
+A\x01@\x02\x04self:\x06offsety\0w\x04\0\x20[method]component.get-member-u64\x01B\
+\x01@\x03\x04self:\x06offsety\x05value~\x01\0\x04\0\x1f[method]component.set-mem\
+ber-i8\x01C\x01@\x02\x04self:\x06offsety\0~\x04\0\x1f[method]component.get-membe\
+r-i8\x01D\x01@\x03\x04self:\x06offsety\x05value|\x01\0\x04\0\x20[method]componen\
+t.set-member-i16\x01E\x01@\x02\x04self:\x06offsety\0|\x04\0\x20[method]component\
+.get-member-i16\x01F\x01@\x03\x04self:\x06offsety\x05valuez\x01\0\x04\0\x20[meth\
+od]component.set-member-i32\x01G\x01@\x02\x04self:\x06offsety\0z\x04\0\x20[metho\
+d]component.get-member-i32\x01H\x01@\x03\x04self:\x06offsety\x05valuex\x01\0\x04\
+\0\x20[method]component.set-member-i64\x01I\x01@\x02\x04self:\x06offsety\0x\x04\0\
+\x20[method]component.get-member-i64\x01J\x01@\x03\x04self:\x06offsety\x05valuev\
+\x01\0\x04\0\x20[method]component.set-member-f32\x01K\x01@\x02\x04self:\x06offse\
+ty\0v\x04\0\x20[method]component.get-member-f32\x01L\x01@\x03\x04self:\x06offset\
+y\x05valueu\x01\0\x04\0\x20[method]component.set-member-f64\x01M\x01@\x02\x04sel\
+f:\x06offsety\0u\x04\0\x20[method]component.get-member-f64\x01N\x01@\x03\x04self\
+:\x06offsety\x05value\x7f\x01\0\x04\0![method]component.set-member-bool\x01O\x01\
+@\x02\x04self:\x06offsety\0\x7f\x04\0![method]component.get-member-bool\x01P\x01\
+@\x03\x04self:\x06offsety\x05values\x01\0\x04\0#[method]component.set-member-str\
+ing\x01Q\x01@\x02\x04self:\x06offsety\0s\x04\0#[method]component.get-member-stri\
+ng\x01R\x01@\x03\x04self:\x06offsety\x05value\x11\x01\0\x04\0#[method]component.\
+set-member-u8list\x01S\x01@\x02\x04self:\x06offsety\0\x11\x04\0#[method]componen\
+t.get-member-u8list\x01T\x01p{\x01@\x03\x04self:\x06offsety\x05value\xd5\0\x01\0\
+\x04\0$[method]component.set-member-u16list\x01V\x01@\x02\x04self:\x06offsety\0\xd5\
+\0\x04\0$[method]component.get-member-u16list\x01W\x01py\x01@\x03\x04self:\x06of\
+fsety\x05value\xd8\0\x01\0\x04\0$[method]component.set-member-u32list\x01Y\x01@\x02\
+\x04self:\x06offsety\0\xd8\0\x04\0$[method]component.get-member-u32list\x01Z\x01\
+pw\x01@\x03\x04self:\x06offsety\x05value\xdb\0\x01\0\x04\0$[method]component.set\
+-member-u64list\x01\\\x01@\x02\x04self:\x06offsety\0\xdb\0\x04\0$[method]compone\
+nt.get-member-u64list\x01]\x01p~\x01@\x03\x04self:\x06offsety\x05value\xde\0\x01\
+\0\x04\0#[method]component.set-member-i8list\x01_\x01@\x02\x04self:\x06offsety\0\
+\xde\0\x04\0#[method]component.get-member-i8list\x01`\x01p|\x01@\x03\x04self:\x06\
+offsety\x05value\xe1\0\x01\0\x04\0$[method]component.set-member-i16list\x01b\x01\
+@\x02\x04self:\x06offsety\0\xe1\0\x04\0$[method]component.get-member-i16list\x01\
+c\x01pz\x01@\x03\x04self:\x06offsety\x05value\xe4\0\x01\0\x04\0$[method]componen\
+t.set-member-i32list\x01e\x01@\x02\x04self:\x06offsety\0\xe4\0\x04\0$[method]com\
+ponent.get-member-i32list\x01f\x01px\x01@\x03\x04self:\x06offsety\x05value\xe7\0\
+\x01\0\x04\0$[method]component.set-member-i64list\x01h\x01@\x02\x04self:\x06offs\
+ety\0\xe7\0\x04\0$[method]component.get-member-i64list\x01i\x01pv\x01@\x03\x04se\
+lf:\x06offsety\x05value\xea\0\x01\0\x04\0$[method]component.set-member-f32list\x01\
+k\x01@\x02\x04self:\x06offsety\0\xea\0\x04\0$[method]component.get-member-f32lis\
+t\x01l\x01pu\x01@\x03\x04self:\x06offsety\x05value\xed\0\x01\0\x04\0$[method]com\
+ponent.set-member-f64list\x01n\x01@\x02\x04self:\x06offsety\0\xed\0\x04\0$[metho\
+d]component.get-member-f64list\x01o\x04\0$[method]component.set-member-pointer\x01\
+A\x04\0$[method]component.get-member-pointer\x01B\x01i)\x01@\x02\x04desc\x18\x08\
+inherits\x1e\0\xf0\0\x04\0\x13[constructor]entity\x01q\x01h)\x01@\x01\x04self\xf2\
+\0\0\x01\x04\0\x15[method]entity.get-id\x01s\x01@\x01\x04self\xf2\0\0s\x04\0\x17\
+[method]entity.get-name\x01t\x01@\x02\x04self\xf2\0\x04names\x01\0\x04\0\x17[met\
+hod]entity.set-name\x01u\x01@\x01\x02idw\0\x03\x04\0\x16[static]entity.from-id\x01\
+v\x01@\x02\x04self\xf2\0\x09component\x01\0\x03\x04\0\x12[method]entity.get\x01w\
+\x01@\x02\x04self\xf2\0\x09component\x01\x01\0\x04\0\x12[method]entity.add\x01x\x01\
+@\x02\x04self\xf2\0\x09component\x01\0\x7f\x04\0\x12[method]entity.has\x01y\x04\0\
+\x15[method]entity.remove\x01x\x01@\x03\x04self\xf2\0\x0crelationship\x05\x06tar\
+get\x01\x01\0\x04\0\x1f[method]entity.add-relationship\x01z\x04\0\"[method]entit\
+y.remove-relationship\x01z\x01@\x02\x04self\xf2\0\x06target\x01\x01\0\x04\0\x18[\
+method]entity.parent-of\x01{\x04\0\x17[method]entity.child-of\x01{\x04\0\x15[met\
+hod]entity.parent\x01s\x01@\x01\x04self\xf2\0\0\x15\x04\0\x17[method]entity.chil\
+dren\x01|\x04\0\x1c[method]entity.relationships\x01|\x04\0\x17[method]entity.get\
+-path\x01t\x01@\x02\x04self\xf2\0\x04paths\0\x1e\x04\0\x15[method]entity.lookup\x01\
+}\x01@\x03\x04self\xf2\0\x0crelationship\x05\x05indexz\0\x1e\x04\0\x15[method]en\
+tity.target\x01~\x01@\x02\x04self\xf2\0\x0crelationship\x05\0\x15\x04\0\x16[meth\
+od]entity.targets\x01\x7f\x01@\x03\x04self\xf2\0\x09component\x01\x04jsons\0\x7f\
+\x04\0\x17[method]entity.set-json\x01\x80\x01\x01@\x02\x04self\xf2\0\x04jsons\0\x7f\
+\x04\0\x18[method]entity.from-json\x01\x81\x01\x01@\x01\x04self\xf2\0\x01\0\x04\0\
+\x16[method]entity.disable\x01\x82\x01\x04\0\x15[method]entity.enable\x01\x82\x01\
+\x01i*\x01@\x01\x04desc\x1a\0\x83\x01\x04\0\x12[constructor]query\x01\x84\x01\x01\
+h*\x01@\x01\x04self\x85\x01\x01\0\x04\0\x13[method]query.build\x01\x86\x01\x01@\x02\
+\x04self\x85\x01\x04desc\x1c\x01\0\x04\0\x16[method]query.order-by\x01\x87\x01\x01\
+@\x01\x04self\x85\x01\0\x03\x04\0\x12[method]query.iter\x01\x88\x01\x01@\x01\x04\
+self\x85\x01\0\x7f\x04\0\x12[method]query.next\x01\x89\x01\x01@\x01\x04self\x85\x01\
+\0z\x04\0\x13[method]query.count\x01\x8a\x01\x01@\x01\x04self\x85\x01\0\x15\x04\0\
+\x16[method]query.entities\x01\x8b\x01\x01p\x03\x01@\x02\x04self\x85\x01\x05inde\
+x~\0\x8c\x01\x04\0\x18[method]query.components\x01\x8d\x01\x04\0\x1c[method]quer\
+y.detect-changes\x01\x86\x01\x04\0\x15[method]query.changed\x01\x89\x01\x01@\x03\
+\x04self\x85\x01\x04names\x05value\x01\x01\0\x04\0\x15[method]query.set-var\x01\x8e\
+\x01\x01@\x02\x04self\x85\x01\x04names\0\x01\x04\0\x15[method]query.get-var\x01\x8f\
+\x01\x01@\x02\x04self\x85\x01\x05index~\0\x7f\x04\0\x14[method]query.is-set\x01\x90\
+\x01\x01@\x02\x04self\x85\x01\x05index~\0\x01\x04\0\x17[method]query.field-src\x01\
+\x91\x01\x01i+\x01@\x01\x03ptrw\0\x92\x01\x04\0\x11[constructor]iter\x01\x93\x01\
+\x01h+\x01@\x01\x04self\x94\x01\0\x7f\x04\0\x11[method]iter.next\x01\x95\x01\x01\
+@\x01\x04self\x94\x01\0z\x04\0\x12[method]iter.count\x01\x96\x01\x01@\x01\x04sel\
+f\x94\x01\0\x15\x04\0\x15[method]iter.entities\x01\x97\x01\x01@\x02\x04self\x94\x01\
+\x05index~\0\x8c\x01\x04\0\x17[method]iter.components\x01\x98\x01\x01@\x02\x04se\
+lf\x94\x01\x04names\0\x01\x04\0\x14[method]iter.get-var\x01\x99\x01\x01@\x02\x04\
+self\x94\x01\x05index~\0\x7f\x04\0\x13[method]iter.is-set\x01\x9a\x01\x01@\x02\x04\
+self\x94\x01\x05index~\0\x01\x04\0\x16[method]iter.field-src\x01\x9b\x01\x04\0\x14\
+[method]iter.changed\x01\x95\x01\x01i,\x01@\x01\x06handlew\0\x9c\x01\x04\0\x15[c\
+onstructor]callback\x01\x9d\x01\x01h,\x01@\x02\x04self\x9e\x01\x04iter\x92\x01\x01\
+\0\x04\0\x14[method]callback.run\x01\x9f\x01\x01@\x01\x04self\x9e\x01\0\x03\x04\0\
+\x1a[method]callback.cb-handle\x01\xa0\x01\x01i-\x01@\x01\x04desc!\0\xa1\x01\x04\
+\0\x13[constructor]system\x01\xa2\x01\x01h-\x01@\x01\x04self\xa3\x01\0\x01\x04\0\
+\x15[method]system.get-id\x01\xa4\x01\x01@\x01\x04self\xa3\x01\x01\0\x04\0\x14[m\
+ethod]system.build\x01\xa5\x01\x01@\x02\x04self\xa3\x01\x04desc\x1c\x01\0\x04\0\x17\
+[method]system.order-by\x01\xa6\x01\x01@\x01\x04self\xa3\x01\0\x03\x04\0\x17[met\
+hod]system.callback\x01\xa7\x01\x04\0\x16[method]system.disable\x01\xa5\x01\x04\0\
+\x15[method]system.enable\x01\xa5\x01\x01@\x02\x04self\xa3\x01\x05phase\x07\x01\0\
+\x04\0\x14[method]system.phase\x01\xa8\x01\x01@\x02\x04self\xa3\x01\x07secondsv\x01\
+\0\x04\0\x17[method]system.interval\x01\xa9\x01\x04\0\x1d[method]system.multi-th\
+readed\x01\xa5\x01\x04\0\x18[method]system.immediate\x01\xa5\x01\x01@\x02\x04sel\
+f\xa3\x01\x06source\x01\x01\0\x04\0\x1a[method]system.tick-source\x01\xaa\x01\x01\
+i.\x01@\x01\x04names\0\xab\x01\x04\0\x12[constructor]phase\x01\xac\x01\x01h.\x01\
+@\x02\x04self\xad\x01\x05phase\x07\x01\0\x04\0\x18[method]phase.depends-on\x01\xae\
+\x01\x01@\x01\x04self\xad\x01\0\x01\x04\0\x14[method]phase.get-id\x01\xaf\x01\x01\
+i/\x01@\x01\x04desc#\0\xb0\x01\x04\0\x15[constructor]pipeline\x01\xb1\x01\x01h/\x01\
+@\x01\x04self\xb2\x01\x01\0\x04\0\x16[method]pipeline.build\x01\xb3\x01\x01@\x02\
+\x04self\xb2\x01\x05phase\x01\x01\0\x04\0\x1a[method]pipeline.add-phase\x01\xb4\x01\
+\x01@\x01\x04self\xb2\x01\0\x01\x04\0\x17[method]pipeline.get-id\x01\xb5\x01\x04\
+\0\x18[method]pipeline.disable\x01\xb3\x01\x04\0\x17[method]pipeline.enable\x01\xb3\
+\x01\x01i0\x01@\x02\x04name\x14\x06parent\x1e\0\xb6\x01\x04\0\x13[constructor]pr\
+efab\x01\xb7\x01\x01h0\x01@\x01\x04self\xb8\x01\0\x01\x04\0\x15[method]prefab.ge\
+t-id\x01\xb9\x01\x01@\x02\x04self\xb8\x01\x09component\x01\x01\0\x04\0\x12[metho\
+d]prefab.add\x01\xba\x01\x01@\x02\x04self\xb8\x01\x09component\x01\0\x03\x04\0\x12\
+[method]prefab.get\x01\xbb\x01\x04\0![method]prefab.override-component\x01\xba\x01\
+\x04\0\x20[method]prefab.inherit-component\x01\xba\x01\x01@\x02\x04self\xb8\x01\x04\
+base\x01\x01\0\x04\0\x13[method]prefab.is-a\x01\xbc\x01\x01@\x01\x04self\xb8\x01\
+\0\x7f\x04\0\x13[method]prefab.slot\x01\xbd\x01\x01@\x02\x04self\xb8\x01\x05coun\
+ty\0\x15\x04\0\x1a[method]prefab.instantiate\x01\xbe\x01\x01i1\x01@\0\0\xbf\x01\x04\
+\0\x12[constructor]timer\x01\xc0\x01\x01h1\x01@\x01\x04self\xc1\x01\0\x01\x04\0\x14\
+[method]timer.get-id\x01\xc2\x01\x01@\x02\x04self\xc1\x01\x07secondsv\x01\0\x04\0\
+\x19[method]timer.set-timeout\x01\xc3\x01\x01@\x01\x04self\xc1\x01\0v\x04\0\x19[\
+method]timer.get-timeout\x01\xc4\x01\x04\0\x1a[method]timer.set-interval\x01\xc3\
+\x01\x04\0\x1a[method]timer.get-interval\x01\xc4\x01\x01@\x03\x04self\xc1\x01\x04\
+ratez\x06source\x1e\x01\0\x04\0\x16[method]timer.set-rate\x01\xc5\x01\x01@\x01\x04\
+self\xc1\x01\x01\0\x04\0\x13[method]timer.start\x01\xc6\x01\x04\0\x12[method]tim\
+er.stop\x01\xc6\x01\x04\0\x13[method]timer.reset\x01\xc6\x01\x01@\x03\x04self\xc1\
+\x01\x05event\x01\x06target\x01\x01\0\x04\0\x12[method]timer.fire\x01\xc7\x01\x01\
+i2\x01@\x01\x04desc&\0\xc8\x01\x04\0\x15[constructor]observer\x01\xc9\x01\x01h2\x01\
+@\x01\x04self\xca\x01\x01\0\x04\0\x16[method]observer.build\x01\xcb\x01\x01@\x01\
+\x04self\xca\x01\0\x03\x04\0\x19[method]observer.callback\x01\xcc\x01\x01@\x01\x0c\
+component-id\x01\x01\0\x04\0\x0dadd-singleton\x01\xcd\x01\x01@\x01\x0ccomponent-\
+id\x01\0w\x04\0\x0dget-singleton\x01\xce\x01\x04\0\x10remove-singleton\x01\xcd\x01\
+\x01@\x01\x09entity-id\x01\x01\0\x04\0\x0aadd-entity\x01\xcf\x01\x04\0\x0dremove\
+-entity\x01\xcf\x01\x01@\x01\x09entity-id\x01\0\x1e\x04\0\x0aget-entity\x01\xd0\x01\
+\x01@\x01\x04names\0\x1e\x04\0\x10get-entity-named\x01\xd1\x01\x01@\x01\x04names\
+\0\x7f\x04\0\x10has-entity-named\x01\xd2\x01\x01@\x01\x05scope\x01\0\x01\x04\0\x09\
+set-scope\x01\xd3\x01\x01@\0\0\x01\x04\0\x09get-scope\x01\xd4\x01\x01@\x01\x0eco\
+mponent-names\0\x01\x04\0\x10get-component-id\x01\xd5\x01\x01@\x02\x0crelationsh\
+ip\x01\x06target\x01\0\x01\x04\0\x09make-pair\x01\xd6\x01\x01@\x02\x05county\x0a\
+components\x15\0\x15\x04\0\x0bspawn-batch\x01\xd7\x01\x01@\x01\x09component\x01\x01\
+\0\x04\0\x0bdelete-with\x01\xd8\x01\x01@\x02\x08filenames\x04codes\0\x01\x04\0\x0b\
+script-init\x01\xd9\x01\x01@\x02\x06script\x01\x04codes\0\x7f\x04\0\x0dscript-up\
+date\x01\xda\x01\x01@\x01\x06script\x01\x01\0\x04\0\x0cscript-clear\x01\xdb\x01\x01\
+@\0\0\x7f\x04\0\x0bdefer-begin\x01\xdc\x01\x04\0\x09defer-end\x01\xdc\x01\x04\0\x0b\
+is-deferred\x01\xdc\x01\x01@\x01\x0emulti-threaded\x7f\0\x7f\x04\0\x0ereadonly-b\
+egin\x01\xdd\x01\x01@\0\x01\0\x04\0\x0creadonly-end\x01\xde\x01\x01p\x0f\x01@\x01\
+\x08commands\xdf\x01\x01\0\x04\0\x06submit\x01\xe0\x01\x04\0\x11toxoid:engine/ec\
+s\x05\0\x04\0!toxoid:engine/toxoid-engine-world\x04\0\x0b\x19\x01\0\x13toxoid-en\
+gine-world\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070\
+.220.1\x10wit-bindgen-rust\x060.35.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -2,10 +2,10 @@
 #![allow(warnings)]
 
 pub mod bindings;
-use bindings::exports::toxoid::engine::ecs::{EcsEntityT, GuestIter, GuestObserver, ObserverDesc, Phases, PointerT, Relationship};
-use bindings::exports::toxoid::engine::ecs::{self, ComponentDesc, EntityDesc, Guest, GuestCallback, GuestComponent, GuestComponentType, GuestEntity, GuestQuery, GuestSystem, GuestPhase, GuestPipeline, QueryDesc, SystemDesc, PipelineDesc, Event, SortingDesc};
+use bindings::exports::toxoid::engine::ecs::{EcsEntityT, GuestIter, GuestObserver, ObserverDesc, Phases, PointerT, Relationship, Command};
+use bindings::exports::toxoid::engine::ecs::{self, ComponentDesc, EntityDesc, Guest, GuestCallback, GuestComponent, GuestComponentType, GuestEntity, GuestQuery, GuestSystem, GuestPhase, GuestPipeline, GuestTimer, GuestPrefab, QueryDesc, SystemDesc, PipelineDesc, Event, SortingDesc};
 pub use toxoid_flecs::bindings::{ecs_add_id, ecs_entity_desc_t, ecs_entity_init, ecs_fini, ecs_get_mut_id, ecs_init, ecs_iter_t, ecs_lookup, ecs_make_pair, ecs_member_t, ecs_progress, ecs_query_desc_t, ecs_query_init, ecs_query_iter, ecs_query_next, ecs_iter_next, ecs_query_t, ecs_struct_desc_t, ecs_struct_init, ecs_system_desc_t, ecs_system_init, ecs_system_t, ecs_world_t, EcsDependsOn, EcsOnUpdate, ecs_set_rate, ecs_get_id, ecs_remove_id};
-use toxoid_flecs::{ecs_children, ecs_children_next, ecs_delete, ecs_enable, ecs_ensure_id, ecs_field_size, ecs_field_w_size, ecs_get_name, ecs_get_parent, ecs_get_path_w_sep, ecs_has_id, ecs_modified_id, ecs_new_w_id, ecs_observer_desc_t, ecs_observer_init, ecs_observer_t, ecs_pipeline_desc_t, ecs_pipeline_init, ecs_set_name, EcsChildOf, EcsInherit, EcsIsA, EcsOnInstantiate, EcsOnLoad, EcsOnStart, EcsOnStore, EcsOnValidate, EcsPhase, EcsPostLoad, EcsPostUpdate, EcsPreStore, EcsPreUpdate, EcsPrefab};
+use toxoid_flecs::{ecs_children, ecs_children_next, ecs_delete, ecs_enable, ecs_ensure_id, ecs_field_size, ecs_field_w_size, ecs_get_name, ecs_get_parent, ecs_get_path_w_sep, ecs_has_id, ecs_modified_id, ecs_new_w_id, ecs_observer_desc_t, ecs_observer_init, ecs_observer_t, ecs_pipeline_desc_t, ecs_pipeline_init, ecs_set_name, ecs_set_timeout, ecs_get_timeout, ecs_set_interval, ecs_get_interval, ecs_start_timer, ecs_stop_timer, ecs_reset_timer, ecs_emit, ecs_event_desc_t, ecs_get_type, ecs_defer_begin, ecs_defer_end, ecs_is_deferred, ecs_readonly_begin, ecs_readonly_end, ecs_query_find_var, ecs_iter_set_var, ecs_iter_get_var, ecs_field_is_set, ecs_field_src, ecs_query_changed, ecs_iter_changed, ecs_query_cache_kind_t_EcsQueryCacheAuto, ecs_lookup_path_w_sep, ecs_auto_override_id, ecs_bulk_init, ecs_delete_with, ecs_script_init, ecs_script_update, ecs_script_clear, ecs_script_desc_t, ecs_ptr_from_json, ecs_entity_from_json, ecs_bulk_desc_t, ecs_stage_is_readonly, ecs_query_fini, EcsSlotOf, ecs_get_target, ecs_is_alive, ecs_set_scope, ecs_get_scope, ecs_os_api, EcsChildOf, EcsInherit, EcsIsA, EcsOnInstantiate, EcsOnLoad, EcsOnStart, EcsOnStore, EcsOnValidate, EcsPhase, EcsPostLoad, EcsPostUpdate, EcsPreStore, EcsPreUpdate, EcsPrefab};
 use std::ffi::CStr;
 use std::{borrow::BorrowMut, mem::MaybeUninit};
 use core::ffi::c_void;
@@ -35,13 +35,14 @@ pub struct Entity {
 
 pub struct Query {
     pub desc: RefCell<ecs_query_desc_t>,
-    pub query: RefCell<ecs_query_t>,
+    pub query: RefCell<*mut ecs_query_t>,
     pub iter: RefCell<ecs_iter_t>
 }
 
 pub struct System {
     pub desc: RefCell<ecs_system_desc_t>,
     pub entity: RefCell<ecs_entity_t>,
+    pub phase: RefCell<ecs_entity_t>,
     pub system: RefCell<ecs_system_t>,
     pub callback: RefCell<Callback>
 }
@@ -71,6 +72,21 @@ pub struct Pipeline {
     pub entity: RefCell<ecs_entity_t>
 }
 
+pub struct Timer {
+    pub entity: RefCell<ecs_entity_t>
+}
+
+pub struct Prefab {
+    pub entity: ecs_entity_t,
+    pub parent: Option<ecs_entity_t>
+}
+
+// Event emitted by a timer system every time its timer ticks
+struct TimerEvent {
+    event: ecs_entity_t,
+    target: ecs_entity_t
+}
+
 pub struct EcsWorldPtr(pub *mut ecs_world_t);
 unsafe impl Send for EcsWorldPtr {}
 unsafe impl Sync for EcsWorldPtr {}
@@ -143,11 +159,39 @@ fn map_event(event: Event) -> ecs_entity_t {
             Event::OnDeleteTarget => toxoid_flecs::EcsOnDeleteTarget,
             Event::OnTableCreate => toxoid_flecs::EcsOnTableCreate,
             Event::OnTableDelete => toxoid_flecs::EcsOnTableDelete,
+            Event::Custom(entity) => entity,
             _ => 0
         }
     }
 }
 
+fn map_phase(phase: Phases) -> ecs_entity_t {
+    unsafe { 
+        match phase {
+            Phases::OnStart => EcsOnStart,
+            Phases::OnLoad => EcsOnLoad,
+            Phases::PostLoad => EcsPostLoad,
+            Phases::PreUpdate => EcsPreUpdate,
+            Phases::OnUpdate => EcsOnUpdate,
+            Phases::OnValidate => EcsOnValidate,
+            Phases::PostUpdate => EcsPostUpdate,
+            Phases::PreStore => EcsPreStore,
+            Phases::OnStore => EcsOnStore,
+            Phases::Custom(entity) => entity
+        } 
+    }
+}
+
+fn map_relationship(relationship: Relationship) -> ecs_entity_t {
+    unsafe {
+        match relationship {
+            Relationship::IsA => EcsIsA,
+            Relationship::ChildOf => EcsChildOf,
+            Relationship::Custom(entity) => entity
+        }
+    }
+}
+
 unsafe fn map_member_type(member_type: u8) -> ecs_entity_t {
     match member_type {
         0 => toxoid_flecs::bindings::FLECS_IDecs_u8_tID_,
@@ -899,13 +943,67 @@ impl GuestEntity for Entity {
         unimplemented!("Relationships not implemented");
     }
 
+    fn get_path(&self) -> String {
+        unsafe {
+            let path = ecs_get_path_w_sep(WORLD.0, 0, self.id, c_string("."), std::ptr::null());
+            if path.is_null() {
+                return String::new();
+            }
+            let path_str = CStr::from_ptr(path).to_str().unwrap_or("").to_string();
+            // The path is allocated by Flecs
+            ecs_os_api.free_.unwrap()(path as *mut c_void);
+            path_str
+        }
+    }
+
+    fn target(&self, relationship: Relationship, index: i32) -> Option<EcsEntityT> {
+        let relationship_entity = map_relationship(relationship);
+        let target = unsafe { ecs_get_target(WORLD.0, self.id, relationship_entity, index) };
+        if target == 0 { None } else { Some(target) }
+    }
+
+    fn targets(&self, relationship: Relationship) -> Vec<EcsEntityT> {
+        let relationship_entity = map_relationship(relationship);
+        let mut targets = Vec::new();
+        loop {
+            let target = unsafe { ecs_get_target(WORLD.0, self.id, relationship_entity, targets.len() as i32) };
+            if target == 0 {
+                break;
+            }
+            targets.push(target);
+        }
+        targets
+    }
+
+    // Set component members from a JSON object, e.g. `{"x": 10, "y": 20}`
+    fn set_json(&self, component: ecs_entity_t, json: String) -> bool {
+        let json = std::ffi::CString::new(json).unwrap_or_default();
+        unsafe {
+            let ptr = ecs_ensure_id(WORLD.0, self.id, component);
+            let result = ecs_ptr_from_json(WORLD.0, component, ptr, json.as_ptr(), std::ptr::null());
+            if result.is_null() {
+                return false;
+            }
+            ecs_modified_id(WORLD.0, self.id, component);
+            true
+        }
+    }
+
+    // Add components from a serialized entity, e.g. `{"components": {"Position": {"x": 10, "y": 20}}}`
+    fn from_json(&self, json: String) -> bool {
+        let json = std::ffi::CString::new(json).unwrap_or_default();
+        unsafe { !ecs_entity_from_json(WORLD.0, self.id, json.as_ptr(), std::ptr::null()).is_null() }
+    }
+
+    fn lookup(&self, path: String) -> Option<EcsEntityT> {
+        // Resolve the path relative to this entity without searching its parents
+        let entity = unsafe { ecs_lookup_path_w_sep(WORLD.0, self.id, c_string(&path), c_string("."), std::ptr::null(), false) };
+        if entity == 0 { None } else { Some(entity) }
+    }
+
     fn add_relationship(&self, relationship: Relationship, target: ecs_entity_t) {
         unsafe { 
-            let relationship_entity = match relationship {
-                Relationship::IsA => EcsIsA,
-                Relationship::ChildOf => EcsChildOf,
-                Relationship::Custom(entity) => entity
-            };
+            let relationship_entity = map_relationship(relationship);
             let pair = ecs_make_pair(relationship_entity, target);
             ecs_add_id(WORLD.0, self.id, pair); 
         };
@@ -913,11 +1011,7 @@ impl GuestEntity for Entity {
 
     fn remove_relationship(&self, relationship: Relationship, target: ecs_entity_t) {
         unsafe {
-            let relationship_entity = match relationship {
-                Relationship::IsA => EcsIsA,
-                Relationship::ChildOf => EcsChildOf,
-                Relationship::Custom(entity) => entity
-            };
+            let relationship_entity = map_relationship(relationship);
             let pair = ecs_make_pair(relationship_entity, target);
             ecs_remove_id(WORLD.0, self.id, pair);
         }
@@ -938,14 +1032,14 @@ impl GuestQuery for Query {
         desc.expr = c_string(&query_desc.expr);
         Query { 
             desc: RefCell::new(desc), 
-            query: RefCell::new(unsafe { MaybeUninit::zeroed().assume_init() }), 
+            query: RefCell::new(std::ptr::null_mut()), 
             iter: RefCell::new(unsafe { MaybeUninit::zeroed().assume_init() }) 
         }
     }
 
     fn build(&self) { 
         let query = unsafe { ecs_query_init(WORLD.0, self.desc.as_ptr()) };
-        *self.query.borrow_mut() = unsafe { *query };
+        *self.query.borrow_mut() = query;
     }
 
     #[cfg(not(target_os = "emscripten"))]
@@ -967,9 +1061,8 @@ impl GuestQuery for Query {
     }
 
     fn iter(&self) -> PointerT {
-        let query = unsafe { *self.query.as_ptr() };
         // Create new iterator
-        let iter = unsafe { ecs_query_iter(WORLD.0, self.query.as_ptr()) };
+        let iter = unsafe { ecs_query_iter(WORLD.0, *self.query.borrow()) };
         
         // Store it in our RefCell
         *self.iter.borrow_mut() = iter;
@@ -1024,6 +1117,49 @@ impl GuestQuery for Query {
             vec![]
         }
     }
+
+    fn detect_changes(&self) {
+        // Change detection is only supported on cached queries, rebuild queries that were built uncached
+        self.desc.borrow_mut().cache_kind = ecs_query_cache_kind_t_EcsQueryCacheAuto;
+        let query = *self.query.borrow();
+        if !query.is_null() && unsafe { (*query).cache_kind } != ecs_query_cache_kind_t_EcsQueryCacheAuto {
+            unsafe { ecs_query_fini(query) };
+            self.build();
+        }
+    }
+
+    fn changed(&self) -> bool {
+        let query = *self.query.borrow();
+        if query.is_null() {
+            return false;
+        }
+        // Without a cache there is nothing to compare against, so uncached queries always report a change
+        if unsafe { (*query).cache_kind } != ecs_query_cache_kind_t_EcsQueryCacheAuto {
+            return true;
+        }
+        unsafe { ecs_query_changed(query) }
+    }
+
+    fn set_var(&self, name: String, value: EcsEntityT) {
+        // Variables constrain the iterator, so this has to be called after `iter` and before `next`
+        let name = name.trim_start_matches('$');
+        let var_id = unsafe { ecs_query_find_var(*self.query.borrow(), c_string(name)) };
+        if var_id != -1 {
+            unsafe { ecs_iter_set_var(self.iter.as_ptr(), var_id, value) };
+        }
+    }
+
+    fn get_var(&self, name: String) -> EcsEntityT {
+        iter_get_var(self.iter.as_ptr(), &name)
+    }
+
+    fn is_set(&self, index: i8) -> bool {
+        unsafe { ecs_field_is_set(self.iter.as_ptr(), index) }
+    }
+
+    fn field_src(&self, index: i8) -> EcsEntityT {
+        unsafe { ecs_field_src(self.iter.as_ptr(), index) }
+    }
 }
 
 impl GuestIter for Iter {
@@ -1075,6 +1211,34 @@ impl GuestIter for Iter {
             vec![]
         }
     }
+
+    fn get_var(&self, name: String) -> EcsEntityT {
+        iter_get_var(self.ptr as *mut ecs_iter_t, &name)
+    }
+
+    fn is_set(&self, index: i8) -> bool {
+        unsafe { ecs_field_is_set(self.ptr as *mut ecs_iter_t, index) }
+    }
+
+    fn field_src(&self, index: i8) -> EcsEntityT {
+        unsafe { ecs_field_src(self.ptr as *mut ecs_iter_t, index) }
+    }
+
+    fn changed(&self) -> bool {
+        unsafe { ecs_iter_changed(self.ptr as *mut ecs_iter_t) }
+    }
+}
+
+// Look up a query variable (e.g. `$Parent`) by name on the query of an iterator, 0 if it does not exist
+fn iter_get_var(iter: *mut ecs_iter_t, name: &str) -> EcsEntityT {
+    unsafe {
+        let name = name.trim_start_matches('$');
+        let var_id = ecs_query_find_var((*iter).query, c_string(name));
+        if var_id == -1 {
+            return 0;
+        }
+        ecs_iter_get_var(iter, var_id)
+    }
 }
 
 pub static mut QUERY_TRAMPOLINE: Option<unsafe extern "C" fn(*mut ecs_iter_t)> = None;
@@ -1090,8 +1254,10 @@ impl GuestSystem for System {
     fn new(desc: SystemDesc) -> System {
         // Create system entity
         let mut entity_desc: ecs_entity_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
-        // We have to add this pair so that the system is part of standard progress stage
-        let pair = &[unsafe { ecs_make_pair(EcsDependsOn, EcsOnUpdate) }];
+        // We have to add this pair so that the system is part of a pipeline phase,
+        // systems without an explicit phase run in the standard progress stage
+        let phase = desc.phase.unwrap_or(unsafe { EcsOnUpdate });
+        let pair = &[unsafe { ecs_make_pair(EcsDependsOn, phase) }, 0];
         entity_desc.add = pair.as_ptr() as *const u64;
         if let Some(name) = desc.name.clone() {
             entity_desc.name = c_string(&name);
@@ -1100,7 +1266,12 @@ impl GuestSystem for System {
         // Create system descriptor
         let mut system_desc: ecs_system_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
         system_desc.entity = entity;
-        system_desc.rate = desc.tick_rate.unwrap_or(1);
+        // Flecs applies the rate, interval and tick source when the system is initialized
+        system_desc.rate = desc.tick_rate.unwrap_or(0);
+        system_desc.interval = desc.interval.unwrap_or(0.);
+        system_desc.tick_source = desc.tick_source.unwrap_or(0);
+        system_desc.multi_threaded = desc.multi_threaded;
+        system_desc.immediate = desc.immediate;
         let mut query_desc: ecs_query_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
         query_desc.expr = c_string(&desc.query_desc.expr);
         system_desc.query = query_desc;
@@ -1114,6 +1285,7 @@ impl GuestSystem for System {
         System { 
             desc: RefCell::new(system_desc),
             entity: RefCell::new(entity), 
+            phase: RefCell::new(phase),
             callback: RefCell::new(Callback::new(desc.callback)),
             system: RefCell::new(
                 unsafe { MaybeUninit::zeroed().assume_init() }
@@ -1151,8 +1323,36 @@ impl GuestSystem for System {
 
     fn build(&self) {
         *self.entity.borrow_mut() = unsafe { ecs_system_init(WORLD.0, self.desc.as_ptr()) };
-        // Set tickrate using world, system id, description, and 0 (to use frames as a source)
-        unsafe { ecs_set_rate(WORLD.0, *self.entity.borrow(), self.desc.borrow().rate, 0) };
+    }
+
+    fn phase(&self, phase: Phases) {
+        let entity = *self.entity.borrow();
+        let phase = map_phase(phase);
+        unsafe {
+            // Move the system from its current phase to the new one
+            ecs_remove_id(WORLD.0, entity, ecs_make_pair(EcsDependsOn, *self.phase.borrow()));
+            ecs_add_id(WORLD.0, entity, ecs_make_pair(EcsDependsOn, phase));
+        }
+        *self.phase.borrow_mut() = phase;
+    }
+
+    fn interval(&self, seconds: f32) {
+        // Flecs does not allow both an interval and a rate on the same system
+        let mut desc = self.desc.borrow_mut();
+        desc.interval = seconds;
+        desc.rate = 0;
+    }
+
+    fn multi_threaded(&self) {
+        self.desc.borrow_mut().multi_threaded = true;
+    }
+
+    fn immediate(&self) {
+        self.desc.borrow_mut().immediate = true;
+    }
+
+    fn tick_source(&self, source: ecs_entity_t) {
+        self.desc.borrow_mut().tick_source = source;
     }
 
     fn disable(&self) {
@@ -1232,24 +1432,12 @@ impl GuestCallback for Callback {
 impl GuestPhase for Phase {
     fn new(name: String) -> Phase {
         let entity = unsafe { ecs_new_w_id(WORLD.0, EcsPhase) };
+        unsafe { ecs_set_name(WORLD.0, entity, c_string(&name)) };
         Phase { name, entity: RefCell::new(entity) }
     }
 
     fn depends_on(&self, phase: Phases) {
-       let phase = unsafe { 
-            match phase {
-                Phases::OnStart => EcsOnStart,
-                Phases::OnLoad => EcsOnLoad,
-                Phases::PostLoad => EcsPostLoad,
-                Phases::PreUpdate => EcsPreUpdate,
-                Phases::OnUpdate => EcsOnUpdate,
-                Phases::OnValidate => EcsOnValidate,
-                Phases::PostUpdate => EcsPostUpdate,
-                Phases::PreStore => EcsPreStore,
-                Phases::OnStore => EcsOnStore,
-                Phases::Custom(entity) => entity
-            } 
-        };
+        let phase = map_phase(phase);
         let pair = unsafe { ecs_make_pair(EcsDependsOn, phase) };
         unsafe { ecs_add_id(WORLD.0, *self.entity.borrow(), pair) };
     }
@@ -1293,6 +1481,181 @@ impl GuestPipeline for Pipeline {
     }
 }
 
+unsafe extern "C" fn timer_event_callback(iter: *mut ecs_iter_t) {
+    let timer_event = &*((*iter).ctx as *const TimerEvent);
+    // Observers match the event against the components of the target
+    let ids = ecs_get_type(WORLD.0, timer_event.target);
+    if ids.is_null() {
+        return;
+    }
+    let mut event_desc: ecs_event_desc_t = MaybeUninit::zeroed().assume_init();
+    event_desc.event = timer_event.event;
+    event_desc.entity = timer_event.target;
+    event_desc.ids = ids;
+    ecs_emit(WORLD.0, &mut event_desc);
+}
+
+unsafe extern "C" fn timer_event_free(ctx: *mut c_void) {
+    drop(Box::from_raw(ctx as *mut TimerEvent));
+}
+
+// Create `count` entities with the same ids in a single table insert.
+// Bulk init can't run while the world is readonly, so inside systems the entities are created one by one with deferred commands.
+fn bulk_init(count: u32, ids: &[ecs_entity_t]) -> Vec<ecs_entity_t> {
+    if count == 0 {
+        return vec![];
+    }
+    unsafe {
+        if ecs_stage_is_readonly(WORLD.0) || ecs_is_deferred(WORLD.0) {
+            let entity_desc: ecs_entity_desc_t = MaybeUninit::zeroed().assume_init();
+            return (0..count)
+                .map(|_| {
+                    let entity = ecs_entity_init(WORLD.0, &entity_desc);
+                    ids.iter().for_each(|id| ecs_add_id(WORLD.0, entity, *id));
+                    entity
+                })
+                .collect();
+        }
+        let mut bulk_desc: ecs_bulk_desc_t = MaybeUninit::zeroed().assume_init();
+        // The ids array is fixed size and 0 terminated, ids that don't fit are added after the bulk insert
+        let (bulk_ids, rest) = ids.split_at(ids.len().min(bulk_desc.ids.len() - 1));
+        bulk_desc.count = count as i32;
+        bulk_desc.ids[..bulk_ids.len()].copy_from_slice(bulk_ids);
+        let entities = ecs_bulk_init(WORLD.0, &bulk_desc);
+        if entities.is_null() {
+            return vec![];
+        }
+        let entities = std::slice::from_raw_parts(entities, count as usize).to_vec();
+        entities
+            .iter()
+            .for_each(|entity| rest.iter().for_each(|id| ecs_add_id(WORLD.0, *entity, *id)));
+        entities
+    }
+}
+
+impl GuestPrefab for Prefab {
+    fn new(name: Option<String>, parent: Option<ecs_entity_t>) -> Prefab {
+        unsafe {
+            let mut entity_desc: ecs_entity_desc_t = MaybeUninit::zeroed().assume_init();
+            if let Some(name) = name {
+                entity_desc.name = c_string(&name);
+            }
+            // Child prefabs are instantiated along with their parent
+            if let Some(parent) = parent {
+                entity_desc.parent = parent;
+            }
+            let add = [EcsPrefab, 0];
+            entity_desc.add = add.as_ptr();
+            let entity = ecs_entity_init(WORLD.0, &entity_desc);
+            Prefab { entity, parent }
+        }
+    }
+
+    fn get_id(&self) -> ecs_entity_t {
+        self.entity
+    }
+
+    fn add(&self, component: ecs_entity_t) {
+        unsafe { ecs_add_id(WORLD.0, self.entity, component) };
+    }
+
+    // Members set on the returned component are the default values of instances
+    fn get(&self, component: ecs_entity_t) -> PointerT {
+        unsafe { ecs_ensure_id(WORLD.0, self.entity, component) as PointerT }
+    }
+
+    // Copy an inherited component into each instance instead of sharing the prefab's value
+    fn override_component(&self, component: ecs_entity_t) {
+        unsafe { ecs_auto_override_id(WORLD.0, self.entity, component) };
+    }
+
+    // Instances share the prefab's value until they override it.
+    // This is a trait of the component so it applies to every prefab and has to be set before the component is used.
+    fn inherit_component(&self, component: ecs_entity_t) {
+        unsafe { ecs_add_id(WORLD.0, component, ecs_make_pair(EcsOnInstantiate, EcsInherit)) };
+    }
+
+    fn is_a(&self, base: ecs_entity_t) {
+        unsafe { ecs_add_id(WORLD.0, self.entity, ecs_make_pair(EcsIsA, base)) };
+    }
+
+    // Instances of the parent get a (slot, instance child) pair to find the child directly
+    fn slot(&self) -> bool {
+        let Some(parent) = self.parent else {
+            println!("Slot prefab must have a parent prefab");
+            return false;
+        };
+        unsafe { ecs_add_id(WORLD.0, self.entity, ecs_make_pair(EcsSlotOf, parent)) };
+        true
+    }
+
+    fn instantiate(&self, count: u32) -> Vec<ecs_entity_t> {
+        let pair = unsafe { ecs_make_pair(EcsIsA, self.entity) };
+        bulk_init(count, &[pair])
+    }
+}
+
+impl GuestTimer for Timer {
+    fn new() -> Timer {
+        let entity_desc: ecs_entity_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        let entity = unsafe { ecs_entity_init(WORLD.0, &entity_desc) };
+        Timer { entity: RefCell::new(entity) }
+    }
+
+    fn get_id(&self) -> ecs_entity_t {
+        *self.entity.borrow()
+    }
+
+    fn set_timeout(&self, seconds: f32) {
+        unsafe { ecs_set_timeout(WORLD.0, *self.entity.borrow(), seconds) };
+    }
+
+    fn get_timeout(&self) -> f32 {
+        unsafe { ecs_get_timeout(WORLD.0, *self.entity.borrow()) }
+    }
+
+    fn set_interval(&self, seconds: f32) {
+        unsafe { ecs_set_interval(WORLD.0, *self.entity.borrow(), seconds) };
+    }
+
+    fn get_interval(&self) -> f32 {
+        unsafe { ecs_get_interval(WORLD.0, *self.entity.borrow()) }
+    }
+
+    fn set_rate(&self, rate: i32, source: Option<ecs_entity_t>) {
+        // A source of 0 uses frames as the tick source
+        unsafe { ecs_set_rate(WORLD.0, *self.entity.borrow(), rate, source.unwrap_or(0)) };
+    }
+
+    fn start(&self) {
+        unsafe { ecs_start_timer(WORLD.0, *self.entity.borrow()) };
+    }
+
+    fn stop(&self) {
+        unsafe { ecs_stop_timer(WORLD.0, *self.entity.borrow()) };
+    }
+
+    fn reset(&self) {
+        unsafe { ecs_reset_timer(WORLD.0, *self.entity.borrow()) };
+    }
+
+    fn fire(&self, event: ecs_entity_t, target: ecs_entity_t) {
+        // Create a system without a query that runs once every time the timer ticks
+        let mut entity_desc: ecs_entity_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        let pair = &[unsafe { ecs_make_pair(EcsDependsOn, EcsOnUpdate) }, 0];
+        entity_desc.add = pair.as_ptr() as *const u64;
+        let entity = unsafe { ecs_entity_init(WORLD.0, &entity_desc) };
+        let mut system_desc: ecs_system_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        system_desc.entity = entity;
+        system_desc.tick_source = *self.entity.borrow();
+        // Emit outside of deferred mode so observers run in the same frame
+        system_desc.immediate = true;
+        system_desc.ctx = Box::into_raw(Box::new(TimerEvent { event, target })) as *mut c_void;
+        system_desc.ctx_free = Some(timer_event_free);
+        system_desc.callback = Some(timer_event_callback);
+        unsafe { ecs_system_init(WORLD.0, &system_desc) };
+    }
+}
 
 impl Guest for ToxoidApi {
     type ComponentType = ComponentType;
@@ -1305,13 +1668,15 @@ impl Guest for ToxoidApi {
     type Callback = Callback;
     type Phase = Phase;
     type Pipeline = Pipeline;
+    type Timer = Timer;
+    type Prefab = Prefab;
     
     fn add_singleton(component: ecs_entity_t) {
         unsafe { ecs_add_id(WORLD.0, component, component) };   
     }
 
     fn get_singleton(component: ecs_entity_t) -> u64 {
-        // unsafe { ecs_get_mut_id(WORLD.0, component, component) as u64 }
+        // Ensure adds the singleton on first access
         unsafe { ecs_ensure_id(WORLD.0, component, component) as u64 }  
     }
 
@@ -1327,6 +1692,24 @@ impl Guest for ToxoidApi {
         unsafe { ecs_delete(WORLD.0, entity); }
     }
 
+    fn get_entity(entity_id: ecs_entity_t) -> Option<ecs_entity_t> {
+        if unsafe { ecs_is_alive(WORLD.0, entity_id) } { Some(entity_id) } else { None }
+    }
+
+    fn get_entity_named(name: String) -> Option<ecs_entity_t> {
+        // Paths are separated by dots (e.g. "Level.Player") and resolved from the current scope up to the root
+        let entity = unsafe { ecs_lookup_path_w_sep(WORLD.0, 0, c_string(&name), c_string("."), std::ptr::null(), true) };
+        if entity == 0 { None } else { Some(entity) }
+    }
+
+    fn set_scope(scope: ecs_entity_t) -> ecs_entity_t {
+        unsafe { ecs_set_scope(WORLD.0, scope) }
+    }
+
+    fn get_scope() -> ecs_entity_t {
+        unsafe { ecs_get_scope(WORLD.0) }
+    }
+
     fn has_entity_named(name: String) -> bool {
         // Convert name to c_string
         let c_name = c_string(&name);
@@ -1337,6 +1720,91 @@ impl Guest for ToxoidApi {
         let c_name = c_string(&component_name);
         unsafe { ecs_lookup(WORLD.0, c_name) }
     }
+
+    fn make_pair(relationship: ecs_entity_t, target: ecs_entity_t) -> ecs_entity_t {
+        unsafe { ecs_make_pair(relationship, target) }
+    }
+
+    fn spawn_batch(count: u32, components: Vec<ecs_entity_t>) -> Vec<ecs_entity_t> {
+        bulk_init(count, &components)
+    }
+
+    fn delete_with(component: ecs_entity_t) {
+        unsafe { ecs_delete_with(WORLD.0, component) };
+    }
+
+    fn script_init(filename: String, code: String) -> ecs_entity_t {
+        // Not cached with `c_string`, scripts can be large and change on reload
+        let filename = std::ffi::CString::new(filename).unwrap_or_default();
+        let code = std::ffi::CString::new(code).unwrap_or_default();
+        let mut script_desc: ecs_script_desc_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        script_desc.filename = filename.as_ptr();
+        script_desc.code = code.as_ptr();
+        unsafe { ecs_script_init(WORLD.0, &script_desc) }
+    }
+
+    fn script_update(script: ecs_entity_t, code: String) -> bool {
+        // Entities created by the previous version of the script are deleted first
+        let code = std::ffi::CString::new(code).unwrap_or_default();
+        unsafe { ecs_script_update(WORLD.0, script, 0, code.as_ptr()) == 0 }
+    }
+
+    fn script_clear(script: ecs_entity_t) {
+        unsafe { ecs_script_clear(WORLD.0, script, 0) };
+    }
+
+    fn defer_begin() -> bool {
+        unsafe { ecs_defer_begin(WORLD.0) }
+    }
+
+    fn defer_end() -> bool {
+        unsafe { ecs_defer_end(WORLD.0) }
+    }
+
+    fn is_deferred() -> bool {
+        unsafe { ecs_is_deferred(WORLD.0) }
+    }
+
+    // Returns false without doing anything when the world is already readonly or deferred,
+    // e.g. inside a system, since flecs asserts on entering readonly mode again
+    fn readonly_begin(multi_threaded: bool) -> bool {
+        unsafe {
+            if ecs_stage_is_readonly(WORLD.0) || ecs_is_deferred(WORLD.0) {
+                return false;
+            }
+            ecs_readonly_begin(WORLD.0, multi_threaded);
+        }
+        true
+    }
+
+    fn readonly_end() {
+        unsafe { ecs_readonly_end(WORLD.0) };
+    }
+
+    fn submit(commands: Vec<Command>) {
+        unsafe {
+            // Commands are queued and applied together when the outermost defer ends
+            ecs_defer_begin(WORLD.0);
+            for command in commands {
+                match command {
+                    Command::Add((entity, component)) => ecs_add_id(WORLD.0, entity, component),
+                    Command::Remove((entity, component)) => ecs_remove_id(WORLD.0, entity, component),
+                    Command::AddRelationship((entity, relationship, target)) => {
+                        let pair = ecs_make_pair(map_relationship(relationship), target);
+                        ecs_add_id(WORLD.0, entity, pair)
+                    },
+                    Command::RemoveRelationship((entity, relationship, target)) => {
+                        let pair = ecs_make_pair(map_relationship(relationship), target);
+                        ecs_remove_id(WORLD.0, entity, pair)
+                    },
+                    Command::Enable(entity) => ecs_enable(WORLD.0, entity, true),
+                    Command::Disable(entity) => ecs_enable(WORLD.0, entity, false),
+                    Command::Delete(entity) => ecs_delete(WORLD.0, entity)
+                }
+            }
+            ecs_defer_end(WORLD.0);
+        }
+    }
 }
 
 // TODO: Don't forget to clean up the cache when components are deleted!
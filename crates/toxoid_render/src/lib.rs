@@ -37,16 +37,27 @@ pub trait Renderer2D {
     fn window_size() -> (u32, u32);
     // Create render target that we can blit sprites on (Tilemaps for example)
     fn create_render_target(width: u32, height: u32) -> Box<dyn RenderTarget>;
+    // Free the GPU resources of a render target
+    fn destroy_render_target(render_target: Box<dyn RenderTarget>);
     // Create image
     fn create_image(data: *const u8, size: usize) -> Box<dyn Image>;
     // Create sprite
     fn create_sprite(data: *const u8, size: usize) -> Box<dyn Sprite>;
     // Blit sprite (draw sprite on another base sprite)
     fn blit_sprite(source: &Box<dyn Sprite>, sx: f32, sy: f32, sw: f32, sh: f32, destination: &Box<dyn RenderTarget>, dx: f32, dy: f32);
+    // Blit sprite flipped horizontally, vertically and / or diagonally (x and y swapped) around its center
+    fn blit_sprite_flipped(source: &Box<dyn Sprite>, sx: f32, sy: f32, sw: f32, sh: f32, destination: &Box<dyn RenderTarget>, dx: f32, dy: f32, flip_x: bool, flip_y: bool, flip_diagonal: bool);
     // Resize sprite
     fn resize_sprite(sprite: &Box<dyn Sprite>, width: u32, height: u32);
     // Draw sprite
     fn draw_sprite(sprite: &Box<dyn Sprite>, x: f32, y: f32);
+    // Begin drawing a camera view of view_width x view_height into a viewport rect, in game coordinates on the screen
+    // or in pixels of the destination render target. A clear color (0xRRGGBBAA) with alpha 0 doesn't clear.
+    fn begin_camera(destination: Option<&Box<dyn RenderTarget>>, x: f32, y: f32, width: f32, height: f32, view_width: f32, view_height: f32, clear_color: u32);
+    fn end_camera(destination: Option<&Box<dyn RenderTarget>>);
+    // Zoom around the view center and offset everything drawn until the transform is popped, e.g. for screen shake
+    fn push_camera_transform(view_width: f32, view_height: f32, zoom: f32, offset_x: f32, offset_y: f32);
+    fn pop_camera_transform();
     // Draw render target
     fn draw_render_target(source: &Box<dyn RenderTarget>, sx: f32, sy: f32, sw: f32, sh: f32, dx: f32, dy: f32, dw: f32, dh: f32, blend_mode: u8);
     // Draw a filled rect
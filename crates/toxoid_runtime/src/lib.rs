@@ -14,19 +14,21 @@ bindgen!({
         "toxoid-component:component/ecs/observer": ObserverProxy,
         "toxoid-component:component/ecs/pipeline": PipelineProxy,
         "toxoid-component:component/ecs/phase": PhaseProxy,
+        "toxoid-component:component/ecs/timer": TimerProxy,
+        "toxoid-component:component/ecs/prefab": PrefabProxy,
     },
 });
 
-use std::collections::HashMap;
 use toxoid_api::{EcsEntityT, GuestObserver};
 use toxoid_component::component::ecs::PointerT;
-use toxoid_host::bindings::exports::toxoid::engine::ecs::{Guest, GuestCallback, GuestComponent, GuestComponentType, GuestEntity, GuestIter, GuestPhase, GuestPipeline, GuestQuery, GuestSystem};
+use toxoid_host::bindings::exports::toxoid::engine::ecs::{Guest, GuestCallback, GuestComponent, GuestComponentType, GuestEntity, GuestIter, GuestPhase, GuestPipeline, GuestQuery, GuestSystem, GuestTimer, GuestPrefab};
 use toxoid_host::ToxoidApi;
 use wasmtime::component::{bindgen, Component, Linker, Resource, ResourceTable};
 use wasmtime::{Config, Engine, OptLevel, Result, Store};
 use wasmtime_wasi::{WasiCtx, WasiView, WasiCtxBuilder};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
+use std::collections::HashMap;
 
 pub struct ComponentTypeProxy {
     ptr: *mut toxoid_host::ComponentType
@@ -68,11 +70,21 @@ pub struct PhaseProxy {
     pub ptr: *mut toxoid_host::Phase
 }
 unsafe impl Send for PhaseProxy {}
+pub struct TimerProxy {
+    pub ptr: *mut toxoid_host::Timer
+}
+unsafe impl Send for TimerProxy {}
+pub struct PrefabProxy {
+    pub ptr: *mut toxoid_host::Prefab
+}
+unsafe impl Send for PrefabProxy {}
 
 // StoreState is the state of the WASM store.
 pub struct StoreState {
     pub ctx: WasiCtx,
     pub table: ResourceTable,
+    // Singleton component resources by component id, reused so every `get_singleton` doesn't push a new one
+    pub singletons: HashMap<toxoid_component::component::ecs::EcsEntityT, u32>,
 }
 
 // A trait which provides access to internal WASI state.
@@ -82,44 +94,73 @@ impl WasiView for StoreState {
     fn table(&mut self) -> &mut ResourceTable { &mut self.table }
 }
 
-static mut SINGLETON_MAP: Lazy<HashMap<toxoid_component::component::ecs::EcsEntityT, u32>> = Lazy::new(|| HashMap::new());
+// Convert guest phases to host phases
+fn map_phases(phase: toxoid_component::component::ecs::Phases) -> toxoid_host::bindings::exports::toxoid::engine::ecs::Phases {
+    match phase {
+        toxoid_component::component::ecs::Phases::OnStart => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnStart,
+        toxoid_component::component::ecs::Phases::OnLoad => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnLoad,
+        toxoid_component::component::ecs::Phases::PostLoad => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PostLoad,
+        toxoid_component::component::ecs::Phases::PreUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PreUpdate,
+        toxoid_component::component::ecs::Phases::OnUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnUpdate,
+        toxoid_component::component::ecs::Phases::OnValidate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnValidate,
+        toxoid_component::component::ecs::Phases::PostUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PostUpdate,
+        toxoid_component::component::ecs::Phases::PreStore => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PreStore,
+        toxoid_component::component::ecs::Phases::OnStore => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnStore,
+        toxoid_component::component::ecs::Phases::Custom(entity) => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::Custom(entity),
+    }
+}
+
+// Convert guest relationships to host relationships
+fn map_relationship(relationship: toxoid_component::component::ecs::Relationship) -> toxoid_api::Relationship {
+    match relationship {
+        toxoid_component::component::ecs::Relationship::IsA => toxoid_api::Relationship::IsA,
+        toxoid_component::component::ecs::Relationship::ChildOf => toxoid_api::Relationship::ChildOf,
+        toxoid_component::component::ecs::Relationship::Custom(entity) => toxoid_api::Relationship::Custom(entity)
+    }
+}
+
+// Convert guest commands to host commands
+fn map_command(command: toxoid_component::component::ecs::Command) -> toxoid_host::bindings::exports::toxoid::engine::ecs::Command {
+    use toxoid_component::component::ecs::Command as GuestCommand;
+    use toxoid_host::bindings::exports::toxoid::engine::ecs::Command as HostCommand;
+    match command {
+        GuestCommand::Add(add) => HostCommand::Add(add),
+        GuestCommand::Remove(remove) => HostCommand::Remove(remove),
+        GuestCommand::AddRelationship((entity, relationship, target)) => HostCommand::AddRelationship((entity, map_relationship(relationship), target)),
+        GuestCommand::RemoveRelationship((entity, relationship, target)) => HostCommand::RemoveRelationship((entity, map_relationship(relationship), target)),
+        GuestCommand::Enable(entity) => HostCommand::Enable(entity),
+        GuestCommand::Disable(entity) => HostCommand::Disable(entity),
+        GuestCommand::Delete(entity) => HostCommand::Delete(entity)
+    }
+}
 
 impl toxoid_component::component::ecs::Host for StoreState {
     fn add_singleton(&mut self, component: toxoid_component::component::ecs::EcsEntityT) {
         ToxoidApi::add_singleton(component);
-        // TODO: Check if !ptr.is_null()
+    }
+
+    fn get_singleton(&mut self, component: toxoid_component::component::ecs::EcsEntityT) -> Resource<ComponentProxy> {
+        // Singletons live in the world, so any instance can read them and they are added on first access
         let component_ptr = ToxoidApi::get_singleton(component);
+        if let Some(rep) = self.singletons.get(&component) {
+            // The singleton may have moved since the resource was created
+            let resource = Resource::<ComponentProxy>::new_own(*rep);
+            let component_proxy = self.table.get(&resource).unwrap() as &ComponentProxy;
+            unsafe { (*component_proxy.ptr).ptr = component_ptr as *const std::ffi::c_void };
+            return resource;
+        }
         let host_component = toxoid_host::Component::new(component_ptr, component, component);
         let boxed_component_ptr = Box::into_raw(Box::new(host_component));
         let resource = self.table.push::<ComponentProxy>(ComponentProxy {
             ptr: boxed_component_ptr 
         })
             .expect("Failed to push component to table");
-        unsafe {
-            SINGLETON_MAP.insert(component, resource.rep());
-        }
-    }
-
-    fn get_singleton(&mut self, component: toxoid_component::component::ecs::EcsEntityT) -> Resource<ComponentProxy> {
-        unsafe {
-            if SINGLETON_MAP.contains_key(&component) {    
-                let resource_rep = SINGLETON_MAP.get(&component).unwrap().clone();
-                let resource = Resource::<ComponentProxy>::new_own(resource_rep);
-                resource
-            } else {
-                panic!("Failed to get singleton, component ID: {:?}", component);
-                // let ptr = ToxoidApi::get_singleton(component) as *mut toxoid_host::Component;
-                // let resource = self.table.push::<ComponentProxy>(ComponentProxy { ptr: ptr }).expect("Failed to push component to table");
-                // resource
-            }
-        }
+        self.singletons.insert(component, resource.rep());
+        resource
     }
 
     fn remove_singleton(&mut self, component: toxoid_component::component::ecs::EcsEntityT) {
         ToxoidApi::remove_singleton(component);
-        unsafe {
-            SINGLETON_MAP.remove(&component);
-        }
     }
 
     fn add_entity(&mut self, entity: toxoid_component::component::ecs::EcsEntityT) {
@@ -134,10 +175,23 @@ impl toxoid_component::component::ecs::Host for StoreState {
         ToxoidApi::remove_entity(entity);
     }
 
-    // fn get_entity_named(&mut self, name: String) -> Resource<EntityProxy> {
-    //     let entity = ToxoidApi::get_entity_named(name);
-    //     self.table.push::<EntityProxy>(EntityProxy { ptr: entity }).unwrap()
-    // }
+    fn get_entity(&mut self, entity: toxoid_component::component::ecs::EcsEntityT) -> Option<Resource<EntityProxy>> {
+        ToxoidApi::get_entity(entity)
+            .map(|id| toxoid_component::component::ecs::HostEntity::from_id(self, id))
+    }
+
+    fn get_entity_named(&mut self, name: String) -> Option<Resource<EntityProxy>> {
+        ToxoidApi::get_entity_named(name)
+            .map(|id| toxoid_component::component::ecs::HostEntity::from_id(self, id))
+    }
+
+    fn set_scope(&mut self, scope: toxoid_component::component::ecs::EcsEntityT) -> toxoid_component::component::ecs::EcsEntityT {
+        ToxoidApi::set_scope(scope)
+    }
+
+    fn get_scope(&mut self) -> toxoid_component::component::ecs::EcsEntityT {
+        ToxoidApi::get_scope()
+    }
 
     fn has_entity_named(&mut self, name: String) -> bool {
         ToxoidApi::has_entity_named(name)
@@ -146,6 +200,54 @@ impl toxoid_component::component::ecs::Host for StoreState {
     fn get_component_id(&mut self, component_name: String) -> toxoid_component::component::ecs::EcsEntityT {
         ToxoidApi::get_component_id(component_name)
     }
+
+    fn make_pair(&mut self, relationship: toxoid_component::component::ecs::EcsEntityT, target: toxoid_component::component::ecs::EcsEntityT) -> toxoid_component::component::ecs::EcsEntityT {
+        ToxoidApi::make_pair(relationship, target)
+    }
+
+    fn spawn_batch(&mut self, count: u32, components: Vec<toxoid_component::component::ecs::EcsEntityT>) -> Vec<toxoid_component::component::ecs::EcsEntityT> {
+        ToxoidApi::spawn_batch(count, components)
+    }
+
+    fn delete_with(&mut self, component: toxoid_component::component::ecs::EcsEntityT) {
+        ToxoidApi::delete_with(component)
+    }
+
+    fn script_init(&mut self, filename: String, code: String) -> toxoid_component::component::ecs::EcsEntityT {
+        ToxoidApi::script_init(filename, code)
+    }
+
+    fn script_update(&mut self, script: toxoid_component::component::ecs::EcsEntityT, code: String) -> bool {
+        ToxoidApi::script_update(script, code)
+    }
+
+    fn script_clear(&mut self, script: toxoid_component::component::ecs::EcsEntityT) {
+        ToxoidApi::script_clear(script)
+    }
+
+    fn defer_begin(&mut self) -> bool {
+        ToxoidApi::defer_begin()
+    }
+
+    fn defer_end(&mut self) -> bool {
+        ToxoidApi::defer_end()
+    }
+
+    fn is_deferred(&mut self) -> bool {
+        ToxoidApi::is_deferred()
+    }
+
+    fn readonly_begin(&mut self, multi_threaded: bool) -> bool {
+        ToxoidApi::readonly_begin(multi_threaded)
+    }
+
+    fn readonly_end(&mut self) {
+        ToxoidApi::readonly_end()
+    }
+
+    fn submit(&mut self, commands: Vec<toxoid_component::component::ecs::Command>) {
+        ToxoidApi::submit(commands.into_iter().map(map_command).collect())
+    }
 }
 
 impl toxoid_component::component::ecs::HostIter for StoreState {
@@ -198,6 +300,38 @@ impl toxoid_component::component::ecs::HostIter for StoreState {
         result
     }
 
+    fn get_var(&mut self, iter: Resource<IterProxy>, name: String) -> EcsEntityT {
+        let iter_proxy = self.table.get(&iter).unwrap() as &IterProxy;
+        let iter = unsafe { Box::from_raw(iter_proxy.ptr) };
+        let result = iter.get_var(name);
+        Box::into_raw(iter);
+        result
+    }
+
+    fn is_set(&mut self, iter: Resource<IterProxy>, index: i8) -> bool {
+        let iter_proxy = self.table.get(&iter).unwrap() as &IterProxy;
+        let iter = unsafe { Box::from_raw(iter_proxy.ptr) };
+        let result = iter.is_set(index);
+        Box::into_raw(iter);
+        result
+    }
+
+    fn field_src(&mut self, iter: Resource<IterProxy>, index: i8) -> EcsEntityT {
+        let iter_proxy = self.table.get(&iter).unwrap() as &IterProxy;
+        let iter = unsafe { Box::from_raw(iter_proxy.ptr) };
+        let result = iter.field_src(index);
+        Box::into_raw(iter);
+        result
+    }
+
+    fn changed(&mut self, iter: Resource<IterProxy>) -> bool {
+        let iter_proxy = self.table.get(&iter).unwrap() as &IterProxy;
+        let iter = unsafe { Box::from_raw(iter_proxy.ptr) };
+        let result = iter.changed();
+        Box::into_raw(iter);
+        result
+    }
+
     fn drop(&mut self, _iter: Resource<IterProxy>) -> Result<(), wasmtime::Error> {
         Ok(())
     }
@@ -249,7 +383,12 @@ impl toxoid_component::component::ecs::HostSystem for StoreState {
             query_desc,
             callback: callback.cb_handle(),
             is_guest: true,
-            tick_rate: desc.tick_rate
+            tick_rate: desc.tick_rate,
+            phase: desc.phase,
+            interval: desc.interval,
+            tick_source: desc.tick_source,
+            multi_threaded: desc.multi_threaded,
+            immediate: desc.immediate
         });
         let id = self
             .table
@@ -306,6 +445,41 @@ impl toxoid_component::component::ecs::HostSystem for StoreState {
         Box::into_raw(system);
     }
 
+    fn phase(&mut self, system: Resource<toxoid_component::component::ecs::System>, phase: toxoid_component::component::ecs::Phases) -> () {
+        let system_proxy = self.table.get(&system).unwrap() as &SystemProxy;
+        let mut system = unsafe { Box::from_raw(system_proxy.ptr) };
+        system.as_mut().phase(map_phases(phase));
+        Box::into_raw(system);
+    }
+
+    fn interval(&mut self, system: Resource<toxoid_component::component::ecs::System>, seconds: f32) -> () {
+        let system_proxy = self.table.get(&system).unwrap() as &SystemProxy;
+        let mut system = unsafe { Box::from_raw(system_proxy.ptr) };
+        system.as_mut().interval(seconds);
+        Box::into_raw(system);
+    }
+
+    fn multi_threaded(&mut self, system: Resource<toxoid_component::component::ecs::System>) -> () {
+        let system_proxy = self.table.get(&system).unwrap() as &SystemProxy;
+        let mut system = unsafe { Box::from_raw(system_proxy.ptr) };
+        system.as_mut().multi_threaded();
+        Box::into_raw(system);
+    }
+
+    fn immediate(&mut self, system: Resource<toxoid_component::component::ecs::System>) -> () {
+        let system_proxy = self.table.get(&system).unwrap() as &SystemProxy;
+        let mut system = unsafe { Box::from_raw(system_proxy.ptr) };
+        system.as_mut().immediate();
+        Box::into_raw(system);
+    }
+
+    fn tick_source(&mut self, system: Resource<toxoid_component::component::ecs::System>, source: EcsEntityT) -> () {
+        let system_proxy = self.table.get(&system).unwrap() as &SystemProxy;
+        let mut system = unsafe { Box::from_raw(system_proxy.ptr) };
+        system.as_mut().tick_source(source);
+        Box::into_raw(system);
+    }
+
     fn drop(&mut self, _system: Resource<toxoid_component::component::ecs::System>) -> Result<(), wasmtime::Error> {
         Ok(())
     }
@@ -328,7 +502,8 @@ impl toxoid_component::component::ecs::HostObserver for StoreState {
                 toxoid_component::component::ecs::Event::OnDelete => toxoid_api::Event::OnDelete,
                 toxoid_component::component::ecs::Event::OnDeleteTarget => toxoid_api::Event::OnDeleteTarget,
                 toxoid_component::component::ecs::Event::OnTableCreate => toxoid_api::Event::OnTableCreate,
-                toxoid_component::component::ecs::Event::OnTableDelete => toxoid_api::Event::OnTableDelete
+                toxoid_component::component::ecs::Event::OnTableDelete => toxoid_api::Event::OnTableDelete,
+                toxoid_component::component::ecs::Event::Custom(entity) => toxoid_api::Event::Custom(*entity)
             })
                 .collect::<Vec<toxoid_api::Event>>(),
             callback: callback.cb_handle(),
@@ -466,11 +641,7 @@ impl toxoid_component::component::ecs::HostEntity for StoreState {
     fn add_relationship(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, relationship: toxoid_component::component::ecs::Relationship, target: toxoid_component::component::ecs::EcsEntityT) -> () {
         let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
         let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
-        let relationship = match relationship {
-            toxoid_component::component::ecs::Relationship::IsA => toxoid_api::Relationship::IsA,
-            toxoid_component::component::ecs::Relationship::ChildOf => toxoid_api::Relationship::ChildOf,
-            toxoid_component::component::ecs::Relationship::Custom(entity) => toxoid_api::Relationship::Custom(entity)
-        };
+        let relationship = map_relationship(relationship);
         entity.add_relationship(relationship, target);
         Box::into_raw(entity);
     }
@@ -478,11 +649,7 @@ impl toxoid_component::component::ecs::HostEntity for StoreState {
     fn remove_relationship(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, relationship: toxoid_component::component::ecs::Relationship, target: toxoid_component::component::ecs::EcsEntityT) -> () {
         let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
         let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
-        let relationship = match relationship {
-            toxoid_component::component::ecs::Relationship::IsA => toxoid_api::Relationship::IsA,
-            toxoid_component::component::ecs::Relationship::ChildOf => toxoid_api::Relationship::ChildOf,
-            toxoid_component::component::ecs::Relationship::Custom(entity) => toxoid_api::Relationship::Custom(entity)
-        };
+        let relationship = map_relationship(relationship);
         entity.remove_relationship(relationship, target);
         Box::into_raw(entity);
     }
@@ -525,6 +692,54 @@ impl toxoid_component::component::ecs::HostEntity for StoreState {
         relationships.iter().map(|relationship| self.from_id(*relationship)).collect()
     }
 
+    fn target(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, relationship: toxoid_component::component::ecs::Relationship, index: i32) -> Option<Resource<EntityProxy>> {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let target = entity.target(map_relationship(relationship), index);
+        Box::into_raw(entity);
+        target.map(|id| self.from_id(id))
+    }
+
+    fn targets(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, relationship: toxoid_component::component::ecs::Relationship) -> Vec<Resource<EntityProxy>> {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let targets = entity.targets(map_relationship(relationship));
+        Box::into_raw(entity);
+        targets.iter().map(|target| self.from_id(*target)).collect()
+    }
+
+    fn set_json(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, component: toxoid_component::component::ecs::EcsEntityT, json: String) -> bool {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let result = entity.set_json(component, json);
+        Box::into_raw(entity);
+        result
+    }
+
+    fn from_json(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, json: String) -> bool {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let result = entity.from_json(json);
+        Box::into_raw(entity);
+        result
+    }
+
+    fn get_path(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>) -> String {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let path = entity.get_path();
+        Box::into_raw(entity);
+        path
+    }
+
+    fn lookup(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>, path: String) -> Option<Resource<EntityProxy>> {
+        let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
+        let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
+        let child = entity.lookup(path);
+        Box::into_raw(entity);
+        child.map(|id| self.from_id(id))
+    }
+
     fn disable(&mut self, entity: Resource<toxoid_component::component::ecs::Entity>) -> () {
         let entity_proxy = self.table.get(&entity).unwrap() as &EntityProxy;
         let entity = unsafe { Box::from_raw(entity_proxy.ptr) };
@@ -1029,6 +1244,52 @@ impl toxoid_component::component::ecs::HostQuery for StoreState {
         components
     }
 
+    fn detect_changes(&mut self, query: Resource<toxoid_component::component::ecs::Query>) -> () {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        query.detect_changes();
+        Box::into_raw(query);
+    }
+
+    fn changed(&mut self, query: Resource<toxoid_component::component::ecs::Query>) -> bool {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        let result = query.changed();
+        Box::into_raw(query);
+        result
+    }
+
+    fn set_var(&mut self, query: Resource<toxoid_component::component::ecs::Query>, name: String, value: EcsEntityT) -> () {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        query.set_var(name, value);
+        Box::into_raw(query);
+    }
+
+    fn get_var(&mut self, query: Resource<toxoid_component::component::ecs::Query>, name: String) -> EcsEntityT {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        let result = query.get_var(name);
+        Box::into_raw(query);
+        result
+    }
+
+    fn is_set(&mut self, query: Resource<toxoid_component::component::ecs::Query>, index: i8) -> bool {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        let result = query.is_set(index);
+        Box::into_raw(query);
+        result
+    }
+
+    fn field_src(&mut self, query: Resource<toxoid_component::component::ecs::Query>, index: i8) -> EcsEntityT {
+        let query_proxy = self.table.get(&query).unwrap() as &QueryProxy;
+        let query = unsafe { Box::from_raw(query_proxy.ptr) };
+        let result = query.field_src(index);
+        Box::into_raw(query);
+        result
+    }
+
     fn drop(&mut self, _query: Resource<toxoid_component::component::ecs::Query>) -> Result<(), wasmtime::Error> {
         Ok(())
     }
@@ -1045,18 +1306,7 @@ impl toxoid_component::component::ecs::HostPhase for StoreState {
     fn depends_on(&mut self, phase: Resource<toxoid_component::component::ecs::Phase>, dependency: toxoid_component::component::ecs::Phases) -> () {
         let phase_proxy = self.table.get(&phase).unwrap() as &PhaseProxy;
         let phase = unsafe { Box::from_raw(phase_proxy.ptr) };
-        let dependency = match dependency {
-            toxoid_component::component::ecs::Phases::OnStart => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnStart,
-            toxoid_component::component::ecs::Phases::OnLoad => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnLoad,
-            toxoid_component::component::ecs::Phases::PostLoad => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PostLoad,
-            toxoid_component::component::ecs::Phases::PreUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PreUpdate,
-            toxoid_component::component::ecs::Phases::OnUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnUpdate,
-            toxoid_component::component::ecs::Phases::OnValidate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnValidate,
-            toxoid_component::component::ecs::Phases::PostUpdate => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PostUpdate,
-            toxoid_component::component::ecs::Phases::PreStore => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::PreStore,
-            toxoid_component::component::ecs::Phases::OnStore => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::OnStore,
-            toxoid_component::component::ecs::Phases::Custom(entity) => toxoid_host::bindings::exports::toxoid::engine::ecs::Phases::Custom(entity),
-        };
+        let dependency = map_phases(dependency);
         phase.depends_on(dependency);
         Box::into_raw(phase);
     }
@@ -1074,6 +1324,166 @@ impl toxoid_component::component::ecs::HostPhase for StoreState {
     }
 }
 
+impl toxoid_component::component::ecs::HostPrefab for StoreState {
+    fn new(&mut self, name: Option<String>, parent: Option<toxoid_component::component::ecs::EcsEntityT>) -> Resource<PrefabProxy> {
+        let prefab = <toxoid_host::Prefab as toxoid_host::bindings::exports::toxoid::engine::ecs::GuestPrefab>::new(name, parent);
+        let ptr = Box::into_raw(Box::new(prefab));
+        self.table.push::<PrefabProxy>(PrefabProxy { ptr }).unwrap()
+    }
+
+    fn get_id(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>) -> toxoid_component::component::ecs::EcsEntityT {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        let result = prefab.get_id();
+        Box::into_raw(prefab);
+        result
+    }
+
+    fn add(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, component: toxoid_component::component::ecs::EcsEntityT) -> () {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        prefab.add(component);
+        Box::into_raw(prefab);
+    }
+
+    fn get(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, component: toxoid_component::component::ecs::EcsEntityT) -> Resource<ComponentProxy> {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        let component_ptr = prefab.get(component);
+        let prefab_id = prefab.get_id();
+        Box::into_raw(prefab);
+        let host_component = toxoid_host::Component::new(component_ptr, prefab_id, component);
+        let boxed_component_ptr = Box::into_raw(Box::new(host_component));
+        self.table.push::<ComponentProxy>(ComponentProxy { ptr: boxed_component_ptr }).unwrap()
+    }
+
+    fn override_component(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, component: toxoid_component::component::ecs::EcsEntityT) -> () {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        prefab.override_component(component);
+        Box::into_raw(prefab);
+    }
+
+    fn inherit_component(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, component: toxoid_component::component::ecs::EcsEntityT) -> () {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        prefab.inherit_component(component);
+        Box::into_raw(prefab);
+    }
+
+    fn is_a(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, base: toxoid_component::component::ecs::EcsEntityT) -> () {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        prefab.is_a(base);
+        Box::into_raw(prefab);
+    }
+
+    fn slot(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>) -> bool {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        let result = prefab.slot();
+        Box::into_raw(prefab);
+        result
+    }
+
+    fn instantiate(&mut self, prefab: Resource<toxoid_component::component::ecs::Prefab>, count: u32) -> Vec<toxoid_component::component::ecs::EcsEntityT> {
+        let prefab_proxy = self.table.get(&prefab).unwrap() as &PrefabProxy;
+        let prefab = unsafe { Box::from_raw(prefab_proxy.ptr) };
+        let result = prefab.instantiate(count);
+        Box::into_raw(prefab);
+        result
+    }
+
+    fn drop(&mut self, _prefab: Resource<toxoid_component::component::ecs::Prefab>) -> Result<(), wasmtime::Error> {
+        Ok(())
+    }
+}
+
+impl toxoid_component::component::ecs::HostTimer for StoreState {
+    fn new(&mut self) -> Resource<TimerProxy> {
+        let timer = <toxoid_host::Timer as toxoid_host::bindings::exports::toxoid::engine::ecs::GuestTimer>::new();
+        let ptr = Box::into_raw(Box::new(timer));
+        self.table.push::<TimerProxy>(TimerProxy { ptr }).unwrap()
+    }
+
+    fn get_id(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> u64 {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        let result = timer.get_id();
+        Box::into_raw(timer);
+        result
+    }
+
+    fn set_timeout(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>, seconds: f32) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.set_timeout(seconds);
+        Box::into_raw(timer);
+    }
+
+    fn get_timeout(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> f32 {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        let result = timer.get_timeout();
+        Box::into_raw(timer);
+        result
+    }
+
+    fn set_interval(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>, seconds: f32) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.set_interval(seconds);
+        Box::into_raw(timer);
+    }
+
+    fn get_interval(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> f32 {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        let result = timer.get_interval();
+        Box::into_raw(timer);
+        result
+    }
+
+    fn set_rate(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>, rate: i32, source: Option<EcsEntityT>) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.set_rate(rate, source);
+        Box::into_raw(timer);
+    }
+
+    fn start(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.start();
+        Box::into_raw(timer);
+    }
+
+    fn stop(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.stop();
+        Box::into_raw(timer);
+    }
+
+    fn reset(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.reset();
+        Box::into_raw(timer);
+    }
+
+    fn fire(&mut self, timer: Resource<toxoid_component::component::ecs::Timer>, event: EcsEntityT, target: EcsEntityT) -> () {
+        let timer_proxy = self.table.get(&timer).unwrap() as &TimerProxy;
+        let timer = unsafe { Box::from_raw(timer_proxy.ptr) };
+        timer.fire(event, target);
+        Box::into_raw(timer);
+    }
+
+    fn drop(&mut self, _timer: Resource<toxoid_component::component::ecs::Timer>) -> Result<(), wasmtime::Error> {
+        Ok(())
+    }
+}
+
 impl toxoid_component::component::ecs::HostPipeline for StoreState {
     fn new(&mut self, desc: toxoid_component::component::ecs::PipelineDesc) -> Resource<PipelineProxy> {
         let query_desc = toxoid_host::bindings::exports::toxoid::engine::ecs::QueryDesc { expr: desc.query_desc.expr };
@@ -1161,6 +1571,7 @@ fn new_store() -> Store<StoreState> {
                     // .inherit_network()
                     .build(),
             table: ResourceTable::new(),
+            singletons: HashMap::new(),
         }
     )
 }
@@ -1183,8 +1594,6 @@ pub fn load_wasm_component(filename: &str) -> Result<()> {
         if let Some(world) = TOXOID_COMPONENT_WORLD.take() {
             drop(world);
         }
-        
-        SINGLETON_MAP.clear();
     }
 
     // Load the component from disk
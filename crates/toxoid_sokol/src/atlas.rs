@@ -0,0 +1,223 @@
+// Packs small sprites into shared atlas pages, so they don't each need their own image
+// and draws of different sprites can be batched together.
+// Pages are render targets, sprites are drawn into them as they load.
+#[cfg(not(target_os = "emscripten"))]
+use crate::bindings::*;
+#[cfg(target_os = "emscripten")]
+use crate::bindings_x86::*;
+use crate::{batch, SokolRenderTarget, SokolSprite};
+use sokol::gfx as sg;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub const PAGE_SIZE: u32 = 1024;
+// Sprites larger than this in either dimension get their own image
+pub const MAX_PACKED_SIZE: u32 = 256;
+// Transparent gap between sprites, keeps linear filtering from bleeding neighbours in
+const PADDING: u32 = 2;
+
+struct Shelf {
+    y: u32,
+    height: u32,
+    next_x: u32,
+}
+
+struct AtlasPage {
+    render_target: SokolRenderTarget,
+    image: sg::Image,
+    shelves: Vec<Shelf>,
+    next_y: u32,
+    // Sprites packed into the page that haven't been released
+    sprites: u32,
+}
+
+// A sub-rect of an image, registered from an atlas manifest
+struct AtlasRegion {
+    image: sg::Image,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+thread_local! {
+    static PAGES: RefCell<Vec<AtlasPage>> = RefCell::new(Vec::new());
+    static REGIONS: RefCell<HashMap<String, AtlasRegion>> = RefCell::new(HashMap::new());
+}
+
+impl AtlasPage {
+    fn new() -> Self {
+        let mut render_target = crate::render_2d::new_render_target(PAGE_SIZE, PAGE_SIZE);
+        let image = sg::Image { id: render_target.sprite.as_any().downcast_ref::<SokolSprite>().unwrap().image.id };
+        // The first pass clears the page, later passes keep what's already packed
+        render_target.pass.action.colors[0].load_action = sg::LoadAction::Clear;
+        AtlasPage { render_target, image, shelves: Vec::new(), next_y: 0, sprites: 0 }
+    }
+
+    // Shelf packing, sprites go on the lowest shelf they fit on or start a new one
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        let (width, height) = (width + PADDING, height + PADDING);
+        if let Some(shelf) = self
+            .shelves
+            .iter_mut()
+            .filter(|shelf| shelf.height >= height && shelf.next_x + width <= PAGE_SIZE)
+            .min_by_key(|shelf| shelf.height)
+        {
+            let x = shelf.next_x;
+            shelf.next_x += width;
+            return Some((x, shelf.y));
+        }
+        if self.next_y + height > PAGE_SIZE {
+            return None;
+        }
+        let y = self.next_y;
+        self.next_y += height;
+        self.shelves.push(Shelf { y, height, next_x: width });
+        Some((0, y))
+    }
+
+    // Copy RGBA8 pixels into the page
+    fn draw(&mut self, pixels: *const u8, width: u32, height: u32, x: u32, y: u32) {
+        let image_desc = sg::ImageDesc {
+            width: width as i32,
+            height: height as i32,
+            pixel_format: sg::PixelFormat::Rgba8,
+            data: sg::ImageData {
+                subimage: [[sg::Range { ptr: pixels as *const core::ffi::c_void, size: (width * height * 4) as usize }; 16]; 6],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let image = sg::make_image(&image_desc);
+        // Draws queued for the current pass have to be issued before switching passes
+        batch::flush();
+        sg::begin_pass(&self.render_target.pass);
+        unsafe {
+            sgp_begin(PAGE_SIZE as i32, PAGE_SIZE as i32);
+            sgp_viewport(0, 0, PAGE_SIZE as i32, PAGE_SIZE as i32);
+            // Flip the y-axis like begin_rt
+            #[cfg(all(target_arch="wasm32", target_os="emscripten"))]
+            sgp_project(0.0, PAGE_SIZE as f32, PAGE_SIZE as f32, 0.0);
+        }
+        let rect = sgp_rect { x: 0., y: 0., w: width as f32, h: height as f32 };
+        let dst = sgp_rect { x: x as f32, y: y as f32, w: width as f32, h: height as f32 };
+        // Copy the pixels as they are instead of blending them with the page
+        batch::draw(image.id, 0, sgp_blend_mode_SGP_BLENDMODE_NONE, dst, rect);
+        batch::flush();
+        unsafe {
+            sgp_flush();
+            sgp_end();
+        }
+        sg::end_pass();
+        sg::destroy_image(image);
+        self.render_target.pass.action.colors[0].load_action = sg::LoadAction::Load;
+    }
+}
+
+// Pack RGBA8 pixels into an atlas page, None when the sprite is too large to share a page
+pub fn pack(pixels: *const u8, width: u32, height: u32) -> Option<SokolSprite> {
+    if width == 0 || height == 0 || width > MAX_PACKED_SIZE || height > MAX_PACKED_SIZE {
+        return None;
+    }
+    PAGES.with(|pages| {
+        let mut pages = pages.borrow_mut();
+        let allocation = pages
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, page)| page.allocate(width, height).map(|position| (index, position)));
+        let (index, (x, y)) = match allocation {
+            Some(allocation) => allocation,
+            None => {
+                let mut page = AtlasPage::new();
+                let position = page.allocate(width, height)?;
+                pages.push(page);
+                (pages.len() - 1, position)
+            }
+        };
+        let page = &mut pages[index];
+        page.draw(pixels, width, height, x, y);
+        page.sprites += 1;
+        Some(SokolSprite { width, height, image: sg::Image { id: page.image.id }, x, y })
+    })
+}
+
+// Register the frames of a build-time atlas manifest, in the TexturePacker JSON hash or array format,
+// as regions of an already loaded page. Frames are keyed by `directory/name`.
+pub fn add_manifest(manifest: &[u8], page: &SokolSprite, directory: &str) -> Result<usize, String> {
+    let manifest: serde_json::Value = serde_json::from_slice(manifest).map_err(|error| error.to_string())?;
+    let frames: Vec<(String, &serde_json::Value)> = match &manifest["frames"] {
+        serde_json::Value::Object(frames) => frames.iter().map(|(name, frame)| (name.clone(), frame)).collect(),
+        serde_json::Value::Array(frames) => frames
+            .iter()
+            .filter_map(|frame| frame["filename"].as_str().map(|name| (name.to_string(), frame)))
+            .collect(),
+        _ => return Err("manifest has no frames".to_string()),
+    };
+    REGIONS.with(|regions| {
+        let mut regions = regions.borrow_mut();
+        let mut added = 0;
+        for (name, frame) in frames {
+            if frame["rotated"].as_bool().unwrap_or(false) {
+                println!("Skipping rotated atlas frame {}", name);
+                continue;
+            }
+            let rect = &frame["frame"];
+            let value = |key: &str| rect[key].as_u64().map(|value| value as u32);
+            let (Some(x), Some(y), Some(width), Some(height)) = (value("x"), value("y"), value("w"), value("h")) else {
+                return Err(format!("frame {} has no rect", name));
+            };
+            let key = if directory.is_empty() { name } else { format!("{}/{}", directory, name) };
+            regions.insert(key, AtlasRegion { image: sg::Image { id: page.image.id }, x: page.x + x, y: page.y + y, width, height });
+            added += 1;
+        }
+        Ok(added)
+    })
+}
+
+// Path of the page image of an atlas manifest, relative to the manifest
+pub fn manifest_image(manifest: &[u8]) -> Option<String> {
+    let manifest: serde_json::Value = serde_json::from_slice(manifest).ok()?;
+    manifest["meta"]["image"].as_str().map(str::to_string)
+}
+
+// Release a sprite's image, true when it's an atlas page the sprite doesn't own.
+// Packed pages are destroyed once every sprite on them is released, their space isn't reused before that.
+pub fn release(image: sg::Image) -> bool {
+    let page = PAGES.with(|pages| {
+        let mut pages = pages.borrow_mut();
+        let index = pages.iter().position(|page| page.image.id == image.id)?;
+        pages[index].sprites = pages[index].sprites.saturating_sub(1);
+        if pages[index].sprites == 0 {
+            Some(Some(pages.remove(index)))
+        } else {
+            Some(None)
+        }
+    });
+    match page {
+        Some(Some(page)) => {
+            // Draws of the page's sprites queued for the current pass have to be issued first
+            batch::flush();
+            let render_target = page.render_target;
+            sg::destroy_attachments(render_target.pass.attachments);
+            sg::destroy_image(page.image);
+            sg::destroy_image(render_target.depth_image);
+            sg::destroy_sampler(render_target.sampler);
+            true
+        }
+        Some(None) => true,
+        None => REGIONS.with(|regions| regions.borrow().values().any(|region| region.image.id == image.id)),
+    }
+}
+
+// Sprite for a path registered by an atlas manifest
+pub fn sprite(path: &str) -> Option<SokolSprite> {
+    REGIONS.with(|regions| {
+        regions.borrow().get(path).map(|region| SokolSprite {
+            width: region.width,
+            height: region.height,
+            image: sg::Image { id: region.image.id },
+            x: region.x,
+            y: region.y,
+        })
+    })
+}
@@ -0,0 +1,79 @@
+// Batches textured draws that share an image, sampler and blend mode into one sokol_gp draw call.
+// sokol_gp applies its state (transform, viewport, color, pass) when a draw is issued,
+// so anything that changes that state has to flush the batch first.
+#[cfg(not(target_os = "emscripten"))]
+use crate::bindings::*;
+#[cfg(target_os = "emscripten")]
+use crate::bindings_x86::*;
+use std::sync::Mutex;
+
+#[derive(Clone, Copy, PartialEq)]
+struct BatchKey {
+    image: u32,
+    sampler: u32,
+    blend_mode: sgp_blend_mode,
+}
+
+struct SpriteBatch {
+    key: Option<BatchKey>,
+    rects: Vec<sgp_textured_rect>,
+    draw_calls: u32,
+    sprites: u32,
+}
+
+static BATCH: Mutex<SpriteBatch> = Mutex::new(SpriteBatch {
+    key: None,
+    rects: Vec::new(),
+    draw_calls: 0,
+    sprites: 0,
+});
+
+impl SpriteBatch {
+    fn flush(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        if self.rects.is_empty() {
+            return;
+        }
+        unsafe {
+            // Batched draws are never tinted
+            sgp_reset_color();
+            sgp_set_blend_mode(key.blend_mode);
+            sgp_set_image(0, sg_image { id: key.image });
+            if key.sampler == 0 {
+                sgp_reset_sampler(0);
+            } else {
+                sgp_set_sampler(0, sg_sampler { id: key.sampler });
+            }
+            sgp_draw_textured_rects(0, self.rects.as_ptr(), self.rects.len() as u32);
+        }
+        self.rects.clear();
+        self.draw_calls += 1;
+    }
+}
+
+// Queue a textured rect, flushing the previous draws when the image, sampler or blend mode changes
+pub fn draw(image: u32, sampler: u32, blend_mode: sgp_blend_mode, dst: sgp_rect, src: sgp_rect) {
+    let mut batch = BATCH.lock().unwrap();
+    let key = BatchKey { image, sampler, blend_mode };
+    if batch.key != Some(key) {
+        batch.flush();
+        batch.key = Some(key);
+    }
+    batch.rects.push(sgp_textured_rect { dst, src });
+    batch.sprites += 1;
+}
+
+pub fn flush() {
+    BATCH.lock().unwrap().flush();
+}
+
+// Draw calls issued and sprites drawn since the last call
+pub fn take_stats() -> (u32, u32) {
+    let mut batch = BATCH.lock().unwrap();
+    let stats = (batch.draw_calls, batch.sprites);
+    batch.draw_calls = 0;
+    batch.sprites = 0;
+    stats
+}
@@ -17,6 +17,8 @@ pub use bindings_x86 as bindings;
 #[cfg(target_os = "emscripten")]
 pub use bindings::*;
 pub mod render_2d;
+mod batch;
+pub mod atlas;
 // include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 pub use render_2d::*;
 pub use sokol;
@@ -6,6 +6,7 @@ use sokol::{app as sapp, gfx as sg, glue as sglue};
 use toxoid_render::{Renderer2D, RenderTarget, Sprite};
 use std::any::Any;
 use toxoid_api::*;
+use crate::{atlas, batch};
 
 pub struct SokolRenderer2D {
     pass_action: sg::PassAction,
@@ -20,7 +21,10 @@ pub struct SokolImage {
 pub struct SokolSprite {
     pub width: u32,
     pub height: u32,
-    pub image: sg::Image
+    pub image: sg::Image,
+    // Offset of the sprite in its image, non zero for sprites packed into an atlas page
+    pub x: u32,
+    pub y: u32
 }
 
 pub struct SokolRenderTarget {
@@ -151,6 +155,95 @@ impl SokolRenderer2D {
     }
 }
 
+// Game area of the window in pixels, centered with letterboxing
+fn game_viewport() -> (i32, i32, i32, i32) {
+    let game_config = World::get_singleton::<GameConfig>();
+    let window_width = game_config.get_window_width() as i32;
+    let window_height = game_config.get_window_height() as i32;
+    let viewport_x = ((sapp::width() - window_width) / 2).max(0);
+    let viewport_y = ((sapp::height() - window_height) / 2).max(0);
+    (viewport_x, viewport_y, window_width, window_height)
+}
+
+// Render target that clears to transparent at the start of every pass
+pub(crate) fn new_render_target(width: u32, height: u32) -> SokolRenderTarget {
+    // Get swapchain info to match formats
+    let swapchain = sglue::swapchain();
+
+    // Create framebuffer image
+    let image_desc = sg::ImageDesc {
+        render_target: true,
+        width: width as i32,
+        height: height as i32,
+        pixel_format: swapchain.color_format, // Match swapchain format
+        sample_count: swapchain.sample_count, // Match swapchain sample count
+        ..Default::default()
+    };
+    let image = sg::make_image(&image_desc);
+
+    // Create framebuffer depth stencil
+    let depth_image_desc = sg::ImageDesc {
+        render_target: true,
+        width: width as i32,
+        height: height as i32,
+        pixel_format: swapchain.depth_format, // Match swapchain depth format
+        sample_count: swapchain.sample_count, // Match swapchain sample count
+        ..Default::default()
+    };
+    let depth_image = sg::make_image(&depth_image_desc);
+
+    // Create linear sampler
+    let sampler_desc = sg::SamplerDesc {
+        min_filter: sg::Filter::Linear,
+        mag_filter: sg::Filter::Linear,
+        wrap_u: sg::Wrap::ClampToEdge,
+        wrap_v: sg::Wrap::ClampToEdge,
+        ..Default::default()
+    };
+    let sampler = sg::make_sampler(&sampler_desc);
+    
+
+    // Create framebuffer pass
+    let mut attachments_desc = sg::AttachmentsDesc::default();
+    attachments_desc.colors[0].image = image;
+    attachments_desc.depth_stencil.image = depth_image;
+    let attachments = sg::make_attachments(&attachments_desc);
+    let mut pass_action = sg::PassAction::default();
+    pass_action.colors[0] = sg::ColorAttachmentAction {
+        load_action: sg::LoadAction::Clear,
+        store_action: sg::StoreAction::Store,
+        clear_value: sg::Color { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
+        ..Default::default()
+    };
+    let fb_pass = sg::Pass {
+        attachments,
+        action: pass_action,
+        ..Default::default()
+    };
+
+    // TODO: Error handling
+    // let state_1 = sg::query_image_state(image);
+    // let state_2 = sg::query_image_state(depth_image);
+    // let state_3 = sg::query_sampler_state(sampler);
+
+    // println!("Image state: {:?}", state_1);
+    // println!("Depth image state: {:?}", state_2);
+    // println!("Sampler state: {:?}", state_3);
+
+    SokolRenderTarget {
+        sprite: Box::new(SokolSprite {
+            width,
+            height,
+            image: sg::Image { id: image.id },
+            x: 0,
+            y: 0
+        }),
+        depth_image: sg::Image { id: depth_image.id },
+        sampler: sg::Sampler { id: sampler.id },
+        pass: fb_pass
+    }
+}
+
 impl Renderer2D for SokolRenderer2D {
     fn new() -> Self {
         Self {
@@ -165,17 +258,10 @@ impl Renderer2D for SokolRenderer2D {
 
     fn begin() {
         let game_config = World::get_singleton::<GameConfig>();
-        let window_width = game_config.get_window_width() as i32;
-        let window_height = game_config.get_window_height() as i32;
+        let (viewport_x, viewport_y, window_width, window_height) = game_viewport();
         let game_width = game_config.get_game_width() as f32;
         let game_height = game_config.get_game_height() as f32;
 
-        // Get camera zoom
-        let main_camera = World::get_singleton::<MainCamera>();
-        let mut camera_entity = Entity::from_id(main_camera.get_entity());
-        let camera = camera_entity.get::<Camera>();
-        let zoom = camera.get_zoom();
-
         unsafe {
             // Clear entire window to pure black (for letterboxing)
             sgp_begin(sapp::width(), sapp::height());
@@ -185,13 +271,9 @@ impl Renderer2D for SokolRenderer2D {
             sgp_set_color(0.0, 0.0, 0.0, 1.0);
             sgp_clear();
             
-            // Calculate viewport size and position accounting for zoom
-            let viewport_x = ((sapp::width() - window_width) / 2).max(0);
-            let viewport_y = ((sapp::height() - window_height) / 2).max(0);
-            
-            // Set up game viewport with zoomed projection
+            // Set up game viewport, camera zoom is applied as a transform when drawing
             sgp_viewport(viewport_x, viewport_y, window_width, window_height);
-            sgp_project(0.0, game_width / zoom, 0.0, game_height / zoom);
+            sgp_project(0.0, game_width, 0.0, game_height);
             sgp_reset_color();
             sgp_set_color(0.1, 0.1, 0.1, 1.0);
             sgp_clear();
@@ -211,6 +293,12 @@ impl Renderer2D for SokolRenderer2D {
 
     fn end() {
         let (window_width, window_height) = (sapp::width(), sapp::height());
+        batch::flush();
+        // Batching stats of the whole frame, including render target passes
+        let (draw_calls, sprites) = batch::take_stats();
+        let render_stats = World::get_singleton::<RenderStats>();
+        render_stats.set_draw_calls(draw_calls);
+        render_stats.set_sprites(sprites);
             
         // Begin render pass
         sg::begin_pass(&sg::Pass {
@@ -250,79 +338,16 @@ impl Renderer2D for SokolRenderer2D {
     }
 
     fn create_render_target(width: u32, height: u32) -> Box<dyn RenderTarget> {
-        // Get swapchain info to match formats
-        let swapchain = sglue::swapchain();
-
-        // Create framebuffer image
-        let image_desc = sg::ImageDesc {
-            render_target: true,
-            width: width as i32,
-            height: height as i32,
-            pixel_format: swapchain.color_format, // Match swapchain format
-            sample_count: swapchain.sample_count, // Match swapchain sample count
-            ..Default::default()
-        };
-        let image = sg::make_image(&image_desc);
-
-        // Create framebuffer depth stencil
-        let depth_image_desc = sg::ImageDesc {
-            render_target: true,
-            width: width as i32,
-            height: height as i32,
-            pixel_format: swapchain.depth_format, // Match swapchain depth format
-            sample_count: swapchain.sample_count, // Match swapchain sample count
-            ..Default::default()
-        };
-        let depth_image = sg::make_image(&depth_image_desc);
-
-        // Create linear sampler
-        let sampler_desc = sg::SamplerDesc {
-            min_filter: sg::Filter::Linear,
-            mag_filter: sg::Filter::Linear,
-            wrap_u: sg::Wrap::ClampToEdge,
-            wrap_v: sg::Wrap::ClampToEdge,
-            ..Default::default()
-        };
-        let sampler = sg::make_sampler(&sampler_desc);
-        
-
-        // Create framebuffer pass
-        let mut attachments_desc = sg::AttachmentsDesc::default();
-        attachments_desc.colors[0].image = image;
-        attachments_desc.depth_stencil.image = depth_image;
-        let attachments = sg::make_attachments(&attachments_desc);
-        let mut pass_action = sg::PassAction::default();
-        pass_action.colors[0] = sg::ColorAttachmentAction {
-            load_action: sg::LoadAction::Clear,
-            store_action: sg::StoreAction::Store,
-            clear_value: sg::Color { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
-            ..Default::default()
-        };
-        let fb_pass = sg::Pass {
-            attachments,
-            action: pass_action,
-            ..Default::default()
-        };
-
-        // TODO: Error handling
-        // let state_1 = sg::query_image_state(image);
-        // let state_2 = sg::query_image_state(depth_image);
-        // let state_3 = sg::query_sampler_state(sampler);
-
-        // println!("Image state: {:?}", state_1);
-        // println!("Depth image state: {:?}", state_2);
-        // println!("Sampler state: {:?}", state_3);
+        Box::new(new_render_target(width, height))
+    }
 
-        Box::new(SokolRenderTarget {
-            sprite: Box::new(SokolSprite {
-                width,
-                height,
-                image: sg::Image { id: image.id },
-            }),
-            depth_image: sg::Image { id: depth_image.id },
-            sampler: sg::Sampler { id: sampler.id },
-            pass: fb_pass
-        })
+    fn destroy_render_target(render_target: Box<dyn RenderTarget>) {
+        let sokol_render_target = render_target.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
+        let sokol_sprite = sokol_render_target.sprite.as_any().downcast_ref::<SokolSprite>().unwrap();
+        sg::destroy_attachments(sokol_render_target.pass.attachments);
+        sg::destroy_image(sokol_sprite.image);
+        sg::destroy_image(sokol_render_target.depth_image);
+        sg::destroy_sampler(sokol_render_target.sampler);
     }
 
     fn create_image(data: *const u8, size: usize) -> Box<dyn toxoid_render::Image> {
@@ -357,33 +382,40 @@ impl Renderer2D for SokolRenderer2D {
         let mut channels: i32 = 0;
         let image_data = unsafe {
             // Converts from PNG format to RGBA8 format
-            stbi_load_from_memory(data as *const u8, size as core::ffi::c_int, &mut width, &mut height, &mut channels, 0)
+            stbi_load_from_memory(data as *const u8, size as core::ffi::c_int, &mut width, &mut height, &mut channels, 4)
         };
-        // Slice from image data from raw parts
-        // let image_data_slice = unsafe { std::slice::from_raw_parts(image_data, (width * height * 4) as usize) };
-        // println!("Image data: {:?}", image_data_slice);
-        let image_desc = sg::ImageDesc {
-            width,
-            height,
-            pixel_format: sg::PixelFormat::Rgba8,
-            data: sg::ImageData {
-                subimage: [[sg::Range { ptr: image_data as *const core::ffi::c_void, size: (width * height * 4) as usize }; 16]; 6],
+        if image_data.is_null() {
+            println!("Failed to decode sprite");
+            return Box::new(SokolSprite { width: 0, height: 0, image: sg::Image::default(), x: 0, y: 0 });
+        }
+        // Small sprites share atlas pages, large ones get their own image
+        let sprite = atlas::pack(image_data as *const u8, width as u32, height as u32).unwrap_or_else(|| {
+            let image_desc = sg::ImageDesc {
+                width,
+                height,
+                pixel_format: sg::PixelFormat::Rgba8,
+                data: sg::ImageData {
+                    subimage: [[sg::Range { ptr: image_data as *const core::ffi::c_void, size: (width * height * 4) as usize }; 16]; 6],
+                    ..Default::default()
+                },
                 ..Default::default()
-            },
-            
-            ..Default::default()
-        };
-        let image = sg::make_image(&image_desc);
-
-        let sprite_boxed = Box::new(SokolSprite {
+            };
+            SokolSprite {
                 width: width as u32,
                 height: height as u32,
-                image
+                image: sg::make_image(&image_desc),
+                x: 0,
+                y: 0
+            }
         });
-        sprite_boxed
+        // The pixels have been copied to the GPU
+        unsafe { stbi_image_free(image_data as *mut core::ffi::c_void) };
+        Box::new(sprite)
     }
     
     fn begin_rt(destination: &Box<dyn RenderTarget>, dw: f32, dh: f32) {
+        // Draws queued for the current pass have to be issued before switching passes
+        batch::flush();
         unsafe {
             // Set the framebuffer as the current render target
             let sokol_destination = destination.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
@@ -405,6 +437,7 @@ impl Renderer2D for SokolRenderer2D {
     }
 
     fn end_rt() {
+        batch::flush();
         unsafe {
             // Flush the sokol-gp commands to the current render target
             sgp_flush();
@@ -416,23 +449,38 @@ impl Renderer2D for SokolRenderer2D {
     }
 
     fn blit_sprite(source: &Box<dyn Sprite>, sx: f32, sy: f32, sw: f32, sh: f32, destination: &Box<dyn RenderTarget>, dx: f32, dy: f32) {
-        unsafe {      
-            let sokol_source = source.as_any().downcast_ref::<SokolSprite>().unwrap();
-        
-            // Set the source image
-            sgp_set_image(0, sg_image { id: sokol_source.image.id });
-        
-            // Add sampler configuration to prevent wrapping
-            sgp_set_sampler(0, sg_sampler { 
-                id: destination.as_any()
-                    .downcast_ref::<SokolRenderTarget>()
-                    .unwrap().sampler.id 
-            });
-            
-            // Draw the source sprite onto the destination sprite
-            let src_rect = sgp_rect { x: sx, y: sy, w: sw, h: sh };
-            let dest_rect = sgp_rect { x: dx, y: dy, w: sw, h: sh };
-            sgp_draw_textured_rect(0, dest_rect, src_rect);
+        let sokol_source = source.as_any().downcast_ref::<SokolSprite>().unwrap();
+        // Use the sampler of the destination to prevent wrapping
+        let sampler = destination.as_any().downcast_ref::<SokolRenderTarget>().unwrap().sampler.id;
+
+        // Draw the source sprite onto the destination sprite
+        let src_rect = sgp_rect { x: sokol_source.x as f32 + sx, y: sokol_source.y as f32 + sy, w: sw, h: sh };
+        let dest_rect = sgp_rect { x: dx, y: dy, w: sw, h: sh };
+        batch::draw(sokol_source.image.id, sampler, sgp_blend_mode_SGP_BLENDMODE_BLEND, dest_rect, src_rect);
+    }
+
+    fn blit_sprite_flipped(source: &Box<dyn Sprite>, sx: f32, sy: f32, sw: f32, sh: f32, destination: &Box<dyn RenderTarget>, dx: f32, dy: f32, flip_x: bool, flip_y: bool, flip_diagonal: bool) {
+        if !flip_x && !flip_y && !flip_diagonal {
+            return Self::blit_sprite(source, sx, sy, sw, sh, destination, dx, dy);
+        }
+        // Transformed draws can't share a batch with untransformed ones
+        batch::flush();
+        unsafe {
+            // Transforms apply to the vertices in reverse order, so the diagonal flip happens first
+            let cx = dx + sw / 2.;
+            let cy = dy + sh / 2.;
+            sgp_push_transform();
+            sgp_translate(cx, cy);
+            sgp_scale(if flip_x { -1. } else { 1. }, if flip_y { -1. } else { 1. });
+            if flip_diagonal {
+                // Swapping x and y is a quarter turn followed by a horizontal flip
+                sgp_scale(-1., 1.);
+                sgp_rotate(std::f32::consts::FRAC_PI_2);
+            }
+            sgp_translate(-cx, -cy);
+            Self::blit_sprite(source, sx, sy, sw, sh, destination, dx, dy);
+            batch::flush();
+            sgp_pop_transform();
         }
     }
 
@@ -469,45 +517,113 @@ impl Renderer2D for SokolRenderer2D {
                 w: sprite.width() as f32, 
                 h: sprite.height() as f32
             };
+            let sokol_sprite = sprite.as_any().downcast_ref::<SokolSprite>().unwrap();
             let src_rect = sgp_rect { 
-                x: 0., 
-                y: 0., 
+                x: sokol_sprite.x as f32, 
+                y: sokol_sprite.y as f32, 
                 w: sprite.width() as f32, 
                 h: sprite.height() as f32 
             };
-            let sokol_sprite = sprite.as_any().downcast_ref::<SokolSprite>().unwrap();
-            sgp_set_image(0, sg_image { id: sokol_sprite.image.id });
-            sgp_draw_textured_rect(0, dest_rect, src_rect);
+            batch::draw(sokol_sprite.image.id, 0, sgp_blend_mode_SGP_BLENDMODE_BLEND, dest_rect, src_rect);
         }
     }
 
+    fn begin_camera(destination: Option<&Box<dyn RenderTarget>>, x: f32, y: f32, width: f32, height: f32, view_width: f32, view_height: f32, clear_color: u32) {
+        batch::flush();
+        if let Some(destination) = destination {
+            let sokol_destination = destination.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
+            let (rt_width, rt_height) = (sokol_destination.sprite.width() as f32, sokol_destination.sprite.height() as f32);
+            Self::begin_rt(destination, rt_width, rt_height);
+            unsafe {
+                sgp_viewport(x as i32, y as i32, width as i32, height as i32);
+                // Flip the y-axis like begin_rt
+                #[cfg(all(target_arch="wasm32", target_os="emscripten"))]
+                sgp_project(0.0, view_width, view_height, 0.0);
+                #[cfg(not(all(target_arch="wasm32", target_os="emscripten")))]
+                sgp_project(0.0, view_width, 0.0, view_height);
+            }
+        } else {
+            // Map the rect from game coordinates to window pixels
+            let game_config = World::get_singleton::<GameConfig>();
+            let (viewport_x, viewport_y, window_width, window_height) = game_viewport();
+            let scale_x = window_width as f32 / game_config.get_game_width().max(1) as f32;
+            let scale_y = window_height as f32 / game_config.get_game_height().max(1) as f32;
+            unsafe {
+                sgp_viewport(
+                    viewport_x + (x * scale_x) as i32,
+                    viewport_y + (y * scale_y) as i32,
+                    (width * scale_x) as i32,
+                    (height * scale_y) as i32
+                );
+                sgp_project(0.0, view_width, 0.0, view_height);
+            }
+        }
+        if clear_color & 0xff != 0 {
+            let channel = |shift: u32| ((clear_color >> shift) & 0xff) as f32 / 255.;
+            unsafe {
+                sgp_set_color(channel(24), channel(16), channel(8), channel(0));
+                sgp_clear();
+                sgp_reset_color();
+            }
+        }
+    }
+
+    fn end_camera(destination: Option<&Box<dyn RenderTarget>>) {
+        batch::flush();
+        if destination.is_some() {
+            Self::end_rt();
+            return;
+        }
+        // Restore the game viewport for whatever is drawn after the cameras
+        let game_config = World::get_singleton::<GameConfig>();
+        let (viewport_x, viewport_y, window_width, window_height) = game_viewport();
+        unsafe {
+            sgp_viewport(viewport_x, viewport_y, window_width, window_height);
+            sgp_project(0.0, game_config.get_game_width() as f32, 0.0, game_config.get_game_height() as f32);
+        }
+    }
+
+    fn push_camera_transform(view_width: f32, view_height: f32, zoom: f32, offset_x: f32, offset_y: f32) {
+        let center_x = view_width / 2.;
+        let center_y = view_height / 2.;
+        batch::flush();
+        unsafe {
+            sgp_push_transform();
+            // Zoom around the center of the view
+            sgp_translate(center_x, center_y);
+            sgp_scale(zoom, zoom);
+            sgp_translate(-center_x + offset_x, -center_y + offset_y);
+        }
+    }
+
+    fn pop_camera_transform() {
+        batch::flush();
+        unsafe { sgp_pop_transform(); }
+    }
+
     fn draw_render_target(
         rt_trait_object: &Box<dyn RenderTarget>,
         sx: f32, sy: f32, sw: f32, sh: f32,
         dx: f32, dy: f32, dw: f32, dh: f32,
         blend_mode: u8
     ) {
-        unsafe {
-            sgp_reset_color();
-            if blend_mode == 0 {
-                sgp_set_blend_mode(sgp_blend_mode_SGP_BLENDMODE_BLEND);
-            } else {
-                sgp_set_blend_mode((blend_mode as u32).try_into().unwrap());
-            }
+        let blend_mode = if blend_mode == 0 {
+            sgp_blend_mode_SGP_BLENDMODE_BLEND
+        } else {
+            (blend_mode as u32).try_into().unwrap()
+        };
 
-            let sokol_source = rt_trait_object.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
-            let sprite = sokol_source.sprite.as_any().downcast_ref::<SokolSprite>().unwrap();
-    
-            // Draw using game coordinates directly
-            let src_rect = sgp_rect { x: sx, y: sy, w: sw, h: sh };
-            let dest_rect = sgp_rect { x: dx, y: dy, w: dw, h: dh };
+        let sokol_source = rt_trait_object.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
+        let sprite = sokol_source.sprite.as_any().downcast_ref::<SokolSprite>().unwrap();
 
-            sgp_set_image(0, sg_image { id: sprite.image.id });
-            sgp_draw_textured_rect(0, dest_rect, src_rect);
-        }
+        // Draw using game coordinates directly
+        let src_rect = sgp_rect { x: sx, y: sy, w: sw, h: sh };
+        let dest_rect = sgp_rect { x: dx, y: dy, w: dw, h: dh };
+        batch::draw(sprite.image.id, sokol_source.sampler.id, blend_mode, dest_rect, src_rect);
     }
 
     fn draw_filled_rect(pos: &Position, size: &Size, color: &Color) {
+        batch::flush();
         unsafe {
             let game_config = World::get_singleton::<GameConfig>();
             let window_width = game_config.get_window_width() as f32;
@@ -528,6 +644,7 @@ impl Renderer2D for SokolRenderer2D {
     }
 
     fn draw_line(ax: f32, ay: f32, bx: f32, by: f32) {
+        batch::flush();
         unsafe {
             let game_config = World::get_singleton::<GameConfig>();
             let window_width = game_config.get_window_width() as f32;
@@ -547,6 +664,7 @@ impl Renderer2D for SokolRenderer2D {
 
     fn clear_sprite(sprite: &Box<dyn RenderTarget>, x: i32, y: i32, width: i32, height: i32) {
         let sokol_render_target = sprite.as_any().downcast_ref::<SokolRenderTarget>().unwrap();
+        batch::flush();
     
         unsafe {
             // The sgp_scissor function sets a scissor rectangle in the viewport. The scissor test is a per-sample operation performed after the fragment shader. It discards the fragment if the fragment's position lies outside the scissor rectangle. In other words, it restricts drawing to a certain rectangular area of the screen.
@@ -601,6 +719,7 @@ impl Renderer2D for SokolRenderer2D {
     }
 
     fn clear_canvas(x: i32, y: i32, width: i32, height: i32) {
+        batch::flush();
         unsafe {
             sgp_scissor(x, y, width, height);
             sgp_clear();
@@ -0,0 +1,63 @@
+use std::io::Read;
+use base64::Engine;
+use crate::Error;
+
+// Decode the tile gids of a layer or chunk, `encoding` is `csv` or `base64`
+// and base64 data may be compressed with `zlib`, `gzip` or `zstd` with the `zstd` feature.
+pub fn decode_tiles(data: &str, encoding: Option<&str>, compression: Option<&str>) -> Result<Vec<u32>, Error> {
+    match encoding.unwrap_or("csv") {
+        "csv" => data
+            .split(',')
+            .map(str::trim)
+            .filter(|gid| !gid.is_empty())
+            .map(|gid| gid.parse::<u32>().map_err(|_| Error::InvalidTileData(format!("`{}` is not a tile gid", gid))))
+            .collect(),
+        "base64" => {
+            let bytes = base64::engine::general_purpose::STANDARD.decode(data.trim())?;
+            let bytes = decompress(bytes, compression)?;
+            if bytes.len() % 4 != 0 {
+                return Err(Error::InvalidTileData(format!("{} bytes is not a whole number of gids", bytes.len())));
+            }
+            // Gids are stored as little endian u32s
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|gid| u32::from_le_bytes([gid[0], gid[1], gid[2], gid[3]]))
+                .collect())
+        },
+        encoding => Err(Error::UnsupportedEncoding(encoding.to_string())),
+    }
+}
+
+fn decompress(bytes: Vec<u8>, compression: Option<&str>) -> Result<Vec<u8>, Error> {
+    let mut decompressed = Vec::new();
+    match compression.unwrap_or("") {
+        "" => return Ok(bytes),
+        "zlib" => flate2::read::ZlibDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decompressed)
+            .map_err(Error::Decompress)?,
+        "gzip" => flate2::read::GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decompressed)
+            .map_err(Error::Decompress)?,
+        #[cfg(feature = "zstd")]
+        "zstd" => return zstd::stream::decode_all(bytes.as_slice()).map_err(Error::Decompress),
+        compression => return Err(Error::UnsupportedCompression(compression.to_string())),
+    };
+    Ok(decompressed)
+}
+
+// Resolve a path relative to the file that references it, e.g. a tileset image relative to its `.tsx`
+pub fn resolve_path(base: &str, relative: &str) -> String {
+    let mut parts: Vec<&str> = base.split('/').collect();
+    // Drop the file name of the base
+    parts.pop();
+    for part in relative.split('/') {
+        match part {
+            "." | "" => {},
+            ".." if parts.last().is_some_and(|last| *last != "..") => {
+                parts.pop();
+            },
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
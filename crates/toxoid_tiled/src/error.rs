@@ -0,0 +1,62 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Xml(roxmltree::Error),
+    Base64(base64::DecodeError),
+    Decompress(std::io::Error),
+    UnsupportedEncoding(String),
+    UnsupportedCompression(String),
+    MissingAttribute { element: String, attribute: String },
+    InvalidAttribute { element: String, attribute: String, value: String },
+    InvalidProperty { name: String, property_type: String, value: String },
+    InvalidTileData(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid Tiled JSON: {}", e),
+            Error::Xml(e) => write!(f, "invalid Tiled XML: {}", e),
+            Error::Base64(e) => write!(f, "invalid base64 tile data: {}", e),
+            Error::Decompress(e) => write!(f, "failed to decompress tile data: {}", e),
+            Error::UnsupportedEncoding(encoding) => write!(f, "unsupported tile data encoding `{}`", encoding),
+            Error::UnsupportedCompression(compression) => write!(f, "unsupported tile data compression `{}`", compression),
+            Error::MissingAttribute { element, attribute } => write!(f, "<{}> is missing the `{}` attribute", element, attribute),
+            Error::InvalidAttribute { element, attribute, value } => write!(f, "<{}> has an invalid `{}` attribute: `{}`", element, attribute, value),
+            Error::InvalidProperty { name, property_type, value } => write!(f, "property `{}` has an invalid {} value: `{}`", name, property_type, value),
+            Error::InvalidTileData(reason) => write!(f, "invalid tile data: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) => Some(e),
+            Error::Xml(e) => Some(e),
+            Error::Base64(e) => Some(e),
+            Error::Decompress(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<roxmltree::Error> for Error {
+    fn from(e: roxmltree::Error) -> Self {
+        Error::Xml(e)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Self {
+        Error::Base64(e)
+    }
+}
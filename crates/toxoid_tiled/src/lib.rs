@@ -1,49 +1,497 @@
 use serde::{Serialize, Deserialize};
 
+mod data;
+mod error;
+mod tmx;
+pub use data::{decode_tiles, resolve_path};
+pub use error::Error;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Object {
     pub id: u32,
     pub name: String,
-    #[serde(rename = "type")] 
+    // Tiled 1.9+ exports the type as `class`
+    #[serde(rename = "type", alias = "class", default)]
     pub object_type: String,
     pub x: f32,
     pub y: f32,
+    #[serde(default)]
     pub width: f32,
+    #[serde(default)]
     pub height: f32,
+    #[serde(default)]
     pub rotation: f32,
     pub gid: Option<u32>,
+    #[serde(default = "default_true")]
     pub visible: bool,
     pub properties: Option<Vec<Property>>,
+    // Shapes, a rectangle when none are set
+    #[serde(default)]
+    pub ellipse: bool,
+    #[serde(default)]
+    pub point: bool,
+    pub polygon: Option<Vec<Point>>,
+    pub polyline: Option<Vec<Point>>,
+}
+
+// Polygon and polyline points are relative to the object position
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Object {
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(&self.properties, name)
+    }
+}
+
+fn find_property<'a>(properties: &'a Option<Vec<Property>>, name: &str) -> Option<&'a PropertyValue> {
+    properties
+        .iter()
+        .flatten()
+        .find(|property| property.name == name)
+        .map(|property| &property.value)
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_opacity() -> f32 {
+    1.
+}
+
+// Tile gids as an array, or as a `base64` string until the layer is decoded
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum TileData {
+    Tiles(Vec<u32>),
+    Encoded(String),
+}
+
+impl TileData {
+    pub fn tiles(&self) -> Option<&[u32]> {
+        match self {
+            TileData::Tiles(tiles) => Some(tiles),
+            TileData::Encoded(_) => None,
+        }
+    }
+
+    fn decode(&mut self, encoding: Option<&str>, compression: Option<&str>) -> Result<(), Error> {
+        if let TileData::Encoded(data) = self {
+            *self = TileData::Tiles(decode_tiles(data, encoding, compression)?);
+        }
+        Ok(())
+    }
+}
+
+// A piece of an infinite map's tile layer, positioned in tiles
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Chunk {
+    pub data: TileData,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Chunk {
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && y >= self.y && x < self.x + self.width as i32 && y < self.y + self.height as i32
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Layer {
-    pub data: Option<Vec<u32>>,
+    pub data: Option<TileData>,
+    // Set instead of `data` for infinite maps
+    pub chunks: Option<Vec<Chunk>>,
+    pub encoding: Option<String>,
+    pub compression: Option<String>,
     pub height: Option<u32>,
     pub id: u32,
     pub name: String,
+    #[serde(default = "default_opacity")]
     pub opacity: f32,
     #[serde(rename = "type")]
     pub layer_type: String,
+    #[serde(default = "default_true")]
     pub visible: bool,
     pub width: Option<u32>,
     pub layers: Option<Vec<Layer>>,
-    pub x: u32,
-    pub y: u32,
+    #[serde(default)]
+    pub x: i32,
+    #[serde(default)]
+    pub y: i32,
+    #[serde(default)]
+    pub startx: i32,
+    #[serde(default)]
+    pub starty: i32,
+    #[serde(default)]
+    pub offsetx: f32,
+    #[serde(default)]
+    pub offsety: f32,
+    // Image layers
+    pub image: Option<String>,
+    pub imagewidth: Option<u32>,
+    pub imageheight: Option<u32>,
+    #[serde(default)]
+    pub repeatx: bool,
+    #[serde(default)]
+    pub repeaty: bool,
     pub properties: Option<Vec<Property>>,
     pub objects: Option<Vec<Object>>,
 }
 
+impl Layer {
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(&self.properties, name)
+    }
+
+    // Every tile of a layer named `collision`, or with a `collision` bool property, is solid
+    pub fn is_collision(&self) -> bool {
+        self.property("collision")
+            .and_then(PropertyValue::as_bool)
+            .unwrap_or_else(|| self.name.eq_ignore_ascii_case("collision"))
+    }
+
+    pub fn tiles(&self) -> Option<&[u32]> {
+        self.data.as_ref().and_then(TileData::tiles)
+    }
+
+    // Raw gid at a tile position, 0 when there is no tile. Positions can be negative in infinite maps.
+    pub fn tile_at(&self, x: i32, y: i32) -> u32 {
+        if let Some(chunks) = &self.chunks {
+            return chunks
+                .iter()
+                .find(|chunk| chunk.contains(x, y))
+                .and_then(|chunk| {
+                    let i = (x - chunk.x) as usize + (y - chunk.y) as usize * chunk.width as usize;
+                    chunk.data.tiles().and_then(|tiles| tiles.get(i).copied())
+                })
+                .unwrap_or(0);
+        }
+        let width = self.width.unwrap_or(0) as i32;
+        if x < 0 || y < 0 || x >= width {
+            return 0;
+        }
+        self.tiles()
+            .and_then(|tiles| tiles.get(x as usize + y as usize * width as usize).copied())
+            .unwrap_or(0)
+    }
+
+    // Tile extents of the layer as (x, y, width, height)
+    pub fn tile_bounds(&self) -> Option<(i32, i32, u32, u32)> {
+        match &self.chunks {
+            Some(chunks) if !chunks.is_empty() => {
+                let min_x = chunks.iter().map(|chunk| chunk.x).min().unwrap();
+                let min_y = chunks.iter().map(|chunk| chunk.y).min().unwrap();
+                let max_x = chunks.iter().map(|chunk| chunk.x + chunk.width as i32).max().unwrap();
+                let max_y = chunks.iter().map(|chunk| chunk.y + chunk.height as i32).max().unwrap();
+                Some((min_x, min_y, (max_x - min_x) as u32, (max_y - min_y) as u32))
+            },
+            Some(_) => None,
+            None => Some((self.startx, self.starty, self.width?, self.height?)),
+        }
+    }
+
+    fn decode(&mut self) -> Result<(), Error> {
+        let encoding = self.encoding.clone();
+        let compression = self.compression.clone();
+        if let Some(data) = &mut self.data {
+            data.decode(encoding.as_deref(), compression.as_deref())?;
+        }
+        for chunk in self.chunks.iter_mut().flatten() {
+            chunk.data.decode(encoding.as_deref(), compression.as_deref())?;
+        }
+        for layer in self.layers.iter_mut().flatten() {
+            layer.decode()?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    // Tiled writes colors as `#AARRGGBB` or `#RRGGBB`, an empty string is an unset color
+    pub fn parse(color: &str) -> Option<Self> {
+        let hex = color.trim_start_matches('#');
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        match hex.len() {
+            0 => Some(Color::default()),
+            6 => Some(Color { r: channel(0)?, g: channel(2)?, b: channel(4)?, a: 255 }),
+            8 => Some(Color { a: channel(0)?, r: channel(2)?, g: channel(4)?, b: channel(6)? }),
+            _ => None,
+        }
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.a, self.r, self.g, self.b)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Color(Color),
+    File(String),
+    // Object id, 0 when unset
+    Object(u32),
+    // Members of a custom class, nested classes are objects too
+    Class(serde_json::Map<String, serde_json::Value>),
+}
+
+impl PropertyValue {
+    pub fn from_json(name: &str, property_type: &str, value: serde_json::Value) -> Result<Self, Error> {
+        let invalid = |value: &serde_json::Value| Error::InvalidProperty {
+            name: name.to_string(),
+            property_type: property_type.to_string(),
+            value: value.to_string(),
+        };
+        Ok(match (property_type, value) {
+            ("int", serde_json::Value::Number(n)) => PropertyValue::Int(n.as_i64().or(n.as_f64().map(|f| f as i64)).ok_or_else(|| invalid(&serde_json::Value::Number(n.clone())))?),
+            ("float", serde_json::Value::Number(n)) => PropertyValue::Float(n.as_f64().ok_or_else(|| invalid(&serde_json::Value::Number(n.clone())))?),
+            ("bool", serde_json::Value::Bool(b)) => PropertyValue::Bool(b),
+            ("color", serde_json::Value::String(color)) => PropertyValue::Color(Color::parse(&color).ok_or_else(|| invalid(&serde_json::Value::String(color.clone())))?),
+            ("file", serde_json::Value::String(file)) => PropertyValue::File(file),
+            ("object", serde_json::Value::Number(n)) => PropertyValue::Object(n.as_u64().unwrap_or(0) as u32),
+            ("class", serde_json::Value::Object(members)) => PropertyValue::Class(members),
+            ("string", serde_json::Value::String(string)) => PropertyValue::String(string),
+            (_, value) => return Err(invalid(&value)),
+        })
+    }
+
+    // Parse the string form XML uses for property values
+    pub fn parse(name: &str, property_type: &str, value: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidProperty {
+            name: name.to_string(),
+            property_type: property_type.to_string(),
+            value: value.to_string(),
+        };
+        Ok(match property_type {
+            "int" => PropertyValue::Int(value.parse().map_err(|_| invalid())?),
+            "float" => PropertyValue::Float(value.parse().map_err(|_| invalid())?),
+            "bool" => PropertyValue::Bool(value.parse().map_err(|_| invalid())?),
+            "color" => PropertyValue::Color(Color::parse(value).ok_or_else(invalid)?),
+            "file" => PropertyValue::File(value.to_string()),
+            "object" => PropertyValue::Object(value.parse().map_err(|_| invalid())?),
+            "string" => PropertyValue::String(value.to_string()),
+            _ => return Err(invalid()),
+        })
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PropertyValue::String(_) => "string",
+            PropertyValue::Int(_) => "int",
+            PropertyValue::Float(_) => "float",
+            PropertyValue::Bool(_) => "bool",
+            PropertyValue::Color(_) => "color",
+            PropertyValue::File(_) => "file",
+            PropertyValue::Object(_) => "object",
+            PropertyValue::Class(_) => "class",
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            PropertyValue::String(value) | PropertyValue::File(value) => serde_json::Value::from(value.as_str()),
+            PropertyValue::Int(value) => serde_json::Value::from(*value),
+            PropertyValue::Float(value) => serde_json::Value::from(*value),
+            PropertyValue::Bool(value) => serde_json::Value::from(*value),
+            PropertyValue::Color(color) => serde_json::Value::from(color.to_hex()),
+            PropertyValue::Object(id) => serde_json::Value::from(*id),
+            PropertyValue::Class(members) => serde_json::Value::Object(members.clone()),
+        }
+    }
+
+    pub fn is_class(&self) -> bool {
+        matches!(self, PropertyValue::Class(_))
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(value) | PropertyValue::File(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            PropertyValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            PropertyValue::Float(value) => Some(*value),
+            PropertyValue::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "RawProperty", into = "RawProperty")]
 pub struct Property {
     pub name: String,
-    #[serde(rename = "type")]
     pub property_type: String,
-    pub value: String,
+    // Name of the custom type for class properties
+    pub custom_type: Option<String>,
+    pub value: PropertyValue,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Property {
+    // The value as a JSON string, class properties become objects
+    pub fn value_json(&self) -> String {
+        match self.value.as_str() {
+            Some(value) => value.to_string(),
+            None => self.value.to_json().to_string(),
+        }
+    }
+}
+
+// Property as it's written in Tiled JSON
+#[derive(Serialize, Deserialize)]
+struct RawProperty {
+    name: String,
+    #[serde(rename = "type", default = "default_property_type")]
+    property_type: String,
+    #[serde(rename = "propertytype", skip_serializing_if = "Option::is_none")]
+    custom_type: Option<String>,
+    value: serde_json::Value,
+}
+
+fn default_property_type() -> String {
+    "string".to_string()
+}
+
+impl TryFrom<RawProperty> for Property {
+    type Error = Error;
+
+    fn try_from(raw: RawProperty) -> Result<Self, Error> {
+        let value = PropertyValue::from_json(&raw.name, &raw.property_type, raw.value)?;
+        Ok(Property { name: raw.name, property_type: raw.property_type, custom_type: raw.custom_type, value })
+    }
+}
+
+impl From<Property> for RawProperty {
+    fn from(property: Property) -> Self {
+        RawProperty {
+            value: property.value.to_json(),
+            name: property.name,
+            property_type: property.property_type,
+            custom_type: property.custom_type,
+        }
+    }
+}
+
+// A frame of a tile animation, `tileid` is local to the tileset and `duration` is in milliseconds
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct Frame {
+    pub tileid: u32,
+    pub duration: u32,
+}
+
+// Per-tile data of a tileset, only tiles with data are listed
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Tile {
+    pub id: u32,
+    #[serde(rename = "type", alias = "class")]
+    pub tile_type: String,
+    pub properties: Option<Vec<Property>>,
+    pub animation: Option<Vec<Frame>>,
+    // Collision shapes, positioned relative to the top left of the tile
+    pub objectgroup: Option<Layer>,
+}
+
+impl Tile {
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(&self.properties, name)
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.animation.as_ref().is_some_and(|frames| !frames.is_empty())
+    }
+
+    pub fn has_properties(&self) -> bool {
+        self.properties.as_ref().is_some_and(|properties| !properties.is_empty())
+    }
+
+    // Properties as a JSON object of their values by name
+    pub fn properties_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.properties
+                .iter()
+                .flatten()
+                .map(|property| (property.name.clone(), property.value.to_json()))
+                .collect()
+        )
+    }
+
+    // Length of one loop of the animation in milliseconds
+    pub fn animation_duration(&self) -> u32 {
+        self.animation.iter().flatten().map(|frame| frame.duration).sum()
+    }
+
+    // Local tile id of the frame shown after `elapsed` milliseconds, looping
+    pub fn frame_at(&self, elapsed: u32) -> Option<u32> {
+        let frames = self.animation.as_ref()?;
+        let duration = self.animation_duration();
+        if duration == 0 {
+            return frames.first().map(|frame| frame.tileid);
+        }
+        let mut time = elapsed % duration;
+        for frame in frames {
+            if time < frame.duration {
+                return Some(frame.tileid);
+            }
+            time -= frame.duration;
+        }
+        frames.last().map(|frame| frame.tileid)
+    }
+
+    // Tiles with a `solid` bool property, or else any collision shape, block movement
+    pub fn is_solid(&self) -> bool {
+        self.property("solid")
+            .and_then(PropertyValue::as_bool)
+            .unwrap_or_else(|| !self.collision_shapes().is_empty())
+    }
+
+    pub fn collision_shapes(&self) -> &[Object] {
+        self.objectgroup
+            .as_ref()
+            .and_then(|group| group.objects.as_deref())
+            .unwrap_or(&[])
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Tileset {
+    // External tilesets only have `firstgid` and `source` until they are resolved
+    pub source: Option<String>,
     pub columns: u32,
     pub firstgid: u32,
     pub image: String,
@@ -55,12 +503,106 @@ pub struct Tileset {
     pub tilecount: u32,
     pub tileheight: u32,
     pub tilewidth: u32,
+    pub tiles: Option<Vec<Tile>>,
+    pub properties: Option<Vec<Property>>,
     #[serde(skip)]
     pub sprite: Option<*mut std::ffi::c_void>
 }
 
+// The high bits of a gid store how the tile is flipped or rotated
+pub const FLIPPED_HORIZONTALLY_FLAG: u32 = 0x80000000;
+pub const FLIPPED_VERTICALLY_FLAG: u32 = 0x40000000;
+pub const FLIPPED_DIAGONALLY_FLAG: u32 = 0x20000000;
+pub const ROTATED_HEXAGONAL_120_FLAG: u32 = 0x10000000;
+const FLAGS_MASK: u32 = FLIPPED_HORIZONTALLY_FLAG | FLIPPED_VERTICALLY_FLAG | FLIPPED_DIAGONALLY_FLAG | ROTATED_HEXAGONAL_120_FLAG;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileGid {
+    pub gid: u32,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    // Swap x and y, applied before the horizontal and vertical flips
+    pub flip_diagonal: bool,
+}
+
+impl TileGid {
+    pub fn parse(raw: u32) -> Self {
+        Self {
+            gid: raw & !FLAGS_MASK,
+            flip_x: raw & FLIPPED_HORIZONTALLY_FLAG != 0,
+            flip_y: raw & FLIPPED_VERTICALLY_FLAG != 0,
+            flip_diagonal: raw & FLIPPED_DIAGONALLY_FLAG != 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gid == 0
+    }
+}
+
+impl Tileset {
+    pub fn is_external(&self) -> bool {
+        self.source.is_some() && self.image.is_empty()
+    }
+
+    // The file the tileset is loaded from, the `.tsx` for external tilesets or else the image
+    pub fn asset(&self) -> &str {
+        self.source.as_deref().unwrap_or(&self.image)
+    }
+
+    // Fill in an external tileset, the image path is made relative to the map like embedded tilesets
+    pub fn resolve(&mut self, external: Tileset) {
+        let firstgid = self.firstgid;
+        let source = self.source.take();
+        *self = external;
+        self.firstgid = firstgid;
+        if let Some(source) = &source {
+            self.image = resolve_path(source, &self.image);
+        }
+        self.source = source;
+    }
+
+    // Tilesets exported before columns existed only have the image width
+    pub fn column_count(&self) -> u32 {
+        if self.columns > 0 {
+            return self.columns;
+        }
+        let stride = self.tilewidth + self.spacing;
+        if stride == 0 {
+            return 0;
+        }
+        (self.imagewidth.saturating_sub(self.margin * 2) + self.spacing) / stride
+    }
+
+    // Per-tile data for a gid of this tileset
+    pub fn tile(&self, gid: u32) -> Option<&Tile> {
+        if !self.contains(gid) {
+            return None;
+        }
+        let local_id = gid - self.firstgid;
+        self.tiles.iter().flatten().find(|tile| tile.id == local_id)
+    }
+
+    pub fn contains(&self, gid: u32) -> bool {
+        gid >= self.firstgid && (self.tilecount == 0 || gid < self.firstgid + self.tilecount)
+    }
+
+    // Source rect (x, y, width, height) of a tile in the tileset image
+    pub fn tile_rect(&self, gid: u32) -> Option<(u32, u32, u32, u32)> {
+        let columns = self.column_count();
+        if columns == 0 || !self.contains(gid) {
+            return None;
+        }
+        let local_id = gid - self.firstgid;
+        let x = self.margin + (local_id % columns) * (self.tilewidth + self.spacing);
+        let y = self.margin + (local_id / columns) * (self.tileheight + self.spacing);
+        Some((x, y, self.tilewidth, self.tileheight))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TiledCell {
+    #[serde(default)]
     pub compressionlevel: i32,
     pub height: u32,
     pub infinite: bool,
@@ -80,6 +622,125 @@ pub struct TiledCell {
     pub cell_type: String,
     pub version: String,
     pub width: u32,
+    pub properties: Option<Vec<Property>>,
+}
+
+impl TiledCell {
+    // The tileset a gid belongs to is the one with the highest firstgid not above it
+    pub fn tileset_index(&self, gid: u32) -> Option<usize> {
+        self.tilesets
+            .iter()
+            .enumerate()
+            .filter(|(_, tileset)| tileset.firstgid <= gid)
+            .max_by_key(|(_, tileset)| tileset.firstgid)
+            .map(|(index, _)| index)
+    }
+
+    pub fn tileset_for_gid(&self, gid: u32) -> Option<&Tileset> {
+        self.tileset_index(gid).map(|index| &self.tilesets[index])
+    }
+
+    pub fn tile(&self, gid: u32) -> Option<&Tile> {
+        self.tileset_for_gid(gid)?.tile(gid)
+    }
+
+    pub fn tile_property(&self, gid: u32, name: &str) -> Option<&PropertyValue> {
+        self.tile(gid)?.property(name)
+    }
+
+    // Property of the topmost tile at a tile position that has it, e.g. `tile_property_at(x, y, "damage")`
+    pub fn tile_property_at(&self, x: i32, y: i32, name: &str) -> Option<&PropertyValue> {
+        self.tile_layers()
+            .into_iter()
+            .rev()
+            .find_map(|layer| self.tile_property(TileGid::parse(layer.tile_at(x, y)).gid, name))
+    }
+
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(&self.properties, name)
+    }
+
+    // Tile layers in draw order, including the ones in groups
+    pub fn tile_layers(&self) -> Vec<&Layer> {
+        fn collect<'a>(layers: &'a [Layer], tile_layers: &mut Vec<&'a Layer>) {
+            for layer in layers {
+                if let Some(layers) = &layer.layers {
+                    collect(layers, tile_layers);
+                } else if layer.layer_type == "tilelayer" {
+                    tile_layers.push(layer);
+                }
+            }
+        }
+        let mut tile_layers = Vec::new();
+        collect(&self.layers, &mut tile_layers);
+        tile_layers
+    }
+
+    // Tile extents as (x, y, width, height), infinite maps cover all of their chunks
+    pub fn tile_bounds(&self) -> (i32, i32, u32, u32) {
+        if !self.infinite {
+            return (0, 0, self.width, self.height);
+        }
+        let bounds = self
+            .tile_layers()
+            .into_iter()
+            .filter_map(Layer::tile_bounds)
+            .collect::<Vec<_>>();
+        if bounds.is_empty() {
+            return (0, 0, 0, 0);
+        }
+        let min_x = bounds.iter().map(|b| b.0).min().unwrap();
+        let min_y = bounds.iter().map(|b| b.1).min().unwrap();
+        let max_x = bounds.iter().map(|b| b.0 + b.2 as i32).max().unwrap();
+        let max_y = bounds.iter().map(|b| b.1 + b.3 as i32).max().unwrap();
+        (min_x, min_y, (max_x - min_x) as u32, (max_y - min_y) as u32)
+    }
+
+    // Gid of the topmost tile with properties at each tile over `tile_bounds`, 0 where no tile has any
+    pub fn property_grid(&self) -> Vec<u32> {
+        let (min_x, min_y, width, height) = self.tile_bounds();
+        let layers = self.tile_layers();
+        let mut grid = vec![0u32; width as usize * height as usize];
+        for y in 0..height {
+            for x in 0..width {
+                grid[x as usize + y as usize * width as usize] = layers
+                    .iter()
+                    .rev()
+                    .map(|layer| TileGid::parse(layer.tile_at(min_x + x as i32, min_y + y as i32)).gid)
+                    .find(|gid| *gid != 0 && self.tile(*gid).is_some_and(Tile::has_properties))
+                    .unwrap_or(0);
+            }
+        }
+        grid
+    }
+
+    // One byte per tile over `tile_bounds`, 1 where the tile is solid.
+    // Collision shapes mark their whole tile as solid.
+    pub fn collision_grid(&self) -> Vec<u8> {
+        let (min_x, min_y, width, height) = self.tile_bounds();
+        let layers = self.tile_layers();
+        let mut grid = vec![0u8; width as usize * height as usize];
+        for y in 0..height {
+            for x in 0..width {
+                let solid = layers.iter().any(|layer| {
+                    let gid = TileGid::parse(layer.tile_at(min_x + x as i32, min_y + y as i32)).gid;
+                    gid != 0 && (layer.is_collision() || self.tile(gid).is_some_and(Tile::is_solid))
+                });
+                grid[x as usize + y as usize * width as usize] = solid as u8;
+            }
+        }
+        grid
+    }
+
+    pub fn pixel_size(&self) -> (u32, u32) {
+        let (_, _, width, height) = self.tile_bounds();
+        (width * self.tilewidth, height * self.tileheight)
+    }
+
+    // Decode base64 / compressed layer data into gids
+    fn decode(&mut self) -> Result<(), Error> {
+        self.layers.iter_mut().try_for_each(Layer::decode)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -105,12 +766,32 @@ pub struct TiledWorld {
     pub world_type: String,
 }
 
-pub fn parse_world(world: &str) -> TiledWorld {
-    let world: TiledWorld = serde_json::from_str(world).unwrap();
-    world
+// XML documents start with `<`, anything else is parsed as JSON
+fn is_xml(data: &str) -> bool {
+    data.trim_start().starts_with('<')
+}
+
+pub fn parse_world(world: &str) -> Result<TiledWorld, Error> {
+    let world: TiledWorld = serde_json::from_str(world)?;
+    Ok(world)
+}
+
+// Parse a `.tmj` or `.tmx` map, external tilesets are left for `Tileset::resolve`
+pub fn parse_cell(cell: &str) -> Result<TiledCell, Error> {
+    let mut cell: TiledCell = if is_xml(cell) {
+        tmx::parse_map(cell)?
+    } else {
+        serde_json::from_str(cell)?
+    };
+    cell.decode()?;
+    Ok(cell)
 }
 
-pub fn parse_cell(cell: &str) -> TiledCell {
-    let cell: TiledCell = serde_json::from_str(cell).unwrap();
-    cell
+// Parse a `.tsj` or `.tsx` tileset
+pub fn parse_tileset(tileset: &str) -> Result<Tileset, Error> {
+    if is_xml(tileset) {
+        tmx::parse_tileset(tileset)
+    } else {
+        Ok(serde_json::from_str(tileset)?)
+    }
 }
\ No newline at end of file
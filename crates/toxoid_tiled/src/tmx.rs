@@ -0,0 +1,279 @@
+// TMX maps and TSX tilesets, parsed into the same types as Tiled JSON
+use std::str::FromStr;
+use roxmltree::{Document, Node};
+use crate::*;
+
+fn attribute<T: FromStr>(node: Node, name: &str) -> Result<Option<T>, Error> {
+    match node.attribute(name) {
+        Some(value) => value.trim().parse::<T>().map(Some).map_err(|_| Error::InvalidAttribute {
+            element: node.tag_name().name().to_string(),
+            attribute: name.to_string(),
+            value: value.to_string(),
+        }),
+        None => Ok(None),
+    }
+}
+
+fn required<T: FromStr>(node: Node, name: &str) -> Result<T, Error> {
+    attribute(node, name)?.ok_or_else(|| Error::MissingAttribute {
+        element: node.tag_name().name().to_string(),
+        attribute: name.to_string(),
+    })
+}
+
+fn string(node: Node, name: &str) -> String {
+    node.attribute(name).unwrap_or_default().to_string()
+}
+
+// Booleans are written as 0 and 1
+fn flag(node: Node, name: &str, default: bool) -> Result<bool, Error> {
+    Ok(attribute::<u8>(node, name)?.map_or(default, |value| value != 0))
+}
+
+fn elements<'a, 'input>(node: Node<'a, 'input>, name: &'static str) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |child| child.has_tag_name(name))
+}
+
+fn element<'a, 'input>(node: Node<'a, 'input>, name: &'static str) -> Option<Node<'a, 'input>> {
+    elements(node, name).next()
+}
+
+pub fn parse_map(xml: &str) -> Result<TiledCell, Error> {
+    let document = Document::parse(xml)?;
+    let map = document.root_element();
+    Ok(TiledCell {
+        compressionlevel: attribute(map, "compressionlevel")?.unwrap_or(-1),
+        height: required(map, "height")?,
+        infinite: flag(map, "infinite", false)?,
+        layers: parse_layers(map)?,
+        nextlayerid: attribute(map, "nextlayerid")?.unwrap_or(0),
+        nextobjectid: attribute(map, "nextobjectid")?.unwrap_or(0),
+        orientation: string(map, "orientation"),
+        render_order: string(map, "renderorder"),
+        tiled_version: string(map, "tiledversion"),
+        tileheight: required(map, "tileheight")?,
+        tilesets: elements(map, "tileset").map(parse_tileset_node).collect::<Result<_, _>>()?,
+        tilewidth: required(map, "tilewidth")?,
+        cell_type: "map".to_string(),
+        version: string(map, "version"),
+        width: required(map, "width")?,
+        properties: parse_properties(map)?,
+    })
+}
+
+pub fn parse_tileset(xml: &str) -> Result<Tileset, Error> {
+    let document = Document::parse(xml)?;
+    parse_tileset_node(document.root_element())
+}
+
+fn parse_tileset_node(node: Node) -> Result<Tileset, Error> {
+    let image = element(node, "image");
+    Ok(Tileset {
+        source: node.attribute("source").map(str::to_string),
+        columns: attribute(node, "columns")?.unwrap_or(0),
+        firstgid: attribute(node, "firstgid")?.unwrap_or(0),
+        image: image.map(|image| string(image, "source")).unwrap_or_default(),
+        imageheight: image.map(|image| attribute(image, "height")).transpose()?.flatten().unwrap_or(0),
+        imagewidth: image.map(|image| attribute(image, "width")).transpose()?.flatten().unwrap_or(0),
+        margin: attribute(node, "margin")?.unwrap_or(0),
+        name: string(node, "name"),
+        spacing: attribute(node, "spacing")?.unwrap_or(0),
+        tilecount: attribute(node, "tilecount")?.unwrap_or(0),
+        tileheight: attribute(node, "tileheight")?.unwrap_or(0),
+        tilewidth: attribute(node, "tilewidth")?.unwrap_or(0),
+        tiles: Some(elements(node, "tile").map(parse_tile).collect::<Result<Vec<Tile>, Error>>()?).filter(|tiles| !tiles.is_empty()),
+        properties: parse_properties(node)?,
+        sprite: None,
+    })
+}
+
+fn parse_tile(node: Node) -> Result<Tile, Error> {
+    let animation = element(node, "animation")
+        .map(|animation| {
+            elements(animation, "frame")
+                .map(|frame| -> Result<Frame, Error> {
+                    Ok(Frame {
+                        tileid: required(frame, "tileid")?,
+                        duration: required(frame, "duration")?,
+                    })
+                })
+                .collect::<Result<Vec<Frame>, Error>>()
+        })
+        .transpose()?;
+    Ok(Tile {
+        id: required(node, "id")?,
+        tile_type: node.attribute("class").or(node.attribute("type")).unwrap_or_default().to_string(),
+        properties: parse_properties(node)?,
+        animation,
+        objectgroup: element(node, "objectgroup").map(parse_layer).transpose()?,
+    })
+}
+
+// Layers keep their document order, which is also their draw order
+fn parse_layers(node: Node) -> Result<Vec<Layer>, Error> {
+    node.children()
+        .filter(|child| matches!(child.tag_name().name(), "layer" | "objectgroup" | "imagelayer" | "group"))
+        .map(parse_layer)
+        .collect()
+}
+
+fn parse_layer(node: Node) -> Result<Layer, Error> {
+    let layer_type = match node.tag_name().name() {
+        "layer" => "tilelayer",
+        tag => tag,
+    };
+    let mut layer = Layer {
+        data: None,
+        chunks: None,
+        encoding: None,
+        compression: None,
+        height: attribute(node, "height")?,
+        id: attribute(node, "id")?.unwrap_or(0),
+        name: string(node, "name"),
+        opacity: attribute(node, "opacity")?.unwrap_or(1.),
+        layer_type: layer_type.to_string(),
+        visible: flag(node, "visible", true)?,
+        width: attribute(node, "width")?,
+        layers: None,
+        x: attribute(node, "x")?.unwrap_or(0),
+        y: attribute(node, "y")?.unwrap_or(0),
+        startx: 0,
+        starty: 0,
+        offsetx: attribute(node, "offsetx")?.unwrap_or(0.),
+        offsety: attribute(node, "offsety")?.unwrap_or(0.),
+        image: None,
+        imagewidth: None,
+        imageheight: None,
+        repeatx: flag(node, "repeatx", false)?,
+        repeaty: flag(node, "repeaty", false)?,
+        properties: parse_properties(node)?,
+        objects: None,
+    };
+    match layer_type {
+        "tilelayer" => {
+            if let Some(data) = element(node, "data") {
+                let encoding = data.attribute("encoding");
+                let compression = data.attribute("compression");
+                let chunks = elements(data, "chunk")
+                    .map(|chunk| -> Result<Chunk, Error> {
+                        Ok(Chunk {
+                            data: TileData::Tiles(parse_tiles(chunk, encoding, compression)?),
+                            x: required(chunk, "x")?,
+                            y: required(chunk, "y")?,
+                            width: required(chunk, "width")?,
+                            height: required(chunk, "height")?,
+                        })
+                    })
+                    .collect::<Result<Vec<Chunk>, Error>>()?;
+                if chunks.is_empty() {
+                    layer.data = Some(TileData::Tiles(parse_tiles(data, encoding, compression)?));
+                } else {
+                    layer.chunks = Some(chunks);
+                }
+                layer.encoding = encoding.map(str::to_string);
+                layer.compression = compression.map(str::to_string);
+            }
+        },
+        "objectgroup" => {
+            layer.objects = Some(elements(node, "object").map(parse_object).collect::<Result<_, _>>()?);
+        },
+        "imagelayer" => {
+            if let Some(image) = element(node, "image") {
+                layer.image = Some(string(image, "source"));
+                layer.imagewidth = attribute(image, "width")?;
+                layer.imageheight = attribute(image, "height")?;
+            }
+        },
+        "group" => {
+            layer.layers = Some(parse_layers(node)?);
+        },
+        _ => {}
+    }
+    Ok(layer)
+}
+
+// Without an encoding the gids are written as `<tile gid="..."/>` elements
+fn parse_tiles(node: Node, encoding: Option<&str>, compression: Option<&str>) -> Result<Vec<u32>, Error> {
+    if encoding.is_none() {
+        return elements(node, "tile")
+            .map(|tile| -> Result<u32, Error> { Ok(attribute(tile, "gid")?.unwrap_or(0)) })
+            .collect();
+    }
+    let text = node
+        .children()
+        .filter(|child| child.is_text())
+        .filter_map(|child| child.text())
+        .collect::<String>();
+    decode_tiles(&text, encoding, compression)
+}
+
+fn parse_object(node: Node) -> Result<Object, Error> {
+    Ok(Object {
+        id: attribute(node, "id")?.unwrap_or(0),
+        name: string(node, "name"),
+        // Tiled 1.9+ writes the type as `class`
+        object_type: node.attribute("class").or(node.attribute("type")).unwrap_or_default().to_string(),
+        x: attribute(node, "x")?.unwrap_or(0.),
+        y: attribute(node, "y")?.unwrap_or(0.),
+        width: attribute(node, "width")?.unwrap_or(0.),
+        height: attribute(node, "height")?.unwrap_or(0.),
+        rotation: attribute(node, "rotation")?.unwrap_or(0.),
+        gid: attribute(node, "gid")?,
+        visible: flag(node, "visible", true)?,
+        properties: parse_properties(node)?,
+        ellipse: element(node, "ellipse").is_some(),
+        point: element(node, "point").is_some(),
+        polygon: element(node, "polygon").map(parse_points).transpose()?,
+        polyline: element(node, "polyline").map(parse_points).transpose()?,
+    })
+}
+
+// Points are written as `x,y x,y ...`
+fn parse_points(node: Node) -> Result<Vec<Point>, Error> {
+    let points = string(node, "points");
+    points
+        .split_whitespace()
+        .map(|point| -> Result<Point, Error> {
+            let invalid = || Error::InvalidAttribute {
+                element: node.tag_name().name().to_string(),
+                attribute: "points".to_string(),
+                value: points.clone(),
+            };
+            let (x, y) = point.split_once(',').ok_or_else(invalid)?;
+            Ok(Point {
+                x: x.parse().map_err(|_| invalid())?,
+                y: y.parse().map_err(|_| invalid())?,
+            })
+        })
+        .collect()
+}
+
+fn parse_properties(node: Node) -> Result<Option<Vec<Property>>, Error> {
+    element(node, "properties")
+        .map(|properties| elements(properties, "property").map(parse_property).collect())
+        .transpose()
+}
+
+fn parse_property(node: Node) -> Result<Property, Error> {
+    let name = string(node, "name");
+    let property_type = node.attribute("type").unwrap_or("string").to_string();
+    let value = if property_type == "class" {
+        // Class members are nested properties
+        let members = parse_properties(node)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|member| (member.name, member.value.to_json()))
+            .collect();
+        PropertyValue::Class(members)
+    } else {
+        // Multiline strings are written as text instead of the value attribute
+        let value = node.attribute("value").or(node.text()).unwrap_or_default();
+        PropertyValue::parse(&name, &property_type, value)?
+    };
+    Ok(Property {
+        name,
+        property_type,
+        custom_type: node.attribute("propertytype").map(str::to_string),
+        value,
+    })
+}
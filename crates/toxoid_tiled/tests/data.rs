@@ -0,0 +1,93 @@
+use std::io::Write;
+use base64::Engine;
+use toxoid_tiled::{decode_tiles, resolve_path, Error};
+
+const GIDS: [u32; 8] = [1, 2, 0, 3, 0x80000001, 4, 4, 1];
+
+fn encode(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn gid_bytes() -> Vec<u8> {
+    GIDS.iter().flat_map(|gid| gid.to_le_bytes()).collect()
+}
+
+#[test]
+fn decodes_csv() {
+    let tiles = decode_tiles("\n1,2,0,3,\n2147483649,4,4,1\n", Some("csv"), None).unwrap();
+    assert_eq!(tiles, GIDS);
+}
+
+#[test]
+fn csv_is_the_default_encoding() {
+    assert_eq!(decode_tiles("5, 6", None, None).unwrap(), vec![5, 6]);
+}
+
+#[test]
+fn rejects_invalid_csv() {
+    assert!(matches!(decode_tiles("1,x", Some("csv"), None), Err(Error::InvalidTileData(_))));
+}
+
+#[test]
+fn decodes_uncompressed_base64() {
+    let data = encode(&gid_bytes());
+    assert_eq!(decode_tiles(&data, Some("base64"), None).unwrap(), GIDS);
+    assert_eq!(decode_tiles(&data, Some("base64"), Some("")).unwrap(), GIDS);
+}
+
+#[test]
+fn decodes_zlib_base64() {
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&gid_bytes()).unwrap();
+    let data = encode(&encoder.finish().unwrap());
+    assert_eq!(decode_tiles(&data, Some("base64"), Some("zlib")).unwrap(), GIDS);
+}
+
+#[test]
+fn decodes_gzip_base64() {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&gid_bytes()).unwrap();
+    let data = encode(&encoder.finish().unwrap());
+    assert_eq!(decode_tiles(&data, Some("base64"), Some("gzip")).unwrap(), GIDS);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn decodes_zstd_base64() {
+    let data = encode(&zstd::stream::encode_all(gid_bytes().as_slice(), 0).unwrap());
+    assert_eq!(decode_tiles(&data, Some("base64"), Some("zstd")).unwrap(), GIDS);
+}
+
+#[cfg(not(feature = "zstd"))]
+#[test]
+fn rejects_zstd_without_feature() {
+    let data = encode(&gid_bytes());
+    assert!(matches!(decode_tiles(&data, Some("base64"), Some("zstd")), Err(Error::UnsupportedCompression(_))));
+}
+
+#[test]
+fn rejects_partial_gids() {
+    let data = encode(&[1, 0, 0]);
+    assert!(matches!(decode_tiles(&data, Some("base64"), None), Err(Error::InvalidTileData(_))));
+}
+
+#[test]
+fn rejects_corrupt_compressed_data() {
+    let data = encode(&gid_bytes());
+    assert!(matches!(decode_tiles(&data, Some("base64"), Some("zlib")), Err(Error::Decompress(_))));
+}
+
+#[test]
+fn rejects_unsupported_encoding_and_compression() {
+    assert!(matches!(decode_tiles("", Some("xml"), None), Err(Error::UnsupportedEncoding(_))));
+    assert!(matches!(decode_tiles("", Some("base64"), Some("lz4")), Err(Error::UnsupportedCompression(_))));
+}
+
+#[test]
+fn resolves_paths_relative_to_the_referencing_file() {
+    assert_eq!(resolve_path("maps/town.tmx", "town.png"), "maps/town.png");
+    assert_eq!(resolve_path("maps/tilesets/terrain.tsx", "../images/terrain.png"), "maps/images/terrain.png");
+    assert_eq!(resolve_path("maps/town.tmx", "./tiles//grass.png"), "maps/tiles/grass.png");
+    assert_eq!(resolve_path("town.tmx", "../shared/grass.png"), "../shared/grass.png");
+    assert_eq!(resolve_path("../town.tmx", "../grass.png"), "../../grass.png");
+}
@@ -0,0 +1,26 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<tileset version="1.10" tiledversion="1.10.2" name="terrain" tilewidth="16" tileheight="16" tilecount="6" columns="3">
+ <image source="../images/terrain.png" width="48" height="32"/>
+ <tile id="0" class="grass">
+  <properties>
+   <property name="speed" type="float" value="0.5"/>
+   <property name="damage" type="int" value="2"/>
+   <property name="stats" type="class" propertytype="Stats">
+    <properties>
+     <property name="armor" type="int" value="3"/>
+    </properties>
+   </property>
+  </properties>
+ </tile>
+ <tile id="1">
+  <objectgroup draworder="index" id="2">
+   <object id="1" x="0" y="8" width="16" height="8"/>
+  </objectgroup>
+ </tile>
+ <tile id="4">
+  <animation>
+   <frame tileid="4" duration="100"/>
+   <frame tileid="5" duration="300"/>
+  </animation>
+ </tile>
+</tileset>
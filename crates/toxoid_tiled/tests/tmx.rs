@@ -0,0 +1,144 @@
+use toxoid_tiled::*;
+
+const FINITE: &str = include_str!("fixtures/finite.tmx");
+const CHUNKED: &str = include_str!("fixtures/chunked.tmx");
+const TERRAIN: &str = include_str!("fixtures/tilesets/terrain.tsx");
+
+#[test]
+fn parses_compressed_layers() {
+    let cell = parse_cell(FINITE).unwrap();
+    assert_eq!((cell.width, cell.height, cell.tilewidth, cell.tileheight), (4, 2, 16, 16));
+    assert!(!cell.infinite);
+    let gids = [1, 2, 0, 3, 0x80000001, 4, 4, 1];
+    assert_eq!(cell.layers[0].tiles().unwrap(), gids);
+    assert_eq!(cell.layers[1].tiles().unwrap(), gids);
+    assert_eq!(cell.layers[1].opacity, 0.5);
+    assert!(!cell.layers[1].visible);
+    assert_eq!(cell.layers[0].tile_at(3, 0), 3);
+    assert_eq!(TileGid::parse(cell.layers[0].tile_at(0, 1)), TileGid { gid: 1, flip_x: true, flip_y: false, flip_diagonal: false });
+    assert_eq!(cell.layers[0].tile_at(4, 0), 0);
+}
+
+#[test]
+fn parses_embedded_tilesets() {
+    let cell = parse_cell(FINITE).unwrap();
+    let tileset = &cell.tilesets[0];
+    assert!(!tileset.is_external());
+    assert_eq!(tileset.image, "town.png");
+    assert_eq!(tileset.tile_rect(1), Some((1, 1, 16, 16)));
+    assert_eq!(tileset.tile_rect(6), Some((18, 18, 16, 16)));
+    assert_eq!(tileset.tile_rect(9), None);
+}
+
+#[test]
+fn parses_objects_and_properties() {
+    let cell = parse_cell(FINITE).unwrap();
+    let properties = cell.properties.as_ref().unwrap();
+    assert_eq!((properties[0].name.as_str(), &properties[0].value), ("music", &PropertyValue::File("../audio/town.ogg".to_string())));
+    assert_eq!((properties[1].name.as_str(), &properties[1].value), ("tint", &PropertyValue::Color(Color { r: 255, g: 0, b: 0, a: 128 })));
+    let objects = cell.layers[2].objects.as_ref().unwrap();
+    assert_eq!(objects[0].name, "door");
+    assert_eq!(objects[0].object_type, "Door");
+    assert_eq!((objects[0].x, objects[0].y, objects[0].width, objects[0].height), (16., 8., 16., 8.));
+    let properties = objects[0].properties.as_ref().unwrap();
+    assert_eq!(properties[0].value, PropertyValue::Bool(true));
+    assert_eq!(properties[1].value, PropertyValue::Object(7));
+}
+
+#[test]
+fn parses_infinite_chunks() {
+    let cell = parse_cell(CHUNKED).unwrap();
+    assert!(cell.infinite);
+    let ground = &cell.layers[0];
+    assert_eq!(ground.chunks.as_ref().unwrap().len(), 2);
+    assert_eq!(ground.tile_at(-2, -2), 1);
+    assert_eq!(ground.tile_at(-1, -1), 4);
+    assert_eq!(ground.tile_at(1, 1), 6);
+    assert_eq!(ground.tile_at(-1, 0), 0);
+    assert_eq!(ground.tile_bounds(), Some((-2, -2, 4, 4)));
+    // Chunks without an encoding list their tiles as elements
+    assert_eq!(cell.layers[1].tile_at(0, -2), 2);
+    assert_eq!(cell.layers[1].tile_at(1, -2), 0);
+    assert_eq!(cell.tile_bounds(), (-2, -2, 4, 4));
+    assert_eq!(cell.pixel_size(), (64, 64));
+}
+
+#[test]
+fn resolves_external_tilesets() {
+    let mut cell = parse_cell(CHUNKED).unwrap();
+    let tileset = &mut cell.tilesets[0];
+    assert!(tileset.is_external());
+    assert_eq!(tileset.asset(), "tilesets/terrain.tsx");
+    tileset.resolve(parse_tileset(TERRAIN).unwrap());
+    assert!(!tileset.is_external());
+    assert_eq!(tileset.firstgid, 1);
+    assert_eq!(tileset.image, "images/terrain.png");
+    assert_eq!(tileset.source.as_deref(), Some("tilesets/terrain.tsx"));
+    assert_eq!(tileset.tile_rect(5), Some((16, 16, 16, 16)));
+}
+
+#[test]
+fn parses_tile_data() {
+    let mut cell = parse_cell(CHUNKED).unwrap();
+    cell.tilesets[0].resolve(parse_tileset(TERRAIN).unwrap());
+    let grass = cell.tile(1).unwrap();
+    assert_eq!(grass.tile_type, "grass");
+    assert_eq!(grass.property("speed").and_then(PropertyValue::as_float), Some(0.5));
+    let stats = grass.property("stats").unwrap();
+    assert!(stats.is_class());
+    assert_eq!(stats.to_json()["armor"], 3);
+    assert!(cell.tile(2).unwrap().is_solid());
+    assert!(!grass.is_solid());
+    let water = cell.tile(5).unwrap();
+    assert!(water.is_animated());
+    assert_eq!(water.animation_duration(), 400);
+    assert_eq!(water.frame_at(50), Some(4));
+    assert_eq!(water.frame_at(150), Some(5));
+    assert_eq!(water.frame_at(450), Some(4));
+    assert_eq!(cell.tile_property_at(-2, -2, "damage"), Some(&PropertyValue::Int(2)));
+    assert_eq!(cell.collision_grid(), vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(cell.property_grid(), vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(grass.properties_json()["damage"], 2);
+    assert!(!cell.tile(2).unwrap().has_properties());
+}
+
+#[test]
+fn parses_json_properties() {
+    let json = r##"[
+        {"name": "hp", "type": "int", "value": 10},
+        {"name": "label", "value": "gate"},
+        {"name": "glow", "type": "color", "value": "#00ff00"}
+    ]"##;
+    let properties: Vec<Property> = serde_json::from_str(json).unwrap();
+    assert_eq!(properties[0].value, PropertyValue::Int(10));
+    assert_eq!(properties[1].value, PropertyValue::String("gate".to_string()));
+    assert_eq!(properties[2].value, PropertyValue::Color(Color { r: 0, g: 255, b: 0, a: 255 }));
+    assert_eq!(properties[2].value_json(), "\"#ff00ff00\"");
+    assert!(serde_json::from_str::<Vec<Property>>(r#"[{"name": "hp", "type": "int", "value": "ten"}]"#).is_err());
+}
+
+#[test]
+fn parses_colors() {
+    assert_eq!(Color::parse("#ff102030"), Some(Color { r: 16, g: 32, b: 48, a: 255 }));
+    assert_eq!(Color::parse("102030"), Some(Color { r: 16, g: 32, b: 48, a: 255 }));
+    assert_eq!(Color::parse(""), Some(Color::default()));
+    assert_eq!(Color::parse("#12345"), None);
+    assert_eq!(Color::parse("#zz2030"), None);
+    assert_eq!(Color::parse("#80ff0000").unwrap().to_hex(), "#80ff0000");
+}
+
+#[test]
+fn parses_xml_property_values() {
+    assert_eq!(PropertyValue::parse("n", "int", "-3").unwrap(), PropertyValue::Int(-3));
+    assert_eq!(PropertyValue::parse("n", "float", "1.5").unwrap(), PropertyValue::Float(1.5));
+    assert_eq!(PropertyValue::parse("n", "bool", "false").unwrap(), PropertyValue::Bool(false));
+    assert!(matches!(PropertyValue::parse("n", "int", "1.5"), Err(Error::InvalidProperty { .. })));
+    assert!(PropertyValue::parse("n", "list", "1").is_err());
+    assert_eq!(PropertyValue::Int(2).as_float(), Some(2.));
+}
+
+#[test]
+fn reports_missing_attributes() {
+    let error = parse_cell(r#"<map width="1" height="1" tilewidth="16"></map>"#).unwrap_err();
+    assert!(matches!(error, Error::MissingAttribute { ref attribute, .. } if attribute == "tileheight"));
+}